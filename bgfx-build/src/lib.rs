@@ -0,0 +1,567 @@
+//! Build script helper that compiles bgfx `.sc` shaders with `shaderc`.
+//!
+//! ```no_run
+//! // build.rs
+//! bgfx_build::ShaderBuild::new()
+//!     .bgfx_dir("../sys/bgfx")
+//!     .backend(bgfx_build::Backend::Spirv)
+//!     .backend(bgfx_build::Backend::Metal)
+//!     .dir("shaders")
+//!     .compile();
+//! ```
+//!
+//! Compiled shaders are written to `$OUT_DIR/shaders/<backend>/<name>.bin`, the same layout
+//! `bgfx_rs::ShaderLibrary::from_dir` and `bgfx_rs::embedded_shader!(.., out_dir = "shaders")`
+//! read from.
+//!
+//! `shaderc` is looked up in this order:
+//! - the path given to [ShaderBuild::shaderc],
+//! - the `SHADERC` environment variable,
+//! - a `shadercRelease` built in `<bgfx_dir>/.build`, f.e. with `make -C sys/bgfx tools`.
+//!
+//! Build scripts may only write to `OUT_DIR`, so shaderc is never built from here.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Shader backend to compile for. The output directory names match
+/// `bgfx_rs::shader_library::shader_dir_name`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Backend {
+    /// Direct3D 9 (`dx9`)
+    Dx9,
+    /// Direct3D 11 and 12 (`dx11`)
+    Dx11,
+    /// OpenGL 4.4 (`glsl`)
+    Glsl,
+    /// OpenGL ES 3.2 (`essl`)
+    Essl,
+    /// Metal (`metal`)
+    Metal,
+    /// Vulkan (`spirv`)
+    Spirv,
+}
+
+impl Backend {
+    /// All supported backends.
+    pub const ALL: [Backend; 6] = [
+        Backend::Dx9,
+        Backend::Dx11,
+        Backend::Glsl,
+        Backend::Essl,
+        Backend::Metal,
+        Backend::Spirv,
+    ];
+
+    /// Output directory name of this backend.
+    pub fn dir_name(self) -> &'static str {
+        match self {
+            Backend::Dx9 => "dx9",
+            Backend::Dx11 => "dx11",
+            Backend::Glsl => "glsl",
+            Backend::Essl => "essl",
+            Backend::Metal => "metal",
+            Backend::Spirv => "spirv",
+        }
+    }
+
+    fn platform(self) -> &'static str {
+        match self {
+            Backend::Dx9 | Backend::Dx11 => "windows",
+            Backend::Glsl | Backend::Spirv => "linux",
+            Backend::Essl => "android",
+            Backend::Metal => "osx",
+        }
+    }
+
+    fn profile(self) -> &'static str {
+        match self {
+            Backend::Dx9 => "s_3_0",
+            Backend::Dx11 => "s_5_0",
+            Backend::Glsl => "440",
+            Backend::Essl => "320_es",
+            Backend::Metal => "metal",
+            Backend::Spirv => "spirv",
+        }
+    }
+}
+
+/// Shader stage, derived from the `vs_`, `fs_` or `cs_` file name prefix.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Stage {
+    Vertex,
+    Fragment,
+    Compute,
+}
+
+impl Stage {
+    /// Returns the stage for a file named `vs_*`, `fs_*` or `cs_*`.
+    pub fn from_file_name(name: &str) -> Option<Stage> {
+        if name.starts_with("vs_") {
+            Some(Stage::Vertex)
+        } else if name.starts_with("fs_") {
+            Some(Stage::Fragment)
+        } else if name.starts_with("cs_") {
+            Some(Stage::Compute)
+        } else {
+            None
+        }
+    }
+
+    fn arg(self) -> &'static str {
+        match self {
+            Stage::Vertex => "v",
+            Stage::Fragment => "f",
+            Stage::Compute => "c",
+        }
+    }
+}
+
+/// Error produced while building `shaderc` or compiling shaders.
+#[derive(Debug)]
+pub enum Error {
+    /// No `shaderc` binary was found.
+    ShadercNotFound(String),
+    /// A shader file name has no `vs_`, `fs_` or `cs_` prefix.
+    UnknownStage(PathBuf),
+    /// `shaderc` rejected a shader.
+    Compile {
+        file: PathBuf,
+        backend: Backend,
+        command: String,
+        output: String,
+    },
+    Io(PathBuf, std::io::Error),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::ShadercNotFound(message) => write!(f, "shaderc not found: {}", message),
+            Error::UnknownStage(file) => write!(
+                f,
+                "{}: shader file names must start with `vs_`, `fs_` or `cs_`",
+                file.display()
+            ),
+            Error::Compile {
+                file,
+                backend,
+                command,
+                output,
+            } => write!(
+                f,
+                "{}: failed to compile for {:?}\n  command: {}\n{}",
+                file.display(),
+                backend,
+                command,
+                indent(output)
+            ),
+            Error::Io(path, error) => write!(f, "{}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Compiles a set of `.sc` shaders for several backends from a build script.
+#[derive(Clone, Debug, Default)]
+pub struct ShaderBuild {
+    bgfx_dir: Option<PathBuf>,
+    shaderc: Option<PathBuf>,
+    backends: Vec<Backend>,
+    files: Vec<PathBuf>,
+    include_dirs: Vec<PathBuf>,
+    varying_def: Option<PathBuf>,
+    out_dir: Option<PathBuf>,
+}
+
+impl ShaderBuild {
+    pub fn new() -> ShaderBuild {
+        ShaderBuild::default()
+    }
+
+    /// Directory containing the bgfx sources, as fetched by `sys/update.sh`. Its `src` is
+    /// included for `bgfx_shader.sh` and a shaderc built in its `.build` is used when `SHADERC`
+    /// isn't set. Defaults to the `BGFX_DIR` environment variable.
+    pub fn bgfx_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        self.bgfx_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Uses the `shaderc` binary at `path`.
+    pub fn shaderc<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.shaderc = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds a backend to compile for. When none is added all of [Backend::ALL] are used.
+    pub fn backend(&mut self, backend: Backend) -> &mut Self {
+        if !self.backends.contains(&backend) {
+            self.backends.push(backend);
+        }
+        self
+    }
+
+    pub fn backends(&mut self, backends: &[Backend]) -> &mut Self {
+        for backend in backends {
+            self.backend(*backend);
+        }
+        self
+    }
+
+    /// Adds a single `vs_*.sc`, `fs_*.sc` or `cs_*.sc` file.
+    pub fn file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.files.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Adds every `vs_*.sc`, `fs_*.sc` and `cs_*.sc` file in `dir` (not recursive).
+    pub fn dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        let dir = dir.as_ref();
+        println!("cargo:rerun-if-changed={}", dir.display());
+
+        if let Ok(entries) = std::fs::read_dir(dir) {
+            let mut files: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| {
+                    path.extension().is_some_and(|ext| ext == "sc")
+                        && path
+                            .file_name()
+                            .and_then(|name| name.to_str())
+                            .and_then(Stage::from_file_name)
+                            .is_some()
+                })
+                .collect();
+            files.sort();
+            self.files.extend(files);
+        }
+        self
+    }
+
+    /// Adds an include directory passed to shaderc with `-i`. `<bgfx_dir>/src` (for
+    /// `bgfx_shader.sh`) and the directory of each shader are always included.
+    pub fn include<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        self.include_dirs.push(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Overrides the `varying.def.sc` file. Defaults to `varying.def.sc` next to each shader.
+    pub fn varying_def<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.varying_def = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Output directory. Defaults to `$OUT_DIR/shaders`.
+    pub fn out_dir<P: AsRef<Path>>(&mut self, dir: P) -> &mut Self {
+        self.out_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Compiles all shaders, panicking with shaderc's output on failure.
+    pub fn compile(&self) {
+        if let Err(e) = self.try_compile() {
+            panic!("\n\n{}\n\n", e);
+        }
+    }
+
+    /// Compiles all shaders and returns the output directory.
+    pub fn try_compile(&self) -> Result<PathBuf, Error> {
+        println!("cargo:rerun-if-env-changed=SHADERC");
+        println!("cargo:rerun-if-env-changed=BGFX_DIR");
+
+        let bgfx_dir = self
+            .bgfx_dir
+            .clone()
+            .or_else(|| std::env::var_os("BGFX_DIR").map(PathBuf::from));
+        let shaderc = self.find_shaderc(bgfx_dir.as_deref())?;
+
+        let out_dir = match &self.out_dir {
+            Some(dir) => dir.clone(),
+            None => std::env::var_os("OUT_DIR")
+                .map(|dir| PathBuf::from(dir).join("shaders"))
+                .ok_or_else(|| {
+                    Error::Io(
+                        PathBuf::from("$OUT_DIR"),
+                        std::io::Error::new(
+                            std::io::ErrorKind::NotFound,
+                            "OUT_DIR is not set, call out_dir() outside of build scripts",
+                        ),
+                    )
+                })?,
+        };
+
+        let backends = if self.backends.is_empty() {
+            &Backend::ALL[..]
+        } else {
+            &self.backends[..]
+        };
+
+        for file in &self.files {
+            println!("cargo:rerun-if-changed={}", file.display());
+
+            let name = file
+                .file_stem()
+                .and_then(|name| name.to_str())
+                .ok_or_else(|| Error::UnknownStage(file.clone()))?;
+            let stage =
+                Stage::from_file_name(name).ok_or_else(|| Error::UnknownStage(file.clone()))?;
+
+            let shader_dir = file.parent().unwrap_or_else(|| Path::new("."));
+            let varying_def = self
+                .varying_def
+                .clone()
+                .unwrap_or_else(|| shader_dir.join("varying.def.sc"));
+            println!("cargo:rerun-if-changed={}", varying_def.display());
+
+            for backend in backends {
+                let backend_dir = out_dir.join(backend.dir_name());
+                std::fs::create_dir_all(&backend_dir)
+                    .map_err(|e| Error::Io(backend_dir.clone(), e))?;
+
+                let mut command = self.command(
+                    &shaderc,
+                    file,
+                    stage,
+                    *backend,
+                    &backend_dir.join(format!("{}.bin", name)),
+                    &varying_def,
+                    bgfx_dir.as_deref(),
+                );
+                let output = command
+                    .output()
+                    .map_err(|e| Error::Io(shaderc.clone(), e))?;
+                if !output.status.success() {
+                    return Err(Error::Compile {
+                        file: file.clone(),
+                        backend: *backend,
+                        command: format!("{:?}", command),
+                        output: command_output(&output),
+                    });
+                }
+            }
+        }
+
+        Ok(out_dir)
+    }
+
+    /// The shaderc command compiling `file` for `backend` into `output`.
+    #[allow(clippy::too_many_arguments)]
+    fn command(
+        &self,
+        shaderc: &Path,
+        file: &Path,
+        stage: Stage,
+        backend: Backend,
+        output: &Path,
+        varying_def: &Path,
+        bgfx_dir: Option<&Path>,
+    ) -> Command {
+        let mut command = Command::new(shaderc);
+        command
+            .arg("-f")
+            .arg(file)
+            .arg("-o")
+            .arg(output)
+            .args(["--type", stage.arg()])
+            .args(["--platform", backend.platform()])
+            .args(["-p", backend.profile()])
+            .arg("-i")
+            .arg(
+                file.parent()
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .unwrap_or_else(|| Path::new(".")),
+            );
+
+        if let Some(bgfx_dir) = bgfx_dir {
+            command.arg("-i").arg(bgfx_dir.join("src"));
+        }
+        for dir in &self.include_dirs {
+            command.arg("-i").arg(dir);
+        }
+        if stage != Stage::Compute {
+            command.arg("--varyingdef").arg(varying_def);
+        }
+        if matches!(backend, Backend::Dx9 | Backend::Dx11) {
+            command.args(["-O", "3"]);
+        }
+        command
+    }
+
+    fn find_shaderc(&self, bgfx_dir: Option<&Path>) -> Result<PathBuf, Error> {
+        if let Some(shaderc) = &self.shaderc {
+            return Ok(shaderc.clone());
+        }
+        if let Some(shaderc) = std::env::var_os("SHADERC") {
+            return Ok(PathBuf::from(shaderc));
+        }
+
+        let bgfx_dir = bgfx_dir.ok_or_else(|| {
+            Error::ShadercNotFound(
+                "set SHADERC to a shaderc binary, or BGFX_DIR / ShaderBuild::bgfx_dir to bgfx \
+                 sources with shaderc built in .build"
+                    .to_owned(),
+            )
+        })?;
+
+        find_built_shaderc(bgfx_dir).ok_or_else(|| {
+            Error::ShadercNotFound(format!(
+                "no shadercRelease in {}, build it with `make -C {} tools` or set SHADERC to a \
+                 shaderc binary",
+                bgfx_dir.join(".build").display(),
+                bgfx_dir.display()
+            ))
+        })
+    }
+}
+
+/// Looks for `<bgfx_dir>/.build/<platform>/bin/shadercRelease`.
+fn find_built_shaderc(bgfx_dir: &Path) -> Option<PathBuf> {
+    let entries = std::fs::read_dir(bgfx_dir.join(".build")).ok()?;
+
+    entries
+        .filter_map(|entry| entry.ok())
+        .flat_map(|entry| {
+            let bin = entry.path().join("bin");
+            vec![bin.join("shadercRelease"), bin.join("shadercRelease.exe")]
+        })
+        .find(|path| path.is_file())
+}
+
+fn command_output(output: &std::process::Output) -> String {
+    let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+    text.push_str(&String::from_utf8_lossy(&output.stderr));
+    text.trim_end().to_owned()
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("    {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::OsStr;
+
+    fn args(command: &Command) -> Vec<&str> {
+        command
+            .get_args()
+            .map(|arg| arg.to_str().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn finds_built_shaderc() {
+        let bgfx_dir = std::env::temp_dir().join(format!("bgfx-build-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&bgfx_dir);
+        assert_eq!(find_built_shaderc(&bgfx_dir), None);
+
+        let bin = bgfx_dir.join(".build/linux64_gcc/bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        std::fs::write(bin.join("shadercDebug"), "").unwrap();
+        assert_eq!(find_built_shaderc(&bgfx_dir), None);
+
+        std::fs::write(bin.join("shadercRelease"), "").unwrap();
+        assert_eq!(
+            find_built_shaderc(&bgfx_dir),
+            Some(bin.join("shadercRelease"))
+        );
+
+        std::fs::remove_dir_all(&bgfx_dir).unwrap();
+    }
+
+    #[test]
+    fn vertex_command() {
+        let mut build = ShaderBuild::new();
+        build.include("common");
+        let command = build.command(
+            Path::new("shaderc"),
+            Path::new("shaders/vs_cubes.sc"),
+            Stage::Vertex,
+            Backend::Spirv,
+            Path::new("out/spirv/vs_cubes.bin"),
+            Path::new("shaders/varying.def.sc"),
+            Some(Path::new("bgfx")),
+        );
+
+        assert_eq!(command.get_program(), OsStr::new("shaderc"));
+        assert_eq!(
+            args(&command),
+            [
+                "-f",
+                "shaders/vs_cubes.sc",
+                "-o",
+                "out/spirv/vs_cubes.bin",
+                "--type",
+                "v",
+                "--platform",
+                "linux",
+                "-p",
+                "spirv",
+                "-i",
+                "shaders",
+                "-i",
+                "bgfx/src",
+                "-i",
+                "common",
+                "--varyingdef",
+                "shaders/varying.def.sc",
+            ]
+        );
+    }
+
+    #[test]
+    fn backend_arguments() {
+        let build = ShaderBuild::new();
+        let command = |stage, backend| {
+            build.command(
+                Path::new("shaderc"),
+                Path::new("cs_update.sc"),
+                stage,
+                backend,
+                Path::new("cs_update.bin"),
+                Path::new("varying.def.sc"),
+                None,
+            )
+        };
+
+        // Compute shaders have no varyings, Direct3D shaders are optimized.
+        assert_eq!(
+            args(&command(Stage::Compute, Backend::Dx11)),
+            [
+                "-f",
+                "cs_update.sc",
+                "-o",
+                "cs_update.bin",
+                "--type",
+                "c",
+                "--platform",
+                "windows",
+                "-p",
+                "s_5_0",
+                "-i",
+                ".",
+                "-O",
+                "3",
+            ]
+        );
+
+        for (backend, platform, profile) in [
+            (Backend::Dx9, "windows", "s_3_0"),
+            (Backend::Glsl, "linux", "440"),
+            (Backend::Essl, "android", "320_es"),
+            (Backend::Metal, "osx", "metal"),
+        ] {
+            let command = command(Stage::Fragment, backend);
+            let args = args(&command);
+            assert_eq!(
+                args[4..10],
+                ["--type", "f", "--platform", platform, "-p", profile]
+            );
+            assert_eq!(args[12..14], ["--varyingdef", "varying.def.sc"]);
+        }
+    }
+}
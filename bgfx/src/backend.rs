@@ -0,0 +1,375 @@
+use std::cell::RefCell;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+use std::rc::Rc;
+
+use bgfx_sys::*;
+
+/// Calls `$callback!` with every bgfx-sys function the API goes through, as
+/// `fn name = bgfx_name(args) -> ret;`. The groups tell how [MockBackend](crate::MockBackend)
+/// handles them: `pure` functions only compute on their arguments and are implemented in Rust by
+/// `pure`, `custom` ones are mocked by hand and `recorded` ones are recorded and return a fake
+/// result.
+macro_rules! for_each_function {
+    ($callback:ident) => {
+        $callback! {
+        pure {
+            fn attachment_init = bgfx_attachment_init(this: *mut bgfx_attachment_t, handle: bgfx_texture_handle_t, access: bgfx_access_t, layer: u16, num_layers: u16, mip: u16, resolve: u8);
+            fn calc_texture_size = bgfx_calc_texture_size(info: *mut bgfx_texture_info_t, width: u16, height: u16, depth: u16, cube_map: bool, has_mips: bool, num_layers: u16, format: bgfx_texture_format_t);
+            fn init_ctor = bgfx_init_ctor(init: *mut bgfx_init_t);
+            fn vertex_layout_add = bgfx_vertex_layout_add(this: *mut bgfx_vertex_layout_t, attrib: bgfx_attrib_t, num: u8, type_: bgfx_attrib_type_t, normalized: bool, as_int: bool) -> *mut bgfx_vertex_layout_t;
+            fn vertex_layout_begin = bgfx_vertex_layout_begin(this: *mut bgfx_vertex_layout_t, renderer_type: bgfx_renderer_type_t) -> *mut bgfx_vertex_layout_t;
+            fn vertex_layout_decode = bgfx_vertex_layout_decode(this: *const bgfx_vertex_layout_t, attrib: bgfx_attrib_t, num: *mut u8, type_: *mut bgfx_attrib_type_t, normalized: *mut bool, as_int: *mut bool);
+            fn vertex_layout_end = bgfx_vertex_layout_end(this: *mut bgfx_vertex_layout_t);
+            fn vertex_layout_has = bgfx_vertex_layout_has(this: *const bgfx_vertex_layout_t, attrib: bgfx_attrib_t) -> bool;
+            fn vertex_layout_skip = bgfx_vertex_layout_skip(this: *mut bgfx_vertex_layout_t, num: u8) -> *mut bgfx_vertex_layout_t;
+        }
+        custom {
+            fn alloc_instance_data_buffer = bgfx_alloc_instance_data_buffer(idb: *mut bgfx_instance_data_buffer_t, num: u32, stride: u16);
+            fn alloc_transient_buffers = bgfx_alloc_transient_buffers(tvb: *mut bgfx_transient_vertex_buffer_t, layout: *const bgfx_vertex_layout_t, num_vertices: u32, tib: *mut bgfx_transient_index_buffer_t, num_indices: u32, index32: bool) -> bool;
+            fn alloc_transient_index_buffer = bgfx_alloc_transient_index_buffer(tib: *mut bgfx_transient_index_buffer_t, num: u32, index32: bool);
+            fn alloc_transient_vertex_buffer = bgfx_alloc_transient_vertex_buffer(tvb: *mut bgfx_transient_vertex_buffer_t, num: u32, layout: *const bgfx_vertex_layout_t);
+            fn alloc_transform = bgfx_alloc_transform(transform: *mut bgfx_transform_t, num: u16) -> u32;
+            fn copy = bgfx_copy(data: *const c_void, size: u32) -> *const bgfx_memory_t;
+            fn encoder_alloc_transform = bgfx_encoder_alloc_transform(this: *mut bgfx_encoder_t, transform: *mut bgfx_transform_t, num: u16) -> u32;
+            fn frame = bgfx_frame(capture: bool) -> u32;
+            fn get_avail_instance_data_buffer = bgfx_get_avail_instance_data_buffer(num: u32, stride: u16) -> u32;
+            fn get_avail_transient_index_buffer = bgfx_get_avail_transient_index_buffer(num: u32, index32: bool) -> u32;
+            fn get_avail_transient_vertex_buffer = bgfx_get_avail_transient_vertex_buffer(num: u32, layout: *const bgfx_vertex_layout_t) -> u32;
+            fn make_ref = bgfx_make_ref(data: *const c_void, size: u32) -> *const bgfx_memory_t;
+        }
+        recorded {
+            fn blit = bgfx_blit(id: bgfx_view_id_t, dst: bgfx_texture_handle_t, dst_mip: u8, dst_x: u16, dst_y: u16, dst_z: u16, src: bgfx_texture_handle_t, src_mip: u8, src_x: u16, src_y: u16, src_z: u16, width: u16, height: u16, depth: u16);
+            fn create_compute_program = bgfx_create_compute_program(csh: bgfx_shader_handle_t, destroy_shaders: bool) -> bgfx_program_handle_t;
+            fn create_dynamic_index_buffer = bgfx_create_dynamic_index_buffer(num: u32, flags: u16) -> bgfx_dynamic_index_buffer_handle_t;
+            fn create_dynamic_index_buffer_mem = bgfx_create_dynamic_index_buffer_mem(mem: *const bgfx_memory_t, flags: u16) -> bgfx_dynamic_index_buffer_handle_t;
+            fn create_dynamic_vertex_buffer = bgfx_create_dynamic_vertex_buffer(num: u32, layout: *const bgfx_vertex_layout_t, flags: u16) -> bgfx_dynamic_vertex_buffer_handle_t;
+            fn create_dynamic_vertex_buffer_mem = bgfx_create_dynamic_vertex_buffer_mem(mem: *const bgfx_memory_t, layout: *const bgfx_vertex_layout_t, flags: u16) -> bgfx_dynamic_vertex_buffer_handle_t;
+            fn create_frame_buffer = bgfx_create_frame_buffer(width: u16, height: u16, format: bgfx_texture_format_t, texture_flags: u64) -> bgfx_frame_buffer_handle_t;
+            fn create_frame_buffer_from_attachment = bgfx_create_frame_buffer_from_attachment(num: u8, attachment: *const bgfx_attachment_t, destroy_texture: bool) -> bgfx_frame_buffer_handle_t;
+            fn create_frame_buffer_from_handles = bgfx_create_frame_buffer_from_handles(num: u8, handles: *const bgfx_texture_handle_t, destroy_texture: bool) -> bgfx_frame_buffer_handle_t;
+            fn create_frame_buffer_from_nwh = bgfx_create_frame_buffer_from_nwh(nwh: *mut c_void, width: u16, height: u16, format: bgfx_texture_format_t, depth_format: bgfx_texture_format_t) -> bgfx_frame_buffer_handle_t;
+            fn create_frame_buffer_scaled = bgfx_create_frame_buffer_scaled(ratio: bgfx_backbuffer_ratio_t, format: bgfx_texture_format_t, texture_flags: u64) -> bgfx_frame_buffer_handle_t;
+            fn create_index_buffer = bgfx_create_index_buffer(mem: *const bgfx_memory_t, flags: u16) -> bgfx_index_buffer_handle_t;
+            fn create_indirect_buffer = bgfx_create_indirect_buffer(num: u32) -> bgfx_indirect_buffer_handle_t;
+            fn create_occlusion_query = bgfx_create_occlusion_query() -> bgfx_occlusion_query_handle_t;
+            fn create_program = bgfx_create_program(vsh: bgfx_shader_handle_t, fsh: bgfx_shader_handle_t, destroy_shaders: bool) -> bgfx_program_handle_t;
+            fn create_shader = bgfx_create_shader(mem: *const bgfx_memory_t) -> bgfx_shader_handle_t;
+            fn create_texture = bgfx_create_texture(mem: *const bgfx_memory_t, flags: u64, skip: u8, info: *mut bgfx_texture_info_t) -> bgfx_texture_handle_t;
+            fn create_texture_2d = bgfx_create_texture_2d(width: u16, height: u16, has_mips: bool, num_layers: u16, format: bgfx_texture_format_t, flags: u64, mem: *const bgfx_memory_t) -> bgfx_texture_handle_t;
+            fn create_texture_2d_scaled = bgfx_create_texture_2d_scaled(ratio: bgfx_backbuffer_ratio_t, has_mips: bool, num_layers: u16, format: bgfx_texture_format_t, flags: u64) -> bgfx_texture_handle_t;
+            fn create_texture_3d = bgfx_create_texture_3d(width: u16, height: u16, depth: u16, has_mips: bool, format: bgfx_texture_format_t, flags: u64, mem: *const bgfx_memory_t) -> bgfx_texture_handle_t;
+            fn create_texture_cube = bgfx_create_texture_cube(size: u16, has_mips: bool, num_layers: u16, format: bgfx_texture_format_t, flags: u64, mem: *const bgfx_memory_t) -> bgfx_texture_handle_t;
+            fn create_uniform = bgfx_create_uniform(name: *const c_char, type_: bgfx_uniform_type_t, num: u16) -> bgfx_uniform_handle_t;
+            fn create_vertex_buffer = bgfx_create_vertex_buffer(mem: *const bgfx_memory_t, layout: *const bgfx_vertex_layout_t, flags: u16) -> bgfx_vertex_buffer_handle_t;
+            fn create_vertex_layout = bgfx_create_vertex_layout(layout: *const bgfx_vertex_layout_t) -> bgfx_vertex_layout_handle_t;
+            fn dbg_text_clear = bgfx_dbg_text_clear(attr: u8, small: bool);
+            fn dbg_text_image = bgfx_dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: *const c_void, pitch: u16);
+            fn destroy_dynamic_index_buffer = bgfx_destroy_dynamic_index_buffer(handle: bgfx_dynamic_index_buffer_handle_t);
+            fn destroy_dynamic_vertex_buffer = bgfx_destroy_dynamic_vertex_buffer(handle: bgfx_dynamic_vertex_buffer_handle_t);
+            fn destroy_frame_buffer = bgfx_destroy_frame_buffer(handle: bgfx_frame_buffer_handle_t);
+            fn destroy_index_buffer = bgfx_destroy_index_buffer(handle: bgfx_index_buffer_handle_t);
+            fn destroy_indirect_buffer = bgfx_destroy_indirect_buffer(handle: bgfx_indirect_buffer_handle_t);
+            fn destroy_occlusion_query = bgfx_destroy_occlusion_query(handle: bgfx_occlusion_query_handle_t);
+            fn destroy_program = bgfx_destroy_program(handle: bgfx_program_handle_t);
+            fn destroy_shader = bgfx_destroy_shader(handle: bgfx_shader_handle_t);
+            fn destroy_texture = bgfx_destroy_texture(handle: bgfx_texture_handle_t);
+            fn destroy_uniform = bgfx_destroy_uniform(handle: bgfx_uniform_handle_t);
+            fn destroy_vertex_buffer = bgfx_destroy_vertex_buffer(handle: bgfx_vertex_buffer_handle_t);
+            fn destroy_vertex_layout = bgfx_destroy_vertex_layout(layout_handle: bgfx_vertex_layout_handle_t);
+            fn discard = bgfx_discard(flags: u8);
+            fn dispatch = bgfx_dispatch(id: bgfx_view_id_t, program: bgfx_program_handle_t, num_x: u32, num_y: u32, num_z: u32, flags: u8);
+            fn dispatch_indirect = bgfx_dispatch_indirect(id: bgfx_view_id_t, program: bgfx_program_handle_t, indirect_handle: bgfx_indirect_buffer_handle_t, start: u16, num: u16, flags: u8);
+            fn encoder_begin = bgfx_encoder_begin(for_thread: bool) -> *mut bgfx_encoder_t;
+            fn encoder_blit = bgfx_encoder_blit(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, dst: bgfx_texture_handle_t, dst_mip: u8, dst_x: u16, dst_y: u16, dst_z: u16, src: bgfx_texture_handle_t, src_mip: u8, src_x: u16, src_y: u16, src_z: u16, width: u16, height: u16, depth: u16);
+            fn encoder_discard = bgfx_encoder_discard(this: *mut bgfx_encoder_t, flags: u8);
+            fn encoder_dispatch = bgfx_encoder_dispatch(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, program: bgfx_program_handle_t, num_x: u32, num_y: u32, num_z: u32, flags: u8);
+            fn encoder_dispatch_indirect = bgfx_encoder_dispatch_indirect(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, program: bgfx_program_handle_t, indirect_handle: bgfx_indirect_buffer_handle_t, start: u16, num: u16, flags: u8);
+            fn encoder_end = bgfx_encoder_end(encoder: *mut bgfx_encoder_t);
+            fn encoder_set_compute_dynamic_index_buffer = bgfx_encoder_set_compute_dynamic_index_buffer(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_dynamic_index_buffer_handle_t, access: bgfx_access_t);
+            fn encoder_set_compute_dynamic_vertex_buffer = bgfx_encoder_set_compute_dynamic_vertex_buffer(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, access: bgfx_access_t);
+            fn encoder_set_compute_index_buffer = bgfx_encoder_set_compute_index_buffer(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_index_buffer_handle_t, access: bgfx_access_t);
+            fn encoder_set_compute_indirect_buffer = bgfx_encoder_set_compute_indirect_buffer(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_indirect_buffer_handle_t, access: bgfx_access_t);
+            fn encoder_set_compute_vertex_buffer = bgfx_encoder_set_compute_vertex_buffer(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_vertex_buffer_handle_t, access: bgfx_access_t);
+            fn encoder_set_condition = bgfx_encoder_set_condition(this: *mut bgfx_encoder_t, handle: bgfx_occlusion_query_handle_t, visible: bool);
+            fn encoder_set_dynamic_index_buffer = bgfx_encoder_set_dynamic_index_buffer(this: *mut bgfx_encoder_t, handle: bgfx_dynamic_index_buffer_handle_t, first_index: u32, num_indices: u32);
+            fn encoder_set_dynamic_vertex_buffer = bgfx_encoder_set_dynamic_vertex_buffer(this: *mut bgfx_encoder_t, stream: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32);
+            fn encoder_set_dynamic_vertex_buffer_with_layout = bgfx_encoder_set_dynamic_vertex_buffer_with_layout(this: *mut bgfx_encoder_t, stream: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn encoder_set_image = bgfx_encoder_set_image(this: *mut bgfx_encoder_t, stage: u8, handle: bgfx_texture_handle_t, mip: u8, access: bgfx_access_t, format: bgfx_texture_format_t);
+            fn encoder_set_index_buffer = bgfx_encoder_set_index_buffer(this: *mut bgfx_encoder_t, handle: bgfx_index_buffer_handle_t, first_index: u32, num_indices: u32);
+            fn encoder_set_instance_count = bgfx_encoder_set_instance_count(this: *mut bgfx_encoder_t, num_instances: u32);
+            fn encoder_set_instance_data_buffer = bgfx_encoder_set_instance_data_buffer(this: *mut bgfx_encoder_t, idb: *const bgfx_instance_data_buffer_t, start: u32, num: u32);
+            fn encoder_set_instance_data_from_dynamic_vertex_buffer = bgfx_encoder_set_instance_data_from_dynamic_vertex_buffer(this: *mut bgfx_encoder_t, handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num: u32);
+            fn encoder_set_instance_data_from_vertex_buffer = bgfx_encoder_set_instance_data_from_vertex_buffer(this: *mut bgfx_encoder_t, handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num: u32);
+            fn encoder_set_marker = bgfx_encoder_set_marker(this: *mut bgfx_encoder_t, marker: *const c_char);
+            fn encoder_set_scissor = bgfx_encoder_set_scissor(this: *mut bgfx_encoder_t, x: u16, y: u16, width: u16, height: u16) -> u16;
+            fn encoder_set_scissor_cached = bgfx_encoder_set_scissor_cached(this: *mut bgfx_encoder_t, cache: u16);
+            fn encoder_set_state = bgfx_encoder_set_state(this: *mut bgfx_encoder_t, state: u64, rgba: u32);
+            fn encoder_set_stencil = bgfx_encoder_set_stencil(this: *mut bgfx_encoder_t, fstencil: u32, bstencil: u32);
+            fn encoder_set_texture = bgfx_encoder_set_texture(this: *mut bgfx_encoder_t, stage: u8, sampler: bgfx_uniform_handle_t, handle: bgfx_texture_handle_t, flags: u32);
+            fn encoder_set_transient_index_buffer = bgfx_encoder_set_transient_index_buffer(this: *mut bgfx_encoder_t, tib: *const bgfx_transient_index_buffer_t, first_index: u32, num_indices: u32);
+            fn encoder_set_transient_vertex_buffer = bgfx_encoder_set_transient_vertex_buffer(this: *mut bgfx_encoder_t, stream: u8, tvb: *const bgfx_transient_vertex_buffer_t, start_vertex: u32, num_vertices: u32);
+            fn encoder_set_transient_vertex_buffer_with_layout = bgfx_encoder_set_transient_vertex_buffer_with_layout(this: *mut bgfx_encoder_t, stream: u8, tvb: *const bgfx_transient_vertex_buffer_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn encoder_set_uniform = bgfx_encoder_set_uniform(this: *mut bgfx_encoder_t, handle: bgfx_uniform_handle_t, value: *const c_void, num: u16);
+            fn encoder_set_vertex_buffer = bgfx_encoder_set_vertex_buffer(this: *mut bgfx_encoder_t, stream: u8, handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32);
+            fn encoder_set_vertex_buffer_with_layout = bgfx_encoder_set_vertex_buffer_with_layout(this: *mut bgfx_encoder_t, stream: u8, handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn encoder_set_vertex_count = bgfx_encoder_set_vertex_count(this: *mut bgfx_encoder_t, num_vertices: u32);
+            fn encoder_submit = bgfx_encoder_submit(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, program: bgfx_program_handle_t, depth: u32, flags: u8);
+            fn encoder_submit_indirect = bgfx_encoder_submit_indirect(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, program: bgfx_program_handle_t, indirect_handle: bgfx_indirect_buffer_handle_t, start: u16, num: u16, depth: u32, flags: u8);
+            fn encoder_submit_occlusion_query = bgfx_encoder_submit_occlusion_query(this: *mut bgfx_encoder_t, id: bgfx_view_id_t, program: bgfx_program_handle_t, occlusion_query: bgfx_occlusion_query_handle_t, depth: u32, flags: u8);
+            fn encoder_touch = bgfx_encoder_touch(this: *mut bgfx_encoder_t, id: bgfx_view_id_t);
+            fn get_caps = bgfx_get_caps() -> *const bgfx_caps_t;
+            fn get_internal_data = bgfx_get_internal_data() -> *const bgfx_internal_data_t;
+            fn get_renderer_type = bgfx_get_renderer_type() -> bgfx_renderer_type_t;
+            fn get_result = bgfx_get_result(handle: bgfx_occlusion_query_handle_t, result: *mut i32) -> bgfx_occlusion_query_result_t;
+            fn get_stats = bgfx_get_stats() -> *const bgfx_stats_t;
+            fn get_texture = bgfx_get_texture(handle: bgfx_frame_buffer_handle_t, attachment: u8) -> bgfx_texture_handle_t;
+            fn get_uniform_info = bgfx_get_uniform_info(handle: bgfx_uniform_handle_t, info: *mut bgfx_uniform_info_t);
+            fn init = bgfx_init(init: *const bgfx_init_t) -> bool;
+            fn is_frame_buffer_valid = bgfx_is_frame_buffer_valid(num: u8, attachment: *const bgfx_attachment_t) -> bool;
+            fn is_texture_valid = bgfx_is_texture_valid(depth: u16, cube_map: bool, num_layers: u16, format: bgfx_texture_format_t, flags: u64) -> bool;
+            fn render_frame = bgfx_render_frame(msecs: i32) -> bgfx_render_frame_t;
+            fn request_screen_shot = bgfx_request_screen_shot(handle: bgfx_frame_buffer_handle_t, file_path: *const c_char);
+            fn reset = bgfx_reset(width: u32, height: u32, flags: u32, format: bgfx_texture_format_t);
+            fn reset_view = bgfx_reset_view(id: bgfx_view_id_t);
+            fn set_compute_dynamic_index_buffer = bgfx_set_compute_dynamic_index_buffer(stage: u8, handle: bgfx_dynamic_index_buffer_handle_t, access: bgfx_access_t);
+            fn set_compute_dynamic_vertex_buffer = bgfx_set_compute_dynamic_vertex_buffer(stage: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, access: bgfx_access_t);
+            fn set_compute_index_buffer = bgfx_set_compute_index_buffer(stage: u8, handle: bgfx_index_buffer_handle_t, access: bgfx_access_t);
+            fn set_compute_indirect_buffer = bgfx_set_compute_indirect_buffer(stage: u8, handle: bgfx_indirect_buffer_handle_t, access: bgfx_access_t);
+            fn set_compute_vertex_buffer = bgfx_set_compute_vertex_buffer(stage: u8, handle: bgfx_vertex_buffer_handle_t, access: bgfx_access_t);
+            fn set_condition = bgfx_set_condition(handle: bgfx_occlusion_query_handle_t, visible: bool);
+            fn set_debug = bgfx_set_debug(debug: u32);
+            fn set_dynamic_index_buffer = bgfx_set_dynamic_index_buffer(handle: bgfx_dynamic_index_buffer_handle_t, first_index: u32, num_indices: u32);
+            fn set_dynamic_vertex_buffer = bgfx_set_dynamic_vertex_buffer(stream: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32);
+            fn set_dynamic_vertex_buffer_with_layout = bgfx_set_dynamic_vertex_buffer_with_layout(stream: u8, handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn set_frame_buffer_name = bgfx_set_frame_buffer_name(handle: bgfx_frame_buffer_handle_t, name: *const c_char, len: i32);
+            fn set_image = bgfx_set_image(stage: u8, handle: bgfx_texture_handle_t, mip: u8, access: bgfx_access_t, format: bgfx_texture_format_t);
+            fn set_index_buffer = bgfx_set_index_buffer(handle: bgfx_index_buffer_handle_t, first_index: u32, num_indices: u32);
+            fn set_index_buffer_name = bgfx_set_index_buffer_name(handle: bgfx_index_buffer_handle_t, name: *const c_char, len: i32);
+            fn set_instance_count = bgfx_set_instance_count(num_instances: u32);
+            fn set_instance_data_buffer = bgfx_set_instance_data_buffer(idb: *const bgfx_instance_data_buffer_t, start: u32, num: u32);
+            fn set_instance_data_from_dynamic_vertex_buffer = bgfx_set_instance_data_from_dynamic_vertex_buffer(handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, num: u32);
+            fn set_instance_data_from_vertex_buffer = bgfx_set_instance_data_from_vertex_buffer(handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num: u32);
+            fn set_marker = bgfx_set_marker(marker: *const c_char);
+            fn set_platform_data = bgfx_set_platform_data(data: *const bgfx_platform_data_t);
+            fn set_scissor = bgfx_set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16;
+            fn set_scissor_cached = bgfx_set_scissor_cached(cache: u16);
+            fn set_shader_name = bgfx_set_shader_name(handle: bgfx_shader_handle_t, name: *const c_char, len: i32);
+            fn set_state = bgfx_set_state(state: u64, rgba: u32);
+            fn set_stencil = bgfx_set_stencil(fstencil: u32, bstencil: u32);
+            fn set_texture = bgfx_set_texture(stage: u8, sampler: bgfx_uniform_handle_t, handle: bgfx_texture_handle_t, flags: u32);
+            fn set_texture_name = bgfx_set_texture_name(handle: bgfx_texture_handle_t, name: *const c_char, len: i32);
+            fn set_transform = bgfx_set_transform(mtx: *const c_void, num: u16) -> u32;
+            fn set_transient_index_buffer = bgfx_set_transient_index_buffer(tib: *const bgfx_transient_index_buffer_t, first_index: u32, num_indices: u32);
+            fn set_transient_vertex_buffer = bgfx_set_transient_vertex_buffer(stream: u8, tvb: *const bgfx_transient_vertex_buffer_t, start_vertex: u32, num_vertices: u32);
+            fn set_transient_vertex_buffer_with_layout = bgfx_set_transient_vertex_buffer_with_layout(stream: u8, tvb: *const bgfx_transient_vertex_buffer_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn set_uniform = bgfx_set_uniform(handle: bgfx_uniform_handle_t, value: *const c_void, num: u16);
+            fn set_vertex_buffer = bgfx_set_vertex_buffer(stream: u8, handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32);
+            fn set_vertex_buffer_name = bgfx_set_vertex_buffer_name(handle: bgfx_vertex_buffer_handle_t, name: *const c_char, len: i32);
+            fn set_vertex_buffer_with_layout = bgfx_set_vertex_buffer_with_layout(stream: u8, handle: bgfx_vertex_buffer_handle_t, start_vertex: u32, num_vertices: u32, layout_handle: bgfx_vertex_layout_handle_t);
+            fn set_vertex_count = bgfx_set_vertex_count(num_vertices: u32);
+            fn set_view_clear = bgfx_set_view_clear(id: bgfx_view_id_t, flags: u16, rgba: u32, depth: f32, stencil: u8);
+            fn set_view_clear_mrt = bgfx_set_view_clear_mrt(id: bgfx_view_id_t, flags: u16, depth: f32, stencil: u8, c0: u8, c1: u8, c2: u8, c3: u8, c4: u8, c5: u8, c6: u8, c7: u8);
+            fn set_view_frame_buffer = bgfx_set_view_frame_buffer(id: bgfx_view_id_t, handle: bgfx_frame_buffer_handle_t);
+            fn set_view_mode = bgfx_set_view_mode(id: bgfx_view_id_t, mode: bgfx_view_mode_t);
+            fn set_view_rect = bgfx_set_view_rect(id: bgfx_view_id_t, x: u16, y: u16, width: u16, height: u16);
+            fn set_view_rect_ratio = bgfx_set_view_rect_ratio(id: bgfx_view_id_t, x: u16, y: u16, ratio: bgfx_backbuffer_ratio_t);
+            fn set_view_scissor = bgfx_set_view_scissor(id: bgfx_view_id_t, x: u16, y: u16, width: u16, height: u16);
+            fn set_view_transform = bgfx_set_view_transform(id: bgfx_view_id_t, view: *const c_void, proj: *const c_void);
+            fn shutdown = bgfx_shutdown();
+            fn submit = bgfx_submit(id: bgfx_view_id_t, program: bgfx_program_handle_t, depth: u32, flags: u8);
+            fn submit_indirect = bgfx_submit_indirect(id: bgfx_view_id_t, program: bgfx_program_handle_t, indirect_handle: bgfx_indirect_buffer_handle_t, start: u16, num: u16, depth: u32, flags: u8);
+            fn submit_occlusion_query = bgfx_submit_occlusion_query(id: bgfx_view_id_t, program: bgfx_program_handle_t, occlusion_query: bgfx_occlusion_query_handle_t, depth: u32, flags: u8);
+            fn touch = bgfx_touch(id: bgfx_view_id_t);
+            fn update_dynamic_index_buffer = bgfx_update_dynamic_index_buffer(handle: bgfx_dynamic_index_buffer_handle_t, start_index: u32, mem: *const bgfx_memory_t);
+            fn update_dynamic_vertex_buffer = bgfx_update_dynamic_vertex_buffer(handle: bgfx_dynamic_vertex_buffer_handle_t, start_vertex: u32, mem: *const bgfx_memory_t);
+            fn update_texture_2d = bgfx_update_texture_2d(handle: bgfx_texture_handle_t, layer: u16, mip: u8, x: u16, y: u16, width: u16, height: u16, mem: *const bgfx_memory_t, pitch: u16);
+            fn update_texture_3d = bgfx_update_texture_3d(handle: bgfx_texture_handle_t, mip: u8, x: u16, y: u16, z: u16, width: u16, height: u16, depth: u16, mem: *const bgfx_memory_t);
+            fn update_texture_cube = bgfx_update_texture_cube(handle: bgfx_texture_handle_t, layer: u16, side: u8, mip: u8, x: u16, y: u16, width: u16, height: u16, mem: *const bgfx_memory_t, pitch: u16);
+        }
+        }
+    };
+}
+pub(crate) use for_each_function;
+
+/// The bgfx-sys function, the entry of the interface loaded by `shared_api` with the
+/// `shared-api` feature, or its stand-in in `unlinked` with the `mock-only` feature.
+#[cfg(not(any(feature = "shared-api", feature = "mock-only")))]
+macro_rules! ffi {
+    ($name:ident, $ffi:ident) => {
+        bgfx_sys::$ffi
+    };
+}
+
+#[cfg(feature = "shared-api")]
+macro_rules! ffi {
+    ($name:ident, $ffi:ident) => {
+        crate::shared_api::vtbl()
+            .$name
+            .expect(concat!(stringify!($ffi), " is missing from the bgfx interface"))
+    };
+}
+
+#[cfg(feature = "mock-only")]
+macro_rules! ffi {
+    ($name:ident, $ffi:ident) => {
+        crate::backend::unlinked::$ffi
+    };
+}
+
+/// Stand-ins for the bgfx-sys functions with the `mock-only` feature, which doesn't build bgfx.
+/// The pure functions are the Rust ones, the others panic, only a backend like
+/// [MockBackend](crate::MockBackend) that doesn't forward them can be used.
+#[cfg(feature = "mock-only")]
+mod unlinked {
+    use std::os::raw::{c_char, c_void};
+
+    use bgfx_sys::*;
+
+    macro_rules! unlinked {
+        (
+            pure { $(fn $pure:ident = $pure_ffi:ident($($pure_arg:ident: $pure_ty:ty),*) $(-> $pure_ret:ty)?;)* }
+            $($group:ident { $(fn $name:ident = $ffi:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)* })*
+        ) => {
+            $(pub(crate) use crate::pure::$pure as $pure_ffi;)*
+
+            $($(
+                #[allow(clippy::too_many_arguments)]
+                pub(crate) unsafe fn $ffi($(_: $ty),*) $(-> $ret)? {
+                    not_linked(stringify!($ffi))
+                }
+            )*)*
+        };
+    }
+
+    for_each_function!(unlinked);
+
+    pub(crate) unsafe fn bgfx_dbg_text_printf(
+        _: u16,
+        _: u16,
+        _: u8,
+        _: *const c_char,
+        _: *const c_char,
+    ) {
+        not_linked("bgfx_dbg_text_printf")
+    }
+
+    fn not_linked(function: &str) -> ! {
+        panic!(
+            "{} was called but bgfx-rs is built with the mock-only feature, which has no bgfx. \
+             Install a MockBackend with set_backend first",
+            function
+        )
+    }
+}
+
+macro_rules! backend {
+    ($($group:ident { $(fn $name:ident = $ffi:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)* })*) => {
+        /// Implementation of the bgfx C API that every call of this crate goes through.
+        ///
+        /// Each method defaults to forwarding to [Backend::inner], or to the bgfx-sys function of
+        /// the same name without one, so an implementation only overrides what it wants to
+        /// intercept. [FfiBackend] keeps all defaults and is used unless [set_backend] installed
+        /// something else, f.e. a [MockBackend](crate::MockBackend) in tests.
+        ///
+        /// # Safety
+        ///
+        /// The methods take the raw arguments of the C API, pointers have to be valid like for
+        /// the bgfx-sys functions.
+        pub trait Backend {
+            /// The backend wrapped by this one, f.e. by a [Recorder](crate::Recorder).
+            fn inner(&self) -> Option<&dyn Backend> {
+                None
+            }
+
+            $($(
+                #[allow(clippy::too_many_arguments, clippy::missing_safety_doc)]
+                unsafe fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                    match self.inner() {
+                        Some(inner) => inner.$name($($arg),*),
+                        None => (ffi!($name, $ffi))($($arg),*),
+                    }
+                }
+            )*)*
+
+            /// `bgfx_dbg_text_printf` with the text already formatted.
+            fn dbg_text(&self, x: u16, y: u16, attr: u8, text: &CStr) {
+                match self.inner() {
+                    Some(inner) => inner.dbg_text(x, y, attr, text),
+                    None => unsafe {
+                        (ffi!(dbg_text_printf, bgfx_dbg_text_printf))(
+                            x,
+                            y,
+                            attr,
+                            c"%s".as_ptr(),
+                            text.as_ptr(),
+                        );
+                    },
+                }
+            }
+        }
+
+        $($(
+            #[allow(clippy::too_many_arguments)]
+            #[inline]
+            pub(crate) unsafe fn $ffi($($arg: $ty),*) $(-> $ret)? {
+                with_backend(|backend| backend.$name($($arg),*))
+            }
+        )*)*
+    };
+}
+
+for_each_function!(backend);
+
+pub(crate) fn bgfx_dbg_text(x: u16, y: u16, attr: u8, text: &CStr) {
+    with_backend(|backend| backend.dbg_text(x, y, attr, text))
+}
+
+/// The real bgfx, calling straight into bgfx-sys, or into the library loaded with
+/// `shared_api::load` with the `shared-api` feature.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct FfiBackend;
+
+impl Backend for FfiBackend {}
+
+thread_local! {
+    static BACKEND: RefCell<Option<Rc<dyn Backend>>> = const { RefCell::new(None) };
+}
+
+fn with_backend<R>(f: impl FnOnce(&dyn Backend) -> R) -> R {
+    BACKEND.with(|backend| match &*backend.borrow() {
+        Some(backend) => f(&**backend),
+        None => f(&FfiBackend),
+    })
+}
+
+/// Routes the calls made on the current thread through `backend` until the returned guard is
+/// dropped, which restores the previous backend.
+///
+/// The backend is per thread, so tests running in parallel each get their own. Encoders used on
+/// other threads keep going to the backend of those threads.
+///
+/// ```ignore
+/// let mock = Rc::new(MockBackend::new());
+/// let _guard = set_backend(mock.clone());
+///
+/// draw_scene();
+/// assert!(mock.submits().iter().any(|submit| submit.view == 3));
+/// ```
+pub fn set_backend(backend: Rc<dyn Backend>) -> BackendGuard {
+    let previous = BACKEND.with(|current| current.borrow_mut().replace(backend));
+    BackendGuard { previous }
+}
+
+/// Restores the previous backend when dropped, see [set_backend].
+#[must_use = "the backend is reset when the guard is dropped"]
+pub struct BackendGuard {
+    previous: Option<Rc<dyn Backend>>,
+}
+
+impl Drop for BackendGuard {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        BACKEND.with(|current| *current.borrow_mut() = previous);
+    }
+}
@@ -0,0 +1,245 @@
+use std::fmt;
+
+use crate::bgfx::{Caps, CapsFlags, CapsFormatFlags, CapsLimits, PciIdFlags, TextureFormat, GPU};
+
+impl Caps {
+    /// Returns true if all of `flags` are supported.
+    ///
+    /// ```ignore
+    /// if get_caps().supports(CapsFlags::COMPUTE | CapsFlags::DRAW_INDIRECT) { ... }
+    /// ```
+    pub fn supports(&self, flags: CapsFlags) -> bool {
+        self.supported & flags.bits() == flags.bits()
+    }
+
+    /// Returns [Caps::supported] as flags.
+    pub fn supported_flags(&self) -> CapsFlags {
+        CapsFlags::from_bits_truncate(self.supported)
+    }
+
+    /// Returns what `format` can be used for.
+    ///
+    /// ```ignore
+    /// let msaa_hdr = get_caps()
+    ///     .format_caps(TextureFormat::RGBA16F)
+    ///     .contains(CapsFormatFlags::TEXTURE_FRAMEBUFFER_MSAA);
+    /// ```
+    pub fn format_caps(&self, format: TextureFormat) -> CapsFormatFlags {
+        match self.formats.get(format as usize) {
+            Some(caps) => CapsFormatFlags::from_bits_truncate(*caps as u32),
+            None => CapsFormatFlags::TEXTURE_NONE,
+        }
+    }
+
+    /// Returns true if `format` supports all of `flags`.
+    pub fn supports_format(&self, format: TextureFormat, flags: CapsFormatFlags) -> bool {
+        self.format_caps(format).contains(flags)
+    }
+
+    /// Iterates over the formats and their capabilities, skipping unsupported formats.
+    pub fn supported_formats(&self) -> impl Iterator<Item = (TextureFormat, CapsFormatFlags)> + '_ {
+        TextureFormat::all()
+            .map(move |format| (format, self.format_caps(format)))
+            .filter(|(_, caps)| !caps.is_empty())
+    }
+
+    /// Enumerated GPUs.
+    pub fn gpus(&self) -> impl Iterator<Item = &GPU> {
+        self.gpu.iter().take(self.num_gp_us as usize)
+    }
+
+    /// Returns the GPU bgfx selected, if it was enumerated.
+    pub fn selected_gpu(&self) -> Option<&GPU> {
+        self.gpus()
+            .find(|gpu| gpu.vendor_id == self.vendor_id && gpu.device_id == self.device_id)
+    }
+}
+
+impl GPU {
+    /// Vendor name for the PCI ids in [PciIdFlags].
+    pub fn vendor_name(&self) -> Option<&'static str> {
+        vendor_name(self.vendor_id)
+    }
+}
+
+fn vendor_name(vendor_id: u16) -> Option<&'static str> {
+    match vendor_id {
+        id if id == PciIdFlags::SOFTWARE_RASTERIZER.bits() => Some("Software rasterizer"),
+        id if id == PciIdFlags::AMD.bits() => Some("AMD"),
+        id if id == PciIdFlags::INTEL.bits() => Some("Intel"),
+        id if id == PciIdFlags::NVIDIA.bits() => Some("NVIDIA"),
+        _ => None,
+    }
+}
+
+struct PciId(u16, u16);
+
+impl fmt::Display for PciId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:04x}:{:04x}", self.0, self.1)?;
+        if let Some(name) = vendor_name(self.0) {
+            write!(f, " ({})", name)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for GPU {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", PciId(self.vendor_id, self.device_id))
+    }
+}
+
+macro_rules! limits {
+    ($($field:ident),* $(,)?) => {
+        impl CapsLimits {
+            /// Name and value of every limit.
+            pub fn iter(&self) -> impl Iterator<Item = (&'static str, u32)> {
+                vec![$((stringify!($field), self.$field)),*].into_iter()
+            }
+        }
+    };
+}
+
+limits!(
+    max_draw_calls,
+    max_blits,
+    max_texture_size,
+    max_texture_layers,
+    max_views,
+    max_frame_buffers,
+    max_fb_attachments,
+    max_programs,
+    max_shaders,
+    max_textures,
+    max_texture_samplers,
+    max_compute_bindings,
+    max_vertex_layouts,
+    max_vertex_streams,
+    max_index_buffers,
+    max_vertex_buffers,
+    max_dynamic_index_buffers,
+    max_dynamic_vertex_buffers,
+    max_uniforms,
+    max_occlusion_queries,
+    max_encoders,
+    min_resource_cb_size,
+    transient_vb_size,
+    transient_ib_size,
+);
+
+impl fmt::Debug for CapsLimits {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut s = f.debug_struct("CapsLimits");
+        for (name, value) in self.iter() {
+            s.field(name, &value);
+        }
+        s.finish()
+    }
+}
+
+/// Multi-line, human readable dump, f.e. for a log at startup.
+impl fmt::Display for Caps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Renderer: {:?}", self.renderer_type)?;
+        writeln!(f, "GPU: {}", PciId(self.vendor_id, self.device_id))?;
+        for (i, gpu) in self.gpus().enumerate() {
+            writeln!(f, "  [{}] {:?}", i, gpu)?;
+        }
+        writeln!(f, "Homogeneous depth: {}", self.homogeneous_depth)?;
+        writeln!(f, "Origin bottom left: {}", self.origin_bottom_left)?;
+        writeln!(f, "Supported: {:?}", self.supported_flags())?;
+
+        writeln!(f, "Limits:")?;
+        for (name, value) in self.limits.iter() {
+            writeln!(f, "  {}: {}", name, value)?;
+        }
+
+        writeln!(f, "Formats:")?;
+        for (format, caps) in self.supported_formats() {
+            writeln!(f, "  {:?}: {:?}", format, caps)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Caps {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Caps")
+            .field("renderer_type", &self.renderer_type)
+            .field("supported", &self.supported_flags())
+            .field("gpu", &PciId(self.vendor_id, self.device_id).to_string())
+            .field("gpus", &self.gpus().collect::<Vec<_>>())
+            .field("homogeneous_depth", &self.homogeneous_depth)
+            .field("origin_bottom_left", &self.origin_bottom_left)
+            .field("limits", &self.limits)
+            .field("formats", &self.supported_formats().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use serde::ser::{SerializeMap, SerializeStruct};
+    use serde::{Serialize, Serializer};
+
+    use crate::bgfx::{Caps, CapsLimits, GPU};
+
+    /// Flag names of a bitflags value, f.e. `["COMPUTE", "INSTANCING"]`.
+    fn flag_names<T: std::fmt::Debug>(flags: &T) -> Vec<String> {
+        format!("{:?}", flags)
+            .split(" | ")
+            .filter(|name| !name.is_empty() && *name != "(empty)")
+            .map(str::to_owned)
+            .collect()
+    }
+
+    impl Serialize for GPU {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("GPU", 3)?;
+            s.serialize_field("vendor_id", &self.vendor_id)?;
+            s.serialize_field("device_id", &self.device_id)?;
+            s.serialize_field("vendor_name", &self.vendor_name())?;
+            s.end()
+        }
+    }
+
+    impl Serialize for CapsLimits {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(None)?;
+            for (name, value) in self.iter() {
+                map.serialize_entry(name, &value)?;
+            }
+            map.end()
+        }
+    }
+
+    struct Formats<'a>(&'a Caps);
+
+    impl Serialize for Formats<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(None)?;
+            for (format, caps) in self.0.supported_formats() {
+                map.serialize_entry(&format!("{:?}", format), &flag_names(&caps))?;
+            }
+            map.end()
+        }
+    }
+
+    /// Serializes flags and enums by name.
+    impl Serialize for Caps {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Caps", 9)?;
+            s.serialize_field("renderer_type", &format!("{:?}", self.renderer_type))?;
+            s.serialize_field("supported", &flag_names(&self.supported_flags()))?;
+            s.serialize_field("vendor_id", &self.vendor_id)?;
+            s.serialize_field("device_id", &self.device_id)?;
+            s.serialize_field("homogeneous_depth", &self.homogeneous_depth)?;
+            s.serialize_field("origin_bottom_left", &self.origin_bottom_left)?;
+            s.serialize_field("gpus", &self.gpus().collect::<Vec<_>>())?;
+            s.serialize_field("limits", &self.limits)?;
+            s.serialize_field("formats", &Formats(self))?;
+            s.end()
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use std::f32::consts::PI;
+
+use crate::bgfx::TextureFormat;
+use crate::texture::{Image, TextureError};
+
+/// Arrangement of the cube faces in a 2D source image.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum CubemapLayout {
+    /// Equirectangular (lat-long) panorama, 2:1.
+    LatLong,
+    /// Faces +X, -X, +Y, -Y, +Z, -Z next to each other, 6:1.
+    Strip,
+    /// Horizontal cross, 4:3. `+Y` above and `-Y` below `+Z`, the middle row is `-X +Z +X -Z`.
+    HorizontalCross,
+    /// Vertical cross, 3:4. Like [CubemapLayout::HorizontalCross] with `-Z` below `-Y`,
+    /// rotated by 180 degrees.
+    VerticalCross,
+}
+
+impl CubemapLayout {
+    /// Guesses the layout from the aspect ratio of the image.
+    pub fn detect(width: u32, height: u32) -> Option<CubemapLayout> {
+        [
+            CubemapLayout::LatLong,
+            CubemapLayout::Strip,
+            CubemapLayout::HorizontalCross,
+            CubemapLayout::VerticalCross,
+        ]
+        .into_iter()
+        .find(|layout| layout.fits(width, height))
+    }
+
+    /// Returns true if an image of `width` x `height` has the aspect ratio of the layout.
+    pub fn fits(self, width: u32, height: u32) -> bool {
+        let (x, y) = match self {
+            CubemapLayout::LatLong => (2, 1),
+            CubemapLayout::Strip => (6, 1),
+            CubemapLayout::HorizontalCross => (4, 3),
+            CubemapLayout::VerticalCross => (3, 4),
+        };
+        width != 0 && width as u64 * y == height as u64 * x
+    }
+}
+
+/// BRDF used by [Cubemap::radiance] to pick the specular lobe of each mip.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum LightingModel {
+    Phong,
+    PhongBrdf,
+    Blinn,
+    BlinnBrdf,
+    Ggx,
+}
+
+/// RGBA32F cubemap used as source for image-based lighting.
+///
+/// ```ignore
+/// let sky = Cubemap::load(&std::fs::read("sky.hdr")?)?;
+///
+/// let (radiance, _) = sky.radiance(LightingModel::Ggx)?.create_texture(0)?;
+/// let (irradiance, _) = sky.irradiance(32)?.create_texture(0)?;
+/// ```
+#[derive(Debug)]
+pub struct Cubemap {
+    image: Image,
+}
+
+impl Cubemap {
+    /// Parses any format supported by [Image::parse]. Cubemap containers (DDS, KTX) are used as
+    /// they are, 2D images are converted according to [CubemapLayout::detect].
+    pub fn load(data: &[u8]) -> Result<Cubemap, TextureError> {
+        let image = Image::parse_as(data, TextureFormat::RGBA32F)?;
+
+        if image.is_cube_map() {
+            return Cubemap::from_cube(image);
+        }
+
+        let layout = CubemapLayout::detect(image.width(), image.height()).ok_or_else(|| {
+            TextureError::InvalidCubemap(format!(
+                "can't detect cubemap layout of a {}x{} image",
+                image.width(),
+                image.height()
+            ))
+        })?;
+        Cubemap::from_image(&image, layout)
+    }
+
+    /// Converts a 2D image with the given layout into a cubemap.
+    pub fn from_image(image: &Image, layout: CubemapLayout) -> Result<Cubemap, TextureError> {
+        if !layout.fits(image.width(), image.height()) {
+            return Err(TextureError::InvalidCubemap(format!(
+                "a {}x{} image doesn't have the {:?} layout",
+                image.width(),
+                image.height(),
+                layout
+            )));
+        }
+
+        let rgba32f;
+        let image = if image.format()? == TextureFormat::RGBA32F {
+            image
+        } else {
+            rgba32f = image.convert(TextureFormat::RGBA32F)?;
+            &rgba32f
+        };
+
+        let cube = match layout {
+            CubemapLayout::LatLong => {
+                Image::try_create(TextureError::InvalidCubemap, |message, max| unsafe {
+                    bgfx_sys::bimg_image_cubemap_from_lat_long_rgba32f(
+                        image.as_ptr(),
+                        true,
+                        message,
+                        max,
+                    )
+                })?
+            }
+            CubemapLayout::Strip => {
+                Image::try_create(TextureError::InvalidCubemap, |message, max| unsafe {
+                    bgfx_sys::bimg_image_cubemap_from_strip_rgba32f(image.as_ptr(), message, max)
+                })?
+            }
+            CubemapLayout::HorizontalCross | CubemapLayout::VerticalCross => {
+                from_cross(image, layout)?
+            }
+        };
+
+        Ok(Cubemap { image: cube })
+    }
+
+    /// Uses an existing cubemap image, converting it to RGBA32F if needed.
+    pub fn from_cube(image: Image) -> Result<Cubemap, TextureError> {
+        if !image.is_cube_map() {
+            return Err(TextureError::InvalidCubemap(
+                "the image has no cube faces".to_owned(),
+            ));
+        }
+
+        let image = if image.format()? == TextureFormat::RGBA32F {
+            image
+        } else {
+            image.convert(TextureFormat::RGBA32F)?
+        };
+
+        Ok(Cubemap { image })
+    }
+
+    /// Size of a face in texels.
+    pub fn size(&self) -> u32 {
+        self.image.width()
+    }
+
+    /// The RGBA32F cubemap, ready for `create_texture_cube` or [Image::create_texture].
+    pub fn image(&self) -> &Image {
+        &self.image
+    }
+
+    pub fn into_image(self) -> Image {
+        self.image
+    }
+
+    /// Prefilters the cubemap for specular image-based lighting. Returns a RGBA32F cubemap with
+    /// a full mip chain, roughness increasing from 0 at mip 0 to 1 at the last mip.
+    pub fn radiance(&self, model: LightingModel) -> Result<Image, TextureError> {
+        Image::try_create(TextureError::InvalidCubemap, |message, max| unsafe {
+            bgfx_sys::bimg_image_cubemap_radiance_filter(
+                self.image.as_ptr(),
+                model as u32,
+                message,
+                max,
+            )
+        })
+    }
+
+    /// Convolves the cubemap for diffuse image-based lighting. Returns a RGBA32F cubemap with
+    /// faces of `size` texels holding irradiance divided by pi, so it can be multiplied with
+    /// the albedo directly.
+    ///
+    /// The convolution is done with order 2 spherical harmonics, which is exact enough for
+    /// diffuse lighting and a lot faster than integrating per texel.
+    pub fn irradiance(&self, size: u16) -> Result<Image, TextureError> {
+        let sh = self.project_sh();
+
+        let mut data = Vec::with_capacity(size as usize * size as usize * 6 * 16);
+        for face in 0..6 {
+            for y in 0..size as u32 {
+                for x in 0..size as u32 {
+                    let (u, v) = texel_uv(x, y, size as u32);
+                    let dir = normalize(face_dir(face, u, v));
+                    let basis = sh_basis(dir);
+
+                    let mut irradiance = [0.0f32; 3];
+                    for (i, coefficient) in sh.iter().enumerate() {
+                        let band = SH_BAND_CONVOLUTION[i] / PI;
+                        for c in 0..3 {
+                            irradiance[c] += coefficient[c] * basis[i] * band;
+                        }
+                    }
+
+                    for value in irradiance.iter().chain(&[1.0]) {
+                        data.extend_from_slice(&value.max(0.0).to_ne_bytes());
+                    }
+                }
+            }
+        }
+
+        Image::from_data(TextureFormat::RGBA32F, size, size, 1, 1, true, false, &data)
+    }
+
+    /// Projects the top mip onto the first 9 spherical harmonics coefficients.
+    fn project_sh(&self) -> [[f32; 3]; 9] {
+        let size = self.size();
+        let data = self.image.data();
+        let face_stride = data.len() / (6 * self.image.num_layers() as usize);
+
+        let mut sh = [[0.0f32; 3]; 9];
+        let mut weight_sum = 0.0;
+
+        for face in 0..6 {
+            let face_data = &data[face as usize * face_stride..];
+
+            for y in 0..size {
+                for x in 0..size {
+                    let (u, v) = texel_uv(x, y, size);
+                    let dir = normalize(face_dir(face, u, v));
+                    let weight = texel_solid_angle(u, v, size);
+                    let basis = sh_basis(dir);
+
+                    let offset = ((y * size + x) * 16) as usize;
+                    let texel = read_rgb(&face_data[offset..offset + 12]);
+
+                    for (i, coefficient) in sh.iter_mut().enumerate() {
+                        for c in 0..3 {
+                            coefficient[c] += texel[c] * basis[i] * weight;
+                        }
+                    }
+                    weight_sum += weight;
+                }
+            }
+        }
+
+        // The texel solid angles don't add up to exactly 4 pi.
+        let normalize = 4.0 * PI / weight_sum;
+        for coefficient in sh.iter_mut() {
+            for c in coefficient.iter_mut() {
+                *c *= normalize;
+            }
+        }
+
+        sh
+    }
+}
+
+/// Cosine lobe convolution factor of each SH coefficient (bands 0, 1 and 2).
+const SH_BAND_CONVOLUTION: [f32; 9] = [
+    PI,
+    2.0 * PI / 3.0,
+    2.0 * PI / 3.0,
+    2.0 * PI / 3.0,
+    PI / 4.0,
+    PI / 4.0,
+    PI / 4.0,
+    PI / 4.0,
+    PI / 4.0,
+];
+
+fn sh_basis([x, y, z]: [f32; 3]) -> [f32; 9] {
+    [
+        0.282_095,
+        0.488_603 * y,
+        0.488_603 * z,
+        0.488_603 * x,
+        1.092_548 * x * y,
+        1.092_548 * y * z,
+        0.315_392 * (3.0 * z * z - 1.0),
+        1.092_548 * x * z,
+        0.546_274 * (x * x - y * y),
+    ]
+}
+
+/// Texel center in [-1, 1] face coordinates.
+fn texel_uv(x: u32, y: u32, size: u32) -> (f32, f32) {
+    let u = (x as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+    let v = (y as f32 + 0.5) / size as f32 * 2.0 - 1.0;
+    (u, v)
+}
+
+fn texel_solid_angle(u: f32, v: f32, size: u32) -> f32 {
+    let texel_area = 4.0 / (size as f32 * size as f32);
+    texel_area / (1.0 + u * u + v * v).powf(1.5)
+}
+
+/// Direction through face coordinates `u`, `v` of cube face `face` (+X, -X, +Y, -Y, +Z, -Z).
+fn face_dir(face: u32, u: f32, v: f32) -> [f32; 3] {
+    match face {
+        0 => [1.0, -v, -u],
+        1 => [-1.0, -v, u],
+        2 => [u, 1.0, v],
+        3 => [u, -1.0, -v],
+        4 => [u, -v, 1.0],
+        _ => [-u, -v, -1.0],
+    }
+}
+
+fn normalize([x, y, z]: [f32; 3]) -> [f32; 3] {
+    let len = (x * x + y * y + z * z).sqrt();
+    [x / len, y / len, z / len]
+}
+
+fn read_rgb(bytes: &[u8]) -> [f32; 3] {
+    let mut rgb = [0.0; 3];
+    for (c, chunk) in rgb.iter_mut().zip(bytes.chunks_exact(4)) {
+        *c = f32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+    }
+    rgb
+}
+
+/// Copies the faces out of a cross layout into a cubemap.
+fn from_cross(image: &Image, layout: CubemapLayout) -> Result<Image, TextureError> {
+    let width = image.width() as usize;
+    let size = match layout {
+        CubemapLayout::HorizontalCross => image.width() / 4,
+        _ => image.width() / 3,
+    } as usize;
+
+    // Cell (column, row) of each face, and whether it's rotated by 180 degrees.
+    let cells: [(usize, usize, bool); 6] = match layout {
+        CubemapLayout::HorizontalCross => [
+            (2, 1, false),
+            (0, 1, false),
+            (1, 0, false),
+            (1, 2, false),
+            (1, 1, false),
+            (3, 1, false),
+        ],
+        _ => [
+            (2, 1, false),
+            (0, 1, false),
+            (1, 0, false),
+            (1, 2, false),
+            (1, 1, false),
+            (1, 3, true),
+        ],
+    };
+
+    let src = image.data();
+    let mut data = Vec::with_capacity(size * size * 6 * 16);
+
+    for (column, row, rotated) in cells.iter().copied() {
+        for y in 0..size {
+            for x in 0..size {
+                let (fx, fy) = if rotated {
+                    (size - 1 - x, size - 1 - y)
+                } else {
+                    (x, y)
+                };
+                let offset = ((row * size + fy) * width + column * size + fx) * 16;
+                data.extend_from_slice(&src[offset..offset + 16]);
+            }
+        }
+    }
+
+    Image::from_data(
+        TextureFormat::RGBA32F,
+        size as u16,
+        size as u16,
+        1,
+        1,
+        true,
+        false,
+        &data,
+    )
+}
@@ -0,0 +1,294 @@
+use std::collections::VecDeque;
+
+use crate::bgfx::{dbg_text_clear, get_stats, set_debug, DbgTextClearArgs, DebugFlags};
+use crate::debug_text::{dbg_text_fmt, TextAttr, TextColor};
+
+/// Severity of a [DebugConsole] line, picks its color.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Severity {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl Severity {
+    pub fn attr(self) -> TextAttr {
+        let fg = match self {
+            Severity::Trace => TextColor::DarkGray,
+            Severity::Debug => TextColor::LightGray,
+            Severity::Info => TextColor::White,
+            Severity::Warn => TextColor::Yellow,
+            Severity::Error => TextColor::LightRed,
+        };
+        TextAttr::new(fg, TextColor::Black)
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct ConsoleLine {
+    pub severity: Severity,
+    pub text: String,
+}
+
+/// Line kept at the top of the console, f.e. frame time or draw counts.
+#[derive(Clone, PartialEq, Debug)]
+struct PinnedLine {
+    key: String,
+    text: String,
+    attr: TextAttr,
+}
+
+/// Scrolling log drawn with the debug text over the frame.
+///
+/// Pinned lines are drawn first, the most recent log lines fill the rest of the
+/// [Stats::text_height](crate::Stats) rows. Lines come from [DebugConsole::push] or from the
+/// `log` crate through `DebugConsoleLogger` with the `log` feature. bgfx-rs doesn't install a
+/// bgfx callback, so bgfx's own trace output isn't forwarded.
+///
+/// ```ignore
+/// let mut console = DebugConsole::new(256);
+/// console.set_visible(true);
+///
+/// loop {
+///     console.pin("fps", TextAttr::default(), format!("{:.1} fps", fps));
+///     console.render();
+///     frame(false);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct DebugConsole {
+    lines: VecDeque<ConsoleLine>,
+    capacity: usize,
+    pinned: Vec<PinnedLine>,
+    min_severity: Severity,
+    scroll: usize,
+    visible: bool,
+    small_font: bool,
+    debug_flags: DebugFlags,
+}
+
+impl DebugConsole {
+    /// Creates a hidden console keeping the last `capacity` lines.
+    pub fn new(capacity: usize) -> DebugConsole {
+        DebugConsole {
+            lines: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            pinned: Vec::new(),
+            min_severity: Severity::Trace,
+            scroll: 0,
+            visible: false,
+            small_font: false,
+            debug_flags: DebugFlags::NONE,
+        }
+    }
+
+    /// Other debug flags to keep enabled, f.e. [DebugFlags::PROFILER]. `set_debug` replaces all
+    /// flags, so the console needs to know them when it toggles [DebugFlags::TEXT].
+    pub fn set_debug_flags(&mut self, flags: DebugFlags) {
+        self.debug_flags = flags - DebugFlags::TEXT;
+        self.apply_debug_flags();
+    }
+
+    /// Lines below `severity` are dropped.
+    pub fn set_min_severity(&mut self, severity: Severity) {
+        self.min_severity = severity;
+    }
+
+    /// Uses the 8x8 font instead of 8x16, doubling the number of rows.
+    pub fn set_small_font(&mut self, small: bool) {
+        self.small_font = small;
+    }
+
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    /// Shows or hides the console by toggling [DebugFlags::TEXT].
+    pub fn set_visible(&mut self, visible: bool) {
+        self.visible = visible;
+        self.apply_debug_flags();
+    }
+
+    pub fn toggle(&mut self) {
+        self.set_visible(!self.visible);
+    }
+
+    fn apply_debug_flags(&self) {
+        let flags = match self.visible {
+            true => self.debug_flags | DebugFlags::TEXT,
+            false => self.debug_flags,
+        };
+        set_debug(flags.bits());
+    }
+
+    /// Appends a line, multi-line text is split. The oldest lines are dropped when full.
+    pub fn push(&mut self, severity: Severity, text: &str) {
+        if severity < self.min_severity {
+            return;
+        }
+
+        for line in text.lines() {
+            if self.lines.len() == self.capacity {
+                self.lines.pop_front();
+            }
+            self.lines.push_back(ConsoleLine {
+                severity,
+                text: line.to_owned(),
+            });
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &ConsoleLine> {
+        self.lines.iter()
+    }
+
+    /// Removes all log lines, pinned lines are kept.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.scroll = 0;
+    }
+
+    /// Sets the pinned line `key`, adding it below the other pinned lines if it's new.
+    pub fn pin(&mut self, key: &str, attr: TextAttr, text: impl Into<String>) {
+        let text = text.into();
+        match self.pinned.iter_mut().find(|line| line.key == key) {
+            Some(line) => {
+                line.text = text;
+                line.attr = attr;
+            }
+            None => self.pinned.push(PinnedLine {
+                key: key.to_owned(),
+                text,
+                attr,
+            }),
+        }
+    }
+
+    pub fn unpin(&mut self, key: &str) {
+        self.pinned.retain(|line| line.key != key);
+    }
+
+    /// Scrolls towards older lines.
+    pub fn scroll_up(&mut self, lines: usize) {
+        self.scroll = (self.scroll + lines).min(self.lines.len().saturating_sub(1));
+    }
+
+    /// Scrolls towards newer lines.
+    pub fn scroll_down(&mut self, lines: usize) {
+        self.scroll = self.scroll.saturating_sub(lines);
+    }
+
+    /// Draws the console into the debug text of the current frame. Call once per frame before
+    /// `frame`, does nothing while hidden.
+    pub fn render(&self) {
+        if !self.visible {
+            return;
+        }
+
+        dbg_text_clear(DbgTextClearArgs {
+            small: self.small_font,
+            ..Default::default()
+        });
+
+        let stats = get_stats();
+        let width = stats.text_width as usize;
+        let height = stats.text_height as usize;
+
+        let mut row = 0;
+        for line in self.pinned.iter().take(height) {
+            draw_line(row, line.attr, &line.text, width);
+            row += 1;
+        }
+
+        let rows = height - row;
+        let end = self.lines.len() - self.scroll.min(self.lines.len());
+        let start = end.saturating_sub(rows);
+        for line in self.lines.range(start..end) {
+            draw_line(row, line.severity.attr(), &line.text, width);
+            row += 1;
+        }
+    }
+}
+
+impl Default for DebugConsole {
+    /// Keeps the last 256 lines.
+    fn default() -> DebugConsole {
+        DebugConsole::new(256)
+    }
+}
+
+fn draw_line(row: usize, attr: TextAttr, text: &str, width: usize) {
+    let end = text
+        .char_indices()
+        .nth(width)
+        .map(|(index, _)| index)
+        .unwrap_or(text.len());
+    dbg_text_fmt(0, row as u16, attr, format_args!("{}", &text[..end]));
+}
+
+#[cfg(feature = "log")]
+mod logger {
+    use std::sync::{Arc, Mutex};
+
+    use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
+
+    use super::{DebugConsole, Severity};
+
+    impl From<Level> for Severity {
+        fn from(level: Level) -> Severity {
+            match level {
+                Level::Trace => Severity::Trace,
+                Level::Debug => Severity::Debug,
+                Level::Info => Severity::Info,
+                Level::Warn => Severity::Warn,
+                Level::Error => Severity::Error,
+            }
+        }
+    }
+
+    /// [Log] implementation feeding a shared [DebugConsole].
+    pub struct DebugConsoleLogger {
+        console: Arc<Mutex<DebugConsole>>,
+    }
+
+    impl DebugConsoleLogger {
+        pub fn new(console: Arc<Mutex<DebugConsole>>) -> DebugConsoleLogger {
+            DebugConsoleLogger { console }
+        }
+
+        /// Installs the logger globally, see [log::set_boxed_logger].
+        ///
+        /// ```ignore
+        /// let console = Arc::new(Mutex::new(DebugConsole::default()));
+        /// DebugConsoleLogger::install(console.clone(), LevelFilter::Info)?;
+        /// ```
+        pub fn install(
+            console: Arc<Mutex<DebugConsole>>,
+            level: LevelFilter,
+        ) -> Result<(), SetLoggerError> {
+            log::set_boxed_logger(Box::new(DebugConsoleLogger::new(console)))?;
+            log::set_max_level(level);
+            Ok(())
+        }
+    }
+
+    impl Log for DebugConsoleLogger {
+        fn enabled(&self, _metadata: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            let text = format!("[{}] {}", record.target(), record.args());
+            if let Ok(mut console) = self.console.lock() {
+                console.push(record.level().into(), &text);
+            }
+        }
+
+        fn flush(&self) {}
+    }
+}
+
+#[cfg(feature = "log")]
+pub use logger::DebugConsoleLogger;
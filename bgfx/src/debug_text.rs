@@ -0,0 +1,259 @@
+use std::ffi::CStr;
+use std::fmt::{self, Write};
+
+use crate::backend;
+
+/// The 16 color VGA palette used by the debug text.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum TextColor {
+    Black,
+    Blue,
+    Green,
+    Cyan,
+    Red,
+    Magenta,
+    Brown,
+    LightGray,
+    DarkGray,
+    LightBlue,
+    LightGreen,
+    LightCyan,
+    LightRed,
+    LightMagenta,
+    Yellow,
+    White,
+}
+
+impl TextColor {
+    const ALL: [TextColor; 16] = [
+        TextColor::Black,
+        TextColor::Blue,
+        TextColor::Green,
+        TextColor::Cyan,
+        TextColor::Red,
+        TextColor::Magenta,
+        TextColor::Brown,
+        TextColor::LightGray,
+        TextColor::DarkGray,
+        TextColor::LightBlue,
+        TextColor::LightGreen,
+        TextColor::LightCyan,
+        TextColor::LightRed,
+        TextColor::LightMagenta,
+        TextColor::Yellow,
+        TextColor::White,
+    ];
+
+    /// Color of the lower 4 bits of `index`.
+    pub const fn from_index(index: u8) -> TextColor {
+        TextColor::ALL[(index & 0xf) as usize]
+    }
+
+    /// sRGB value of the color in the VGA palette.
+    pub const fn rgb(self) -> [u8; 3] {
+        const PALETTE: [[u8; 3]; 16] = [
+            [0x00, 0x00, 0x00],
+            [0x00, 0x00, 0xaa],
+            [0x00, 0xaa, 0x00],
+            [0x00, 0xaa, 0xaa],
+            [0xaa, 0x00, 0x00],
+            [0xaa, 0x00, 0xaa],
+            [0xaa, 0x55, 0x00],
+            [0xaa, 0xaa, 0xaa],
+            [0x55, 0x55, 0x55],
+            [0x55, 0x55, 0xff],
+            [0x55, 0xff, 0x55],
+            [0x55, 0xff, 0xff],
+            [0xff, 0x55, 0x55],
+            [0xff, 0x55, 0xff],
+            [0xff, 0xff, 0x55],
+            [0xff, 0xff, 0xff],
+        ];
+        PALETTE[self as usize]
+    }
+
+    /// Closest palette color to an sRGB value.
+    pub fn nearest([r, g, b]: [u8; 3]) -> TextColor {
+        let distance = |color: &TextColor| {
+            let [pr, pg, pb] = color.rgb();
+            let dr = r as i32 - pr as i32;
+            let dg = g as i32 - pg as i32;
+            let db = b as i32 - pb as i32;
+            // Weighted for perceived brightness, green matters most.
+            2 * dr * dr + 4 * dg * dg + 3 * db * db
+        };
+        TextColor::ALL.into_iter().min_by_key(distance).unwrap()
+    }
+
+    /// Escape sequence that only changes the foreground color.
+    pub fn fg(self) -> TextEscape {
+        TextEscape {
+            fg: Some(self),
+            bg: None,
+        }
+    }
+
+    /// Escape sequence that only changes the background color.
+    pub fn bg(self) -> TextEscape {
+        TextEscape {
+            fg: None,
+            bg: Some(self),
+        }
+    }
+}
+
+/// Attribute of debug text, foreground color in the lower and background color in the upper
+/// 4 bits.
+///
+/// ```ignore
+/// let warning = TextAttr::new(TextColor::Yellow, TextColor::Black);
+/// dbg_text!(0, 2, warning, "{} textures failed to load", failed);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TextAttr {
+    pub fg: TextColor,
+    pub bg: TextColor,
+}
+
+impl TextAttr {
+    pub const fn new(fg: TextColor, bg: TextColor) -> TextAttr {
+        TextAttr { fg, bg }
+    }
+
+    pub const fn from_bits(bits: u8) -> TextAttr {
+        TextAttr {
+            fg: TextColor::from_index(bits),
+            bg: TextColor::from_index(bits >> 4),
+        }
+    }
+
+    pub const fn bits(self) -> u8 {
+        (self.bg as u8) << 4 | self.fg as u8
+    }
+
+    /// Escape sequence switching to this attribute in the middle of a string.
+    ///
+    /// ```ignore
+    /// let error = TextAttr::new(TextColor::White, TextColor::Red);
+    /// dbg_text!(0, 1, TextAttr::default(), "status: {}failed{}", error.escape(), TextEscape::RESET);
+    /// ```
+    pub fn escape(self) -> TextEscape {
+        TextEscape {
+            fg: Some(self.fg),
+            bg: Some(self.bg),
+        }
+    }
+}
+
+/// White on black, the attribute used by the bgfx examples.
+impl Default for TextAttr {
+    fn default() -> TextAttr {
+        TextAttr::new(TextColor::White, TextColor::Black)
+    }
+}
+
+impl From<TextAttr> for u8 {
+    fn from(attr: TextAttr) -> u8 {
+        attr.bits()
+    }
+}
+
+impl From<u8> for TextAttr {
+    fn from(bits: u8) -> TextAttr {
+        TextAttr::from_bits(bits)
+    }
+}
+
+/// `\x1b[<fg>;<bg>m` color escape understood by the debug text, colors that are `None` are
+/// left unchanged. Use it through its `Display` impl.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TextEscape {
+    pub fg: Option<TextColor>,
+    pub bg: Option<TextColor>,
+}
+
+impl TextEscape {
+    /// Switches back to the attribute passed to the print call.
+    pub const RESET: TextEscape = TextEscape { fg: None, bg: None };
+}
+
+impl fmt::Display for TextEscape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if *self == TextEscape::RESET {
+            return f.write_str("\x1b[0m");
+        }
+
+        f.write_str("\x1b[")?;
+        if let Some(fg) = self.fg {
+            write!(f, "{}", fg as u8)?;
+        }
+        f.write_char(';')?;
+        if let Some(bg) = self.bg {
+            write!(f, "{}", bg as u8)?;
+        }
+        f.write_char('m')
+    }
+}
+
+/// Size of the stack buffer used by [dbg_text_fmt], longer text is truncated.
+pub const DBG_TEXT_MAX_LEN: usize = 1024;
+
+/// NUL terminated string on the stack.
+struct StackBuffer {
+    data: [u8; DBG_TEXT_MAX_LEN],
+    len: usize,
+}
+
+impl StackBuffer {
+    fn new() -> StackBuffer {
+        StackBuffer {
+            data: [0; DBG_TEXT_MAX_LEN],
+            len: 0,
+        }
+    }
+
+    fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_until_nul(&self.data).unwrap_or_default()
+    }
+}
+
+impl Write for StackBuffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        // Keep room for the terminator, text past the end is dropped rather than failing the
+        // whole line.
+        let available = DBG_TEXT_MAX_LEN - 1 - self.len;
+        let len = s.len().min(available);
+        self.data[self.len..self.len + len].copy_from_slice(&s.as_bytes()[..len]);
+        self.len += len;
+        self.data[self.len] = 0;
+        Ok(())
+    }
+}
+
+/// Prints formatted debug text without allocating, see [dbg_text!](crate::dbg_text!).
+///
+/// The text is passed to bgfx as a `%s` argument, so `%` needs no escaping. Text is truncated
+/// at [DBG_TEXT_MAX_LEN] bytes or the first NUL character.
+pub fn dbg_text_fmt(x: u16, y: u16, attr: impl Into<u8>, args: fmt::Arguments) {
+    let mut buffer = StackBuffer::new();
+    let _ = buffer.write_fmt(args);
+
+    backend::bgfx_dbg_text(x, y, attr.into(), buffer.as_c_str());
+}
+
+/// Prints formatted debug text at column `x`, row `y`. `attr` is a [TextAttr] or its `u8` bits.
+///
+/// Formats into a stack buffer instead of allocating, and supports color escapes from
+/// [TextAttr::escape] and [TextColor::fg] inside the text.
+///
+/// ```ignore
+/// dbg_text!(0, 1, 0x0f, "Frame: {:.3} ms", frame_ms);
+/// dbg_text!(0, 2, TextAttr::default(), "{}GPU{} {:?}", TextColor::Yellow.fg(), TextEscape::RESET, renderer);
+/// ```
+#[macro_export]
+macro_rules! dbg_text {
+    ($x:expr, $y:expr, $attr:expr, $($arg:tt)*) => {
+        $crate::debug_text::dbg_text_fmt($x, $y, $attr, ::std::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,355 @@
+use crate::backend;
+use crate::bgfx::get_stats;
+use crate::debug_text::{TextAttr, TextColor};
+
+/// A character and its attribute in a [DebugTextImage].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TextCell {
+    pub ch: char,
+    pub attr: TextAttr,
+}
+
+impl TextCell {
+    pub const fn new(ch: char, attr: TextAttr) -> TextCell {
+        TextCell { ch, attr }
+    }
+
+    /// The raw character/attribute pair bgfx expects. Characters without a code page 437 glyph
+    /// are drawn as `?`.
+    pub fn encode(self) -> [u8; 2] {
+        [cp437(self.ch).unwrap_or(b'?'), self.attr.bits()]
+    }
+}
+
+impl Default for TextCell {
+    fn default() -> TextCell {
+        TextCell::new(' ', TextAttr::default())
+    }
+}
+
+/// Line style of [DebugTextImage::draw_box].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BoxStyle {
+    Single,
+    Double,
+}
+
+impl BoxStyle {
+    /// Horizontal, vertical, top left, top right, bottom left and bottom right characters.
+    fn chars(self) -> [char; 6] {
+        match self {
+            BoxStyle::Single => ['─', '│', '┌', '┐', '└', '┘'],
+            BoxStyle::Double => ['═', '║', '╔', '╗', '╚', '╝'],
+        }
+    }
+}
+
+/// Grid of text cells drawn with a single `dbg_text_image` call.
+///
+/// Writes outside the grid are ignored and [DebugTextImage::draw] clips against the debug text
+/// size reported in [Stats::text_width](crate::Stats) and `text_height`.
+///
+/// ```ignore
+/// let attr = TextAttr::new(TextColor::LightGreen, TextColor::Black);
+/// let mut image = DebugTextImage::new(34, 4);
+/// image.draw_box(0, 0, 34, 4, attr, BoxStyle::Single);
+/// image.print(2, 1, attr, "frame time");
+/// image.sparkline(2, 2, 30, 1, &frame_times, attr);
+/// image.draw(0, 10);
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct DebugTextImage {
+    width: u16,
+    height: u16,
+    cells: Vec<TextCell>,
+}
+
+impl DebugTextImage {
+    /// Widest image [DebugTextImage::draw] can pass to bgfx, the row pitch is a `u16` of 2 bytes
+    /// per cell.
+    pub const MAX_WIDTH: u16 = u16::MAX / 2;
+
+    /// Creates an image of blank cells. `width` is clamped to [DebugTextImage::MAX_WIDTH].
+    pub fn new(width: u16, height: u16) -> DebugTextImage {
+        let width = width.min(DebugTextImage::MAX_WIDTH);
+        DebugTextImage {
+            width,
+            height,
+            cells: vec![TextCell::default(); width as usize * height as usize],
+        }
+    }
+
+    /// Creates an image covering the whole debug text area of the current frame.
+    pub fn screen() -> DebugTextImage {
+        let stats = get_stats();
+        DebugTextImage::new(stats.text_width, stats.text_height)
+    }
+
+    /// Converts an RGB8 image to half-block characters, each cell holding two pixels on top of
+    /// each other. Colors are quantized to the 16 color palette, an odd last row is padded with
+    /// black.
+    ///
+    /// # Panics
+    ///
+    /// If `rgb` doesn't hold `width * height` pixels.
+    pub fn from_rgb(width: u16, height: u16, rgb: &[u8]) -> DebugTextImage {
+        assert_eq!(
+            rgb.len(),
+            width as usize * height as usize * 3,
+            "expected {}x{} RGB8 pixels",
+            width,
+            height
+        );
+
+        let pixel = |x: u16, y: u16| {
+            if y >= height {
+                return TextColor::Black;
+            }
+            let offset = (y as usize * width as usize + x as usize) * 3;
+            TextColor::nearest([rgb[offset], rgb[offset + 1], rgb[offset + 2]])
+        };
+
+        let mut image = DebugTextImage::new(width, height.div_ceil(2));
+        for y in 0..image.height {
+            for x in 0..width {
+                let top = pixel(x, y * 2);
+                let bottom = pixel(x, y * 2 + 1);
+                image.set(x, y, TextCell::new('▀', TextAttr::new(top, bottom)));
+            }
+        }
+        image
+    }
+
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    pub fn cells(&self) -> &[TextCell] {
+        &self.cells
+    }
+
+    fn index(&self, x: u16, y: u16) -> Option<usize> {
+        if x < self.width && y < self.height {
+            Some(y as usize * self.width as usize + x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, x: u16, y: u16) -> Option<TextCell> {
+        self.index(x, y).map(|index| self.cells[index])
+    }
+
+    /// Sets a cell, ignored outside the image.
+    pub fn set(&mut self, x: u16, y: u16, cell: TextCell) {
+        if let Some(index) = self.index(x, y) {
+            self.cells[index] = cell;
+        }
+    }
+
+    /// Sets every cell to a blank with background `attr`.
+    pub fn clear(&mut self, attr: TextAttr) {
+        self.cells.fill(TextCell::new(' ', attr));
+    }
+
+    /// Fills a rectangle with `ch`, clipped to the image.
+    pub fn fill(&mut self, x: u16, y: u16, width: u16, height: u16, ch: char, attr: TextAttr) {
+        for cy in y..y.saturating_add(height).min(self.height) {
+            for cx in x..x.saturating_add(width).min(self.width) {
+                self.set(cx, cy, TextCell::new(ch, attr));
+            }
+        }
+    }
+
+    /// Writes a single line of text, clipped at the right edge. Returns the column after the
+    /// last character.
+    pub fn print(&mut self, x: u16, y: u16, attr: TextAttr, text: &str) -> u16 {
+        let mut cx = x;
+        for ch in text.chars() {
+            if cx >= self.width {
+                break;
+            }
+            self.set(cx, y, TextCell::new(ch, attr));
+            cx += 1;
+        }
+        cx
+    }
+
+    /// Draws the outline of a rectangle.
+    pub fn draw_box(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        attr: TextAttr,
+        style: BoxStyle,
+    ) {
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let [horizontal, vertical, top_left, top_right, bottom_left, bottom_right] = style.chars();
+        let right = x.saturating_add(width - 1);
+        let bottom = y.saturating_add(height - 1);
+
+        for cx in x..=right {
+            self.set(cx, y, TextCell::new(horizontal, attr));
+            self.set(cx, bottom, TextCell::new(horizontal, attr));
+        }
+        for cy in y..=bottom {
+            self.set(x, cy, TextCell::new(vertical, attr));
+            self.set(right, cy, TextCell::new(vertical, attr));
+        }
+
+        self.set(x, y, TextCell::new(top_left, attr));
+        self.set(right, y, TextCell::new(top_right, attr));
+        self.set(x, bottom, TextCell::new(bottom_left, attr));
+        self.set(right, bottom, TextCell::new(bottom_right, attr));
+    }
+
+    /// Draws a horizontal bar filled to `fraction` (0 to 1) of `width`, with half cell
+    /// resolution. The unfilled part is shaded.
+    pub fn bar(&mut self, x: u16, y: u16, width: u16, fraction: f32, attr: TextAttr) {
+        let halves = (fraction.clamp(0.0, 1.0) * width as f32 * 2.0).round() as u16;
+        for i in 0..width {
+            let ch = match halves.saturating_sub(i * 2) {
+                0 => '░',
+                1 => '▌',
+                _ => '█',
+            };
+            self.set(x.saturating_add(i), y, TextCell::new(ch, attr));
+        }
+    }
+
+    /// Draws the last `width` values as columns `height` rows high, scaled so the largest value
+    /// fills the whole height. Each row has two steps of resolution.
+    pub fn sparkline(
+        &mut self,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        values: &[f32],
+        attr: TextAttr,
+    ) {
+        let values = &values[values.len().saturating_sub(width as usize)..];
+        let max = values.iter().copied().fold(0.0f32, f32::max);
+        let steps = height as f32 * 2.0;
+
+        for (i, value) in values.iter().enumerate() {
+            let level = match max > 0.0 {
+                true => (value.max(0.0) / max * steps).round() as u16,
+                false => 0,
+            };
+            for row in 0..height {
+                // Rows count from the bottom.
+                let ch = match level.saturating_sub(row * 2) {
+                    0 => ' ',
+                    1 => '▄',
+                    _ => '█',
+                };
+                let cy = y.saturating_add(height - 1 - row);
+                self.set(x.saturating_add(i as u16), cy, TextCell::new(ch, attr));
+            }
+        }
+    }
+
+    /// Copies another image into this one at `x`, `y`, clipped to this image.
+    pub fn blit(&mut self, x: u16, y: u16, image: &DebugTextImage) {
+        for sy in 0..image.height {
+            for sx in 0..image.width {
+                if let Some(cell) = image.get(sx, sy) {
+                    self.set(x.saturating_add(sx), y.saturating_add(sy), cell);
+                }
+            }
+        }
+    }
+
+    /// Raw character/attribute pairs, row by row.
+    pub fn encode(&self) -> Vec<u8> {
+        self.cells.iter().flat_map(|cell| cell.encode()).collect()
+    }
+
+    /// Draws the image at column `x`, row `y` of the debug text. The part outside the debug text
+    /// area of the current frame is clipped. Requires [DebugFlags::TEXT](crate::DebugFlags).
+    pub fn draw(&self, x: u16, y: u16) {
+        let stats = get_stats();
+        let width = self.width.min(stats.text_width.saturating_sub(x));
+        let height = self.height.min(stats.text_height.saturating_sub(y));
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        let data = self.encode();
+        unsafe {
+            backend::bgfx_dbg_text_image(
+                x,
+                y,
+                width,
+                height,
+                data.as_ptr() as *const _,
+                self.width * 2,
+            );
+        }
+    }
+}
+
+/// Code page 437 byte of `ch`, the font of the debug text.
+fn cp437(ch: char) -> Option<u8> {
+    if ch.is_ascii() && !ch.is_ascii_control() {
+        return Some(ch as u8);
+    }
+
+    let byte = match ch {
+        '░' => 0xb0,
+        '▒' => 0xb1,
+        '▓' => 0xb2,
+        '│' => 0xb3,
+        '┤' => 0xb4,
+        '╣' => 0xb9,
+        '║' => 0xba,
+        '╗' => 0xbb,
+        '╝' => 0xbc,
+        '┐' => 0xbf,
+        '└' => 0xc0,
+        '┴' => 0xc1,
+        '┬' => 0xc2,
+        '├' => 0xc3,
+        '─' => 0xc4,
+        '┼' => 0xc5,
+        '╚' => 0xc8,
+        '╔' => 0xc9,
+        '╩' => 0xca,
+        '╦' => 0xcb,
+        '╠' => 0xcc,
+        '═' => 0xcd,
+        '╬' => 0xce,
+        '┘' => 0xd9,
+        '┌' => 0xda,
+        '█' => 0xdb,
+        '▄' => 0xdc,
+        '▌' => 0xdd,
+        '▐' => 0xde,
+        '▀' => 0xdf,
+        '■' => 0xfe,
+        '·' => 0xfa,
+        '°' => 0xf8,
+        '±' => 0xf1,
+        'µ' => 0xe6,
+        '→' => 0x1a,
+        '←' => 0x1b,
+        '↑' => 0x18,
+        '↓' => 0x19,
+        '▲' => 0x1e,
+        '▼' => 0x1f,
+        '►' => 0x10,
+        '◄' => 0x11,
+        _ => return None,
+    };
+    Some(byte)
+}
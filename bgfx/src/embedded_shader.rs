@@ -0,0 +1,85 @@
+use crate::bgfx::{get_renderer_type, Program, RendererType, Shader};
+use crate::shader_library::{create_named_shader, shader_dir_name, ShaderError, ShaderLibrary};
+
+/// Shader binaries for every available backend, compiled into the executable.
+///
+/// Usually created with the [embedded_shader!](crate::embedded_shader) macro, which
+/// `include_bytes!` each `<backend>/<name>.bin` variant found in a directory:
+///
+/// ```ignore
+/// static VS_CUBES: EmbeddedShader = embedded_shader!("vs_cubes", dir = "shaders");
+/// static FS_CUBES: EmbeddedShader = embedded_shader!("fs_cubes", dir = "shaders");
+///
+/// let program = create_embedded_program(&VS_CUBES, &FS_CUBES)?;
+/// ```
+pub struct EmbeddedShader {
+    name: &'static str,
+    variants: &'static [(&'static str, &'static [u8])],
+}
+
+impl EmbeddedShader {
+    /// `variants` pairs a backend directory name (see [shader_dir_name]) with the shader binary.
+    pub const fn new(
+        name: &'static str,
+        variants: &'static [(&'static str, &'static [u8])],
+    ) -> EmbeddedShader {
+        EmbeddedShader { name, variants }
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the backend directory names this shader was compiled for.
+    pub fn backends(&self) -> impl Iterator<Item = &'static str> {
+        self.variants.iter().map(|(backend, _)| *backend)
+    }
+
+    /// Returns the shader binary for `renderer` if it was embedded.
+    pub fn data(&self, renderer: RendererType) -> Option<&'static [u8]> {
+        let dir = shader_dir_name(renderer)?;
+        self.variants
+            .iter()
+            .find(|(backend, _)| *backend == dir)
+            .map(|(_, data)| *data)
+    }
+
+    /// Creates the shader for the current renderer (see [get_renderer_type]).
+    pub fn create_shader(&self) -> Result<Shader, ShaderError> {
+        let renderer = get_renderer_type();
+        let data = self.data(renderer).ok_or_else(|| {
+            if shader_dir_name(renderer).is_none() {
+                ShaderError::UnsupportedRenderer(renderer)
+            } else {
+                ShaderError::MissingVariant {
+                    name: self.name.to_owned(),
+                    renderer,
+                    path: None,
+                }
+            }
+        })?;
+
+        Ok(create_named_shader(self.name, data.to_vec()))
+    }
+}
+
+/// Creates a program from embedded vertex and fragment shaders for the current renderer.
+pub fn create_embedded_program(
+    vs: &EmbeddedShader,
+    fs: &EmbeddedShader,
+) -> Result<Program, ShaderError> {
+    let vsh = vs.create_shader()?;
+    let fsh = fs.create_shader()?;
+    Ok(Program::create_program(&vsh, &fsh, false))
+}
+
+impl ShaderLibrary {
+    /// Registers all variants of an embedded shader. Does nothing for libraries created with
+    /// [ShaderLibrary::from_dir].
+    pub fn insert_embedded(&mut self, shader: &EmbeddedShader) -> &mut Self {
+        for (backend, data) in shader.variants {
+            self.insert_variant(backend, shader.name, data.to_vec());
+        }
+        self
+    }
+}
@@ -1,8 +1,73 @@
 #[macro_use]
 extern crate bitflags;
 
-#[cfg(not(feature = "shared-api"))]
 pub mod static_lib;
-#[cfg(not(feature = "shared-api"))]
 pub use static_lib as bgfx;
-pub use bgfx::*;
\ No newline at end of file
+pub use bgfx::*;
+
+pub mod shader_library;
+pub use shader_library::{ShaderError, ShaderLibrary};
+
+pub mod embedded_shader;
+pub use embedded_shader::{create_embedded_program, EmbeddedShader};
+pub use bgfx_rs_macros::embedded_shader;
+
+pub mod varying;
+pub use varying::{shader_inputs, LayoutMismatch, VaryingDecl, VaryingDef, VaryingError};
+
+pub mod texture;
+pub use texture::{Image, TextureError};
+
+pub mod texture_encoder;
+pub use texture_encoder::{EncodeQuality, TextureEncoder};
+
+pub mod cubemap;
+pub use cubemap::{Cubemap, CubemapLayout, LightingModel};
+
+pub mod mip_chain;
+pub use mip_chain::{convert_pixels, texture_memory, MipArgs, MipChain, MipFilter};
+
+pub mod texture_format;
+
+pub mod caps;
+
+pub mod stats;
+pub use stats::{EncoderStatsSnapshot, StatsSnapshot, ViewStatsSnapshot};
+
+pub mod profiler;
+pub use profiler::{FrameProfiler, FrameSummary, Summary, ViewSummary};
+
+pub mod debug_text;
+pub use debug_text::{TextAttr, TextColor, TextEscape};
+
+pub mod debug_text_image;
+pub use debug_text_image::{BoxStyle, DebugTextImage, TextCell};
+
+pub mod debug_console;
+#[cfg(feature = "log")]
+pub use debug_console::DebugConsoleLogger;
+pub use debug_console::{ConsoleLine, DebugConsole, Severity};
+
+pub mod backend;
+pub use backend::{set_backend, Backend, BackendGuard, FfiBackend};
+
+pub mod mock;
+pub use mock::{MockArg, MockBackend, MockCall, MockSubmit};
+mod pure;
+
+pub mod recording;
+pub use recording::{Command, HandleKind, LayoutData, Recording};
+
+pub mod recorder;
+pub use recorder::Recorder;
+
+pub mod replayer;
+pub use replayer::Replayer;
+
+#[cfg(feature = "shared-api")]
+pub mod shared_api;
+#[cfg(feature = "shared-api")]
+pub use shared_api::SharedApiError;
+
+#[cfg(all(feature = "shared-api", feature = "mock-only"))]
+compile_error!("The shared-api and mock-only features can't be combined, mock-only has no bgfx to load");
@@ -0,0 +1,583 @@
+use std::f32::consts::PI;
+
+use crate::bgfx::{Memory, TextureFormat};
+use crate::texture::TextureError;
+
+/// Downsampling filter used by [MipChain::generate].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MipFilter {
+    /// Averages the 2x2 texels covered by each texel of the next mip. Fast, slightly blurry.
+    Box,
+    /// Kaiser windowed sinc, keeps more detail in the smaller mips.
+    Kaiser,
+}
+
+pub struct MipArgs {
+    /// Downsampling filter.
+    pub filter: MipFilter,
+    /// Treat RGBA8 color channels as sRGB and filter them in linear space. Ignored for float
+    /// formats, which are always linear.
+    pub srgb: bool,
+}
+
+impl Default for MipArgs {
+    fn default() -> MipArgs {
+        MipArgs {
+            filter: MipFilter::Box,
+            srgb: false,
+        }
+    }
+}
+
+/// Base level and mips of one 2D surface (a texture, array layer or cube face).
+///
+/// ```ignore
+/// let mips = MipChain::generate(TextureFormat::RGBA8, 256, 256, &pixels, MipArgs {
+///     srgb: true,
+///     ..Default::default()
+/// })?;
+///
+/// let texture = create_texture_2d(256, 256, true, 1, TextureFormat::RGBA8, flags, &mips.to_memory());
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct MipChain {
+    format: TextureFormat,
+    width: u32,
+    height: u32,
+    levels: Vec<Vec<u8>>,
+}
+
+impl MipChain {
+    /// Generates the full mip chain of `pixels` down to 1x1. Supported formats are RGBA8,
+    /// RGBA16F and RGBA32F, convert other formats first with [convert_pixels].
+    pub fn generate(
+        format: TextureFormat,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+        params: MipArgs,
+    ) -> Result<MipChain, TextureError> {
+        let codec = Codec::new(format, params.srgb)?;
+        if width == 0 || height == 0 {
+            return Err(TextureError::InvalidInput(format!(
+                "empty {}x{} image",
+                width, height
+            )));
+        }
+        check_len(pixels.len(), width, height, codec.texel_size())?;
+
+        let mut levels = vec![pixels.to_vec()];
+        let mut texels = codec.decode(pixels);
+        let (mut w, mut h) = (width, height);
+
+        while w > 1 || h > 1 {
+            let (dst_w, dst_h) = ((w / 2).max(1), (h / 2).max(1));
+            texels = downsample(&texels, w, h, dst_w, dst_h, params.filter);
+            levels.push(codec.encode(&texels));
+            w = dst_w;
+            h = dst_h;
+        }
+
+        Ok(MipChain {
+            format,
+            width,
+            height,
+            levels,
+        })
+    }
+
+    /// A surface without mips, f.e. to combine with other surfaces in [texture_memory].
+    pub fn single(format: TextureFormat, width: u32, height: u32, pixels: &[u8]) -> MipChain {
+        MipChain {
+            format,
+            width,
+            height,
+            levels: vec![pixels.to_vec()],
+        }
+    }
+
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    pub fn num_mips(&self) -> u8 {
+        self.levels.len() as u8
+    }
+
+    /// Pixel data of each level, starting with the base level.
+    pub fn levels(&self) -> &[Vec<u8>] {
+        &self.levels
+    }
+
+    /// Copies all levels into a single bgfx buffer for `create_texture_2d` with `has_mips`.
+    pub fn to_memory(&self) -> Memory {
+        Memory::copy(&self.levels.concat())
+    }
+}
+
+/// Copies surfaces into a single bgfx buffer for textures with layers or cube faces. bgfx
+/// expects all mips of a surface together, cube faces in +X, -X, +Y, -Y, +Z, -Z order, and layers
+/// one after another, so `surfaces` is `[layer 0 +X, layer 0 -X, ..., layer 1 +X, ...]` for cube
+/// maps and `[layer 0, layer 1, ...]` otherwise.
+pub fn texture_memory(surfaces: &[MipChain]) -> Result<Memory, TextureError> {
+    let first = surfaces
+        .first()
+        .ok_or_else(|| TextureError::InvalidInput("no surfaces".to_owned()))?;
+
+    for surface in surfaces {
+        if surface.format != first.format
+            || surface.width != first.width
+            || surface.height != first.height
+            || surface.levels.len() != first.levels.len()
+        {
+            return Err(TextureError::InvalidInput(
+                "surfaces differ in format, size or number of mips".to_owned(),
+            ));
+        }
+    }
+
+    let data: Vec<u8> = surfaces
+        .iter()
+        .flat_map(|surface| surface.levels.iter().flatten().copied())
+        .collect();
+    Ok(Memory::copy(&data))
+}
+
+/// Converts pixels between uncompressed formats, f.e. RGBA8 to BGRA8 or RGBA32F to RGBA16F.
+/// bimg takes the size as `u16`, larger images return [TextureError::TooLarge].
+pub fn convert_pixels(
+    pixels: &[u8],
+    src_format: TextureFormat,
+    dst_format: TextureFormat,
+    width: u32,
+    height: u32,
+) -> Result<Vec<u8>, TextureError> {
+    let (width, height) = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => return Err(TextureError::TooLarge { width, height }),
+    };
+
+    let size = |format: TextureFormat| unsafe {
+        bgfx_sys::bimg_image_get_size(width, height, 1, false, false, 1, format as u32) as usize
+    };
+
+    let expected = size(src_format);
+    if pixels.len() != expected {
+        return Err(TextureError::InvalidData {
+            expected,
+            found: pixels.len(),
+        });
+    }
+
+    let mut data = vec![0u8; size(dst_format)];
+    let ok = unsafe {
+        bgfx_sys::bimg_image_convert_pixels(
+            data.as_mut_ptr() as _,
+            dst_format as u32,
+            pixels.as_ptr() as _,
+            src_format as u32,
+            width as u32,
+            height as u32,
+            1,
+        )
+    };
+
+    if !ok {
+        return Err(TextureError::UnsupportedEncoding(dst_format));
+    }
+    Ok(data)
+}
+
+type Texel = [f32; 4];
+
+/// Decodes and encodes the formats mips can be generated for.
+#[derive(Clone, Copy)]
+enum Codec {
+    Rgba8 { srgb: bool },
+    Rgba16F,
+    Rgba32F,
+}
+
+impl Codec {
+    fn new(format: TextureFormat, srgb: bool) -> Result<Codec, TextureError> {
+        match format {
+            TextureFormat::RGBA8 => Ok(Codec::Rgba8 { srgb }),
+            TextureFormat::RGBA16F => Ok(Codec::Rgba16F),
+            TextureFormat::RGBA32F => Ok(Codec::Rgba32F),
+            _ => Err(TextureError::UnsupportedEncoding(format)),
+        }
+    }
+
+    fn texel_size(self) -> usize {
+        match self {
+            Codec::Rgba8 { .. } => 4,
+            Codec::Rgba16F => 8,
+            Codec::Rgba32F => 16,
+        }
+    }
+
+    fn decode(self, pixels: &[u8]) -> Vec<Texel> {
+        pixels
+            .chunks_exact(self.texel_size())
+            .map(|texel| match self {
+                Codec::Rgba8 { srgb } => {
+                    let mut out = [0.0; 4];
+                    for (c, value) in out.iter_mut().enumerate() {
+                        *value = texel[c] as f32 / 255.0;
+                        if srgb && c < 3 {
+                            *value = srgb_to_linear(*value);
+                        }
+                    }
+                    out
+                }
+                Codec::Rgba16F => {
+                    let mut out = [0.0; 4];
+                    for (value, half) in out.iter_mut().zip(texel.chunks_exact(2)) {
+                        *value = half_to_f32(u16::from_ne_bytes([half[0], half[1]]));
+                    }
+                    out
+                }
+                Codec::Rgba32F => {
+                    let mut out = [0.0; 4];
+                    for (value, bytes) in out.iter_mut().zip(texel.chunks_exact(4)) {
+                        *value = f32::from_ne_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+                    }
+                    out
+                }
+            })
+            .collect()
+    }
+
+    fn encode(self, texels: &[Texel]) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(texels.len() * self.texel_size());
+
+        for texel in texels {
+            match self {
+                Codec::Rgba8 { srgb } => {
+                    for (c, value) in texel.iter().enumerate() {
+                        let value = if srgb && c < 3 {
+                            linear_to_srgb(*value)
+                        } else {
+                            *value
+                        };
+                        pixels.push((value.clamp(0.0, 1.0) * 255.0 + 0.5) as u8);
+                    }
+                }
+                Codec::Rgba16F => {
+                    for value in texel {
+                        pixels.extend_from_slice(&f32_to_half(*value).to_ne_bytes());
+                    }
+                }
+                Codec::Rgba32F => {
+                    for value in texel {
+                        pixels.extend_from_slice(&value.to_ne_bytes());
+                    }
+                }
+            }
+        }
+
+        pixels
+    }
+}
+
+fn check_len(len: usize, width: u32, height: u32, texel_size: usize) -> Result<(), TextureError> {
+    let expected = width as usize * height as usize * texel_size;
+    if len != expected {
+        return Err(TextureError::InvalidData {
+            expected,
+            found: len,
+        });
+    }
+    Ok(())
+}
+
+/// Separable resample, first horizontally then vertically.
+fn downsample(
+    texels: &[Texel],
+    width: u32,
+    height: u32,
+    dst_width: u32,
+    dst_height: u32,
+    filter: MipFilter,
+) -> Vec<Texel> {
+    let (width, height) = (width as usize, height as usize);
+    let (dst_width, dst_height) = (dst_width as usize, dst_height as usize);
+
+    let horizontal = Kernel::new(width, dst_width, filter);
+    let mut rows = vec![[0.0; 4]; dst_width * height];
+    for y in 0..height {
+        for x in 0..dst_width {
+            rows[y * dst_width + x] = horizontal.apply(x, |i| texels[y * width + i]);
+        }
+    }
+
+    let vertical = Kernel::new(height, dst_height, filter);
+    let mut out = vec![[0.0; 4]; dst_width * dst_height];
+    for y in 0..dst_height {
+        for x in 0..dst_width {
+            out[y * dst_width + x] = vertical.apply(y, |i| rows[i * dst_width + x]);
+        }
+    }
+
+    out
+}
+
+/// Normalized weights of the source texels contributing to each destination texel, with
+/// clamp to edge addressing.
+struct Kernel {
+    taps: Vec<Vec<(usize, f32)>>,
+}
+
+impl Kernel {
+    fn new(src: usize, dst: usize, filter: MipFilter) -> Kernel {
+        let scale = src as f32 / dst as f32;
+        // Filter support in destination texels.
+        let support = match filter {
+            MipFilter::Box => 0.5,
+            MipFilter::Kaiser => 1.5,
+        };
+
+        let taps = (0..dst)
+            .map(|i| {
+                let center = (i as f32 + 0.5) * scale - 0.5;
+                let first = (center - support * scale).floor() as isize;
+                let last = (center + support * scale).ceil() as isize;
+
+                let mut taps: Vec<(usize, f32)> = Vec::new();
+                for j in first..=last {
+                    let x = (j as f32 - center) / scale;
+                    let weight = match filter {
+                        MipFilter::Box => (x.abs() < support) as u32 as f32,
+                        MipFilter::Kaiser => kaiser(x, support),
+                    };
+                    if weight == 0.0 {
+                        continue;
+                    }
+
+                    let index = j.clamp(0, src as isize - 1) as usize;
+                    match taps.iter_mut().find(|(tap, _)| *tap == index) {
+                        Some(tap) => tap.1 += weight,
+                        None => taps.push((index, weight)),
+                    }
+                }
+
+                let sum: f32 = taps.iter().map(|(_, weight)| weight).sum();
+                if sum == 0.0 {
+                    return vec![(center.round().clamp(0.0, src as f32 - 1.0) as usize, 1.0)];
+                }
+                for tap in taps.iter_mut() {
+                    tap.1 /= sum;
+                }
+                taps
+            })
+            .collect();
+
+        Kernel { taps }
+    }
+
+    fn apply<F: Fn(usize) -> Texel>(&self, i: usize, texel: F) -> Texel {
+        let mut out = [0.0; 4];
+        for (index, weight) in &self.taps[i] {
+            let value = texel(*index);
+            for c in 0..4 {
+                out[c] += value[c] * weight;
+            }
+        }
+        out
+    }
+}
+
+/// Kaiser windowed sinc with alpha 4, the same parameters nvtt uses for mips.
+fn kaiser(x: f32, support: f32) -> f32 {
+    const ALPHA: f32 = 4.0;
+
+    let t = x / support;
+    if t.abs() >= 1.0 {
+        return 0.0;
+    }
+
+    let sinc = if x.abs() < 1e-6 {
+        1.0
+    } else {
+        (PI * x).sin() / (PI * x)
+    };
+    sinc * bessel_i0(ALPHA * (1.0 - t * t).sqrt()) / bessel_i0(ALPHA)
+}
+
+/// Zeroth order modified Bessel function of the first kind.
+fn bessel_i0(x: f32) -> f32 {
+    let mut sum = 1.0;
+    let mut term = 1.0;
+    let mut k = 1.0;
+    while term > sum * 1e-8 {
+        term *= (x / (2.0 * k)) * (x / (2.0 * k));
+        sum += term;
+        k += 1.0;
+    }
+    sum
+}
+
+fn srgb_to_linear(value: f32) -> f32 {
+    if value <= 0.04045 {
+        value / 12.92
+    } else {
+        ((value + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(value: f32) -> f32 {
+    if value <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        1.055 * value.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+fn half_to_f32(half: u16) -> f32 {
+    let sign = ((half >> 15) as u32) << 31;
+    let exponent = ((half >> 10) & 0x1f) as u32;
+    let mantissa = (half & 0x3ff) as u32;
+
+    let bits = match (exponent, mantissa) {
+        (0, 0) => sign,
+        (0, _) => {
+            // Subnormal, renormalize.
+            let shift = mantissa.leading_zeros() - 21;
+            let mantissa = (mantissa << shift) & 0x3ff;
+            sign | ((127 - 15 + 1 - shift) << 23) | (mantissa << 13)
+        }
+        (0x1f, _) => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => sign | ((exponent + 127 - 15) << 23) | (mantissa << 13),
+    };
+    f32::from_bits(bits)
+}
+
+fn f32_to_half(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        let nan = if mantissa != 0 { 0x200 } else { 0 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exponent = exponent - 127 + 15;
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+        // Subnormal, round to nearest even.
+        let mantissa = mantissa | 0x80_0000;
+        let shift = (14 - exponent) as u32;
+        let half = mantissa >> shift;
+        let rest = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+        let round = (rest > halfway || (rest == halfway && half & 1 == 1)) as u32;
+        return sign | (half + round) as u16;
+    }
+
+    // Round to nearest even, a carry into the exponent is still correct.
+    let half = ((exponent as u32) << 10) | (mantissa >> 13);
+    let rest = mantissa & 0x1fff;
+    let round = (rest > 0x1000 || (rest == 0x1000 && half & 1 == 1)) as u32;
+    sign | (half + round) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn half_round_trip() {
+        for half in 0..=u16::MAX {
+            let value = half_to_f32(half);
+            if value.is_nan() {
+                assert!(half_to_f32(f32_to_half(value)).is_nan(), "{:#x}", half);
+            } else {
+                assert_eq!(f32_to_half(value), half, "{:#x} -> {}", half, value);
+            }
+        }
+    }
+
+    #[test]
+    fn half_rounding() {
+        assert_eq!(f32_to_half(1.0), 0x3c00);
+        assert_eq!(f32_to_half(-2.0), 0xc000);
+        assert_eq!(f32_to_half(65504.0), 0x7bff);
+        assert_eq!(f32_to_half(65520.0), 0x7c00);
+        assert_eq!(f32_to_half(f32::INFINITY), 0x7c00);
+        assert_eq!(f32_to_half(1e-8), 0);
+        assert_eq!(f32_to_half(5.960_464_5e-8), 1);
+        // Halfway between two halves rounds to the even one.
+        assert_eq!(f32_to_half(1.0 + 1.0 / 2048.0), 0x3c00);
+        assert_eq!(f32_to_half(1.0 + 3.0 / 2048.0), 0x3c02);
+    }
+
+    fn gray(values: &[f32]) -> Vec<Texel> {
+        values.iter().map(|&v| [v, v, v, 1.0]).collect()
+    }
+
+    #[test]
+    fn box_averages() {
+        #[rustfmt::skip]
+        let texels = gray(&[
+            0.0, 1.0, 2.0, 3.0,
+            4.0, 5.0, 6.0, 7.0,
+            8.0, 9.0, 10.0, 11.0,
+            12.0, 13.0, 14.0, 15.0,
+        ]);
+        let out = downsample(&texels, 4, 4, 2, 2, MipFilter::Box);
+        assert_eq!(out, gray(&[2.5, 4.5, 10.5, 12.5]));
+
+        // Odd sizes average all texels the destination texel covers.
+        let out = downsample(&gray(&[1.0, 2.0, 6.0]), 3, 1, 1, 1, MipFilter::Box);
+        assert_eq!(out, gray(&[3.0]));
+    }
+
+    #[test]
+    fn kaiser_averages() {
+        // The weights are normalized, a constant image stays constant.
+        let out = downsample(&gray(&[0.75; 64]), 8, 8, 4, 4, MipFilter::Kaiser);
+        for texel in out {
+            for (value, expected) in texel.iter().zip([0.75, 0.75, 0.75, 1.0]) {
+                assert!((value - expected).abs() < 1e-6, "{:?}", texel);
+            }
+        }
+
+        // A checkerboard is above the Nyquist frequency of the next mip, it filters to its mean.
+        let checker: Vec<f32> = (0..64).map(|i| ((i % 8 + i / 8) % 2) as f32).collect();
+        let out = downsample(&gray(&checker), 8, 8, 4, 4, MipFilter::Kaiser);
+        for texel in out {
+            assert!((texel[0] - 0.5).abs() < 0.05, "{:?}", texel);
+        }
+    }
+
+    #[test]
+    fn empty_image() {
+        for (width, height) in [(0, 2), (2, 0), (0, 0)] {
+            let result =
+                MipChain::generate(TextureFormat::RGBA8, width, height, &[], MipArgs::default());
+            assert!(
+                matches!(result, Err(TextureError::InvalidInput(_))),
+                "{}x{}: {:?}",
+                width,
+                height,
+                result
+            );
+        }
+    }
+}
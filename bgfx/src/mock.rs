@@ -0,0 +1,572 @@
+use std::cell::{Cell, RefCell};
+use std::ffi::CStr;
+use std::mem::MaybeUninit;
+use std::os::raw::{c_char, c_void};
+use std::ptr::NonNull;
+use std::sync::OnceLock;
+
+use bgfx_sys::*;
+
+use crate::backend::{for_each_function, Backend};
+
+/// Argument of a [MockCall].
+#[derive(Clone, PartialEq, Debug)]
+pub enum MockArg {
+    Int(i64),
+    Uint(u64),
+    Float(f32),
+    Bool(bool),
+    /// Index of a handle of any type.
+    Handle(u16),
+    /// Contents of a `Memory`.
+    Bytes(Vec<u8>),
+    /// Text of `dbg_text`.
+    Str(String),
+    /// Any other pointer, only its address is recorded.
+    Ptr(usize),
+}
+
+impl MockArg {
+    /// The value of an integer or handle argument.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            MockArg::Int(value) => u64::try_from(value).ok(),
+            MockArg::Uint(value) => Some(value),
+            MockArg::Handle(idx) => Some(idx as u64),
+            _ => None,
+        }
+    }
+
+    pub fn as_handle(&self) -> Option<u16> {
+        match *self {
+            MockArg::Handle(idx) => Some(idx),
+            _ => None,
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            MockArg::Bytes(bytes) => Some(bytes),
+            _ => None,
+        }
+    }
+}
+
+/// A call made through a [MockBackend]. `name` is the bgfx-sys function without the `bgfx_`
+/// prefix, f.e. `set_state` or `encoder_submit`, `args` are in the order of the C API.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MockCall {
+    pub name: &'static str,
+    pub args: Vec<MockArg>,
+}
+
+/// A draw or compute submit seen by [MockBackend::submits], with the state set before it.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MockSubmit {
+    pub view: u16,
+    pub program: u16,
+    pub state: u64,
+    pub depth: u32,
+}
+
+/// [Backend] recording every call instead of calling bgfx, for testing code built on this crate
+/// without a bgfx context.
+///
+/// Creating a resource returns a new handle, handle indices are unique across all types. Memory,
+/// transient and instance data buffers and transform matrices are backed by zeroed buffers owned
+/// by the mock, `get_caps` and `get_stats` return zeroed structs with
+/// [RendererType::Noop](crate::RendererType). Calls that only compute on their arguments, f.e.
+/// building a vertex layout or `calc_texture_size`, aren't recorded and run the Rust versions of
+/// the bgfx functions, so with the `mock-only` feature the mock works without compiling bgfx.
+///
+/// ```ignore
+/// let mock = Rc::new(MockBackend::new());
+/// let _guard = set_backend(mock.clone());
+///
+/// material.submit(3);
+///
+/// let submit = mock.submits()[0];
+/// assert_eq!(submit.view, 3);
+/// assert_eq!(submit.state & StateBlendFlags::ALPHA.bits(), StateBlendFlags::ALPHA.bits());
+/// ```
+#[allow(clippy::vec_box)]
+pub struct MockBackend {
+    calls: RefCell<Vec<MockCall>>,
+    // Boxed so the pointers handed out stay valid when the vectors grow.
+    buffers: RefCell<Vec<Box<[u8]>>>,
+    memories: RefCell<Vec<Box<bgfx_memory_t>>>,
+    next_handle: Cell<u16>,
+    frame: Cell<u32>,
+}
+
+/// The zeroed structs returned by `get_caps` and friends. Callers keep them as `&'static`, so
+/// they are shared by all mocks instead of being owned by one.
+struct Zeroed {
+    caps: bgfx_caps_t,
+    stats: bgfx_stats_t,
+    internal_data: bgfx_internal_data_t,
+}
+
+// The pointers in the structs are null and never written to.
+unsafe impl Send for Zeroed {}
+unsafe impl Sync for Zeroed {}
+
+fn zeroed_structs() -> &'static Zeroed {
+    static ZEROED: OnceLock<Zeroed> = OnceLock::new();
+    ZEROED.get_or_init(|| Zeroed {
+        caps: zeroed(),
+        stats: zeroed(),
+        internal_data: zeroed(),
+    })
+}
+
+impl MockBackend {
+    pub fn new() -> MockBackend {
+        MockBackend {
+            calls: RefCell::new(Vec::new()),
+            buffers: RefCell::new(Vec::new()),
+            memories: RefCell::new(Vec::new()),
+            next_handle: Cell::new(0),
+            frame: Cell::new(0),
+        }
+    }
+
+    /// All calls in the order they were made.
+    pub fn calls(&self) -> Vec<MockCall> {
+        self.calls.borrow().clone()
+    }
+
+    /// The calls to the function `name`, see [MockCall::name].
+    pub fn calls_to(&self, name: &str) -> Vec<MockCall> {
+        self.calls
+            .borrow()
+            .iter()
+            .filter(|call| call.name == name)
+            .cloned()
+            .collect()
+    }
+
+    pub fn count(&self, name: &str) -> usize {
+        self.calls
+            .borrow()
+            .iter()
+            .filter(|call| call.name == name)
+            .count()
+    }
+
+    /// Forgets the recorded calls, handles and buffers stay valid.
+    pub fn clear(&self) {
+        self.calls.borrow_mut().clear();
+    }
+
+    /// The `submit` and `encoder_submit` calls with the state set by the preceding `set_state`.
+    /// Like in bgfx the state goes back to [StateFlags::DEFAULT](crate::StateFlags) after a
+    /// submit.
+    pub fn submits(&self) -> Vec<MockSubmit> {
+        let default = BGFX_STATE_DEFAULT;
+        let mut state = default;
+        let mut encoder_state = default;
+        let mut submits = Vec::new();
+
+        let arg = |call: &MockCall, index: usize| call.args[index].as_u64().unwrap_or(0);
+        for call in self.calls.borrow().iter() {
+            match call.name {
+                "set_state" => state = arg(call, 0),
+                "encoder_set_state" => encoder_state = arg(call, 1),
+                "submit" => {
+                    submits.push(MockSubmit {
+                        view: arg(call, 0) as u16,
+                        program: arg(call, 1) as u16,
+                        state,
+                        depth: arg(call, 2) as u32,
+                    });
+                    state = default;
+                }
+                "encoder_submit" => {
+                    submits.push(MockSubmit {
+                        view: arg(call, 1) as u16,
+                        program: arg(call, 2) as u16,
+                        state: encoder_state,
+                        depth: arg(call, 3) as u32,
+                    });
+                    encoder_state = default;
+                }
+                _ => {}
+            }
+        }
+        submits
+    }
+
+    fn record(&self, name: &'static str, args: Vec<MockArg>) {
+        self.calls.borrow_mut().push(MockCall { name, args });
+    }
+
+    fn next_handle(&self) -> u16 {
+        let idx = self.next_handle.get();
+        self.next_handle.set(idx.wrapping_add(1));
+        idx
+    }
+
+    /// Zeroed buffer living as long as the mock.
+    fn alloc(&self, size: usize) -> *mut u8 {
+        let mut buffer = vec![0u8; size].into_boxed_slice();
+        let data = buffer.as_mut_ptr();
+        self.buffers.borrow_mut().push(buffer);
+        data
+    }
+
+    unsafe fn memory(&self, data: *const c_void, size: u32) -> *const bgfx_memory_t {
+        let copy = self.alloc(size as usize);
+        if !data.is_null() {
+            std::ptr::copy_nonoverlapping(data as *const u8, copy, size as usize);
+        }
+        let memory = Box::new(bgfx_memory_t { data: copy, size });
+        let handle = &*memory as *const bgfx_memory_t;
+        self.memories.borrow_mut().push(memory);
+        handle
+    }
+
+    unsafe fn transient_vertices(
+        &self,
+        tvb: *mut bgfx_transient_vertex_buffer_t,
+        num: u32,
+        layout: *const bgfx_vertex_layout_t,
+    ) {
+        let stride = (*layout).stride;
+        let size = num * stride as u32;
+        *tvb = bgfx_transient_vertex_buffer_t {
+            data: self.alloc(size as usize),
+            size,
+            startVertex: 0,
+            stride,
+            handle: Fake::fake(self),
+            layoutHandle: Fake::fake(self),
+        };
+    }
+
+    unsafe fn transient_indices(
+        &self,
+        tib: *mut bgfx_transient_index_buffer_t,
+        num: u32,
+        index32: bool,
+    ) {
+        let size = num * if index32 { 4 } else { 2 };
+        *tib = bgfx_transient_index_buffer_t {
+            data: self.alloc(size as usize),
+            size,
+            startIndex: 0,
+            handle: Fake::fake(self),
+            isIndex16: !index32,
+        };
+    }
+
+    unsafe fn transform(&self, transform: *mut bgfx_transform_t, num: u16) -> u32 {
+        let size = num as usize * 16 * std::mem::size_of::<f32>();
+        *transform = bgfx_transform_t {
+            data: self.alloc(size) as *mut f32,
+            num,
+        };
+        0
+    }
+}
+
+impl Default for MockBackend {
+    fn default() -> MockBackend {
+        MockBackend::new()
+    }
+}
+
+fn zeroed<T>() -> T {
+    unsafe { MaybeUninit::<T>::zeroed().assume_init() }
+}
+
+/// Conversion of a C API argument to a [MockArg].
+trait Record {
+    fn record(self) -> MockArg;
+}
+
+macro_rules! record_as {
+    ($variant:ident($as:ty): $($ty:ty),*) => {
+        $(impl Record for $ty {
+            fn record(self) -> MockArg {
+                MockArg::$variant(self as $as)
+            }
+        })*
+    };
+}
+
+record_as!(Int(i64): i32);
+record_as!(Uint(u64): u8, u16, u32, u64);
+record_as!(Float(f32): f32);
+record_as!(Bool(bool): bool);
+
+macro_rules! record_ptr {
+    ($($ty:ty),*) => {
+        $(impl Record for $ty {
+            fn record(self) -> MockArg {
+                MockArg::Ptr(self as usize)
+            }
+        })*
+    };
+}
+
+record_ptr!(
+    *const bgfx_attachment_t,
+    *const bgfx_init_t,
+    *const bgfx_instance_data_buffer_t,
+    *const bgfx_platform_data_t,
+    *const bgfx_texture_handle_t,
+    *const bgfx_transient_index_buffer_t,
+    *const bgfx_transient_vertex_buffer_t,
+    *const bgfx_vertex_layout_t,
+    *const c_char,
+    *const c_void,
+    *mut bgfx_encoder_t,
+    *mut bgfx_instance_data_buffer_t,
+    *mut bgfx_texture_info_t,
+    *mut bgfx_transform_t,
+    *mut bgfx_transient_index_buffer_t,
+    *mut bgfx_transient_vertex_buffer_t,
+    *mut bgfx_uniform_handle_t,
+    *mut bgfx_uniform_info_t,
+    *mut c_void,
+    *mut i32
+);
+
+impl Record for *const bgfx_memory_t {
+    fn record(self) -> MockArg {
+        match self.is_null() {
+            true => MockArg::Ptr(0),
+            false => unsafe {
+                let memory = &*self;
+                let bytes = std::slice::from_raw_parts(memory.data, memory.size as usize);
+                MockArg::Bytes(bytes.to_vec())
+            },
+        }
+    }
+}
+
+/// Result returned by a recorded call.
+trait Fake {
+    fn fake(mock: &MockBackend) -> Self;
+}
+
+macro_rules! fake_value {
+    ($value:expr => $($ty:ty),*) => {
+        $(impl Fake for $ty {
+            fn fake(_mock: &MockBackend) -> $ty {
+                $value
+            }
+        })*
+    };
+}
+
+fake_value!(() => ());
+fake_value!(true => bool);
+// Also covers the enums, 0 is `RendererType::Noop`.
+fake_value!(0 => u16, u32);
+
+macro_rules! handles {
+    ($($ty:ident),*) => {
+        $(
+            impl Record for $ty {
+                fn record(self) -> MockArg {
+                    MockArg::Handle(self.idx)
+                }
+            }
+
+            impl Fake for $ty {
+                fn fake(mock: &MockBackend) -> $ty {
+                    $ty { idx: mock.next_handle() }
+                }
+            }
+        )*
+    };
+}
+
+handles!(
+    bgfx_dynamic_index_buffer_handle_t,
+    bgfx_dynamic_vertex_buffer_handle_t,
+    bgfx_frame_buffer_handle_t,
+    bgfx_index_buffer_handle_t,
+    bgfx_indirect_buffer_handle_t,
+    bgfx_occlusion_query_handle_t,
+    bgfx_program_handle_t,
+    bgfx_shader_handle_t,
+    bgfx_texture_handle_t,
+    bgfx_uniform_handle_t,
+    bgfx_vertex_buffer_handle_t,
+    bgfx_vertex_layout_handle_t
+);
+
+impl Fake for *const bgfx_caps_t {
+    fn fake(_mock: &MockBackend) -> *const bgfx_caps_t {
+        &zeroed_structs().caps
+    }
+}
+
+impl Fake for *const bgfx_stats_t {
+    fn fake(_mock: &MockBackend) -> *const bgfx_stats_t {
+        &zeroed_structs().stats
+    }
+}
+
+impl Fake for *const bgfx_internal_data_t {
+    fn fake(_mock: &MockBackend) -> *const bgfx_internal_data_t {
+        &zeroed_structs().internal_data
+    }
+}
+
+impl Fake for *mut bgfx_encoder_t {
+    // `Encoder` has no fields, the pointer is never read.
+    fn fake(_mock: &MockBackend) -> *mut bgfx_encoder_t {
+        NonNull::dangling().as_ptr()
+    }
+}
+
+macro_rules! mock_backend {
+    (
+        pure { $(fn $pure:ident = $pure_ffi:ident($($pure_arg:ident: $pure_ty:ty),*) $(-> $pure_ret:ty)?;)* }
+        custom { $($custom:tt)* }
+        recorded { $(fn $name:ident = $ffi:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)?;)* }
+    ) => {
+        impl Backend for MockBackend {
+            $(
+                #[allow(clippy::too_many_arguments)]
+                unsafe fn $pure(&self, $($pure_arg: $pure_ty),*) $(-> $pure_ret)? {
+                    crate::pure::$pure($($pure_arg),*)
+                }
+            )*
+
+            $(
+                #[allow(clippy::too_many_arguments)]
+                unsafe fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                    self.record(stringify!($name), vec![$(Record::record($arg)),*]);
+                    Fake::fake(self)
+                }
+            )*
+
+            unsafe fn copy(&self, data: *const c_void, size: u32) -> *const bgfx_memory_t {
+                let memory = self.memory(data, size);
+                self.record("copy", vec![memory.record()]);
+                memory
+            }
+
+            unsafe fn make_ref(&self, data: *const c_void, size: u32) -> *const bgfx_memory_t {
+                let memory = self.memory(data, size);
+                self.record("make_ref", vec![memory.record()]);
+                memory
+            }
+
+            unsafe fn frame(&self, capture: bool) -> u32 {
+                self.record("frame", vec![capture.record()]);
+                let frame = self.frame.get();
+                self.frame.set(frame + 1);
+                frame
+            }
+
+            unsafe fn alloc_transient_vertex_buffer(
+                &self,
+                tvb: *mut bgfx_transient_vertex_buffer_t,
+                num: u32,
+                layout: *const bgfx_vertex_layout_t,
+            ) {
+                self.record("alloc_transient_vertex_buffer", vec![num.record()]);
+                self.transient_vertices(tvb, num, layout);
+            }
+
+            unsafe fn alloc_transient_index_buffer(
+                &self,
+                tib: *mut bgfx_transient_index_buffer_t,
+                num: u32,
+                index32: bool,
+            ) {
+                self.record(
+                    "alloc_transient_index_buffer",
+                    vec![num.record(), index32.record()],
+                );
+                self.transient_indices(tib, num, index32);
+            }
+
+            unsafe fn alloc_transient_buffers(
+                &self,
+                tvb: *mut bgfx_transient_vertex_buffer_t,
+                layout: *const bgfx_vertex_layout_t,
+                num_vertices: u32,
+                tib: *mut bgfx_transient_index_buffer_t,
+                num_indices: u32,
+                index32: bool,
+            ) -> bool {
+                self.record(
+                    "alloc_transient_buffers",
+                    vec![num_vertices.record(), num_indices.record(), index32.record()],
+                );
+                self.transient_vertices(tvb, num_vertices, layout);
+                self.transient_indices(tib, num_indices, index32);
+                true
+            }
+
+            unsafe fn alloc_transform(&self, transform: *mut bgfx_transform_t, num: u16) -> u32 {
+                self.record("alloc_transform", vec![num.record()]);
+                self.transform(transform, num)
+            }
+
+            unsafe fn encoder_alloc_transform(
+                &self,
+                encoder: *mut bgfx_encoder_t,
+                transform: *mut bgfx_transform_t,
+                num: u16,
+            ) -> u32 {
+                self.record(
+                    "encoder_alloc_transform",
+                    vec![encoder.record(), num.record()],
+                );
+                self.transform(transform, num)
+            }
+
+            unsafe fn alloc_instance_data_buffer(
+                &self,
+                idb: *mut bgfx_instance_data_buffer_t,
+                num: u32,
+                stride: u16,
+            ) {
+                self.record(
+                    "alloc_instance_data_buffer",
+                    vec![num.record(), stride.record()],
+                );
+                let size = num * stride as u32;
+                *idb = bgfx_instance_data_buffer_t {
+                    data: self.alloc(size as usize),
+                    size,
+                    offset: 0,
+                    num,
+                    stride,
+                    handle: Fake::fake(self),
+                };
+            }
+
+            unsafe fn get_avail_transient_vertex_buffer(
+                &self,
+                num: u32,
+                _layout: *const bgfx_vertex_layout_t,
+            ) -> u32 {
+                num
+            }
+
+            unsafe fn get_avail_transient_index_buffer(&self, num: u32, _index32: bool) -> u32 {
+                num
+            }
+
+            unsafe fn get_avail_instance_data_buffer(&self, num: u32, _stride: u16) -> u32 {
+                num
+            }
+
+            fn dbg_text(&self, x: u16, y: u16, attr: u8, text: &CStr) {
+                let text = MockArg::Str(text.to_string_lossy().into_owned());
+                self.record("dbg_text", vec![x.record(), y.record(), attr.record(), text]);
+            }
+        }
+    };
+}
+
+for_each_function!(mock_backend);
@@ -0,0 +1,367 @@
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::bgfx::{get_stats, ViewId};
+use crate::stats::StatsSnapshot;
+
+/// Min, average and max of a value over the recorded frames.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct Summary<T> {
+    pub min: T,
+    pub avg: T,
+    pub max: T,
+}
+
+impl Summary<Duration> {
+    fn of(values: impl Iterator<Item = Duration>) -> Self {
+        let mut summary = Summary {
+            min: Duration::MAX,
+            avg: Duration::ZERO,
+            max: Duration::ZERO,
+        };
+        let mut count = 0u32;
+        let mut total = Duration::ZERO;
+        for value in values {
+            summary.min = summary.min.min(value);
+            summary.max = summary.max.max(value);
+            total += value;
+            count += 1;
+        }
+
+        if count == 0 {
+            return Summary::default();
+        }
+        summary.avg = total / count;
+        summary
+    }
+}
+
+impl Summary<u32> {
+    fn of(values: impl Iterator<Item = u32>) -> Self {
+        let mut summary = Summary {
+            min: u32::MAX,
+            avg: 0,
+            max: 0,
+        };
+        let mut count = 0u64;
+        let mut total = 0u64;
+        for value in values {
+            summary.min = summary.min.min(value);
+            summary.max = summary.max.max(value);
+            total += value as u64;
+            count += 1;
+        }
+
+        if count == 0 {
+            return Summary::default();
+        }
+        summary.avg = (total / count) as u32;
+        summary
+    }
+}
+
+/// Frame timings and counters over the recorded frames, see [FrameProfiler::summary].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct FrameSummary {
+    pub frames: usize,
+    pub cpu_frame_time: Summary<Duration>,
+    pub cpu_submit_time: Summary<Duration>,
+    pub gpu_frame_time: Summary<Duration>,
+    pub num_draw: Summary<u32>,
+    pub num_compute: Summary<u32>,
+    pub num_blit: Summary<u32>,
+    pub views: Vec<ViewSummary>,
+}
+
+/// Timings of a single view, only over the frames that submitted to it.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ViewSummary {
+    pub view: ViewId,
+    /// Name of the view in the most recent frame.
+    pub name: String,
+    pub frames: usize,
+    pub cpu_time: Summary<Duration>,
+    pub gpu_time: Summary<Duration>,
+}
+
+/// Keeps the [StatsSnapshot]s of the last `capacity` frames.
+///
+/// Per view timings are only reported by bgfx while [DebugFlags::PROFILER](crate::DebugFlags)
+/// is enabled.
+///
+/// ```ignore
+/// set_debug(DebugFlags::PROFILER.bits());
+/// let mut profiler = FrameProfiler::new(300);
+///
+/// loop {
+///     ...
+///     frame(false);
+///     profiler.sample();
+/// }
+///
+/// println!("{:#?}", profiler.summary());
+/// profiler.save_chrome_trace("frames.json")?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct FrameProfiler {
+    frames: VecDeque<StatsSnapshot>,
+    capacity: usize,
+    total_frames: u64,
+}
+
+impl FrameProfiler {
+    pub fn new(capacity: usize) -> FrameProfiler {
+        let capacity = capacity.max(1);
+        FrameProfiler {
+            frames: VecDeque::with_capacity(capacity),
+            capacity,
+            total_frames: 0,
+        }
+    }
+
+    /// Records the stats of the last frame. Call this once after every `frame`.
+    pub fn sample(&mut self) {
+        self.push(get_stats().snapshot());
+    }
+
+    /// Records a snapshot, dropping the oldest one when the buffer is full.
+    pub fn push(&mut self, snapshot: StatsSnapshot) {
+        if self.frames.len() == self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(snapshot);
+        self.total_frames += 1;
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Recorded frames, oldest first.
+    pub fn frames(&self) -> impl Iterator<Item = &StatsSnapshot> {
+        self.frames.iter()
+    }
+
+    pub fn last(&self) -> Option<&StatsSnapshot> {
+        self.frames.back()
+    }
+
+    /// Min, average and max timings and counts over the recorded frames.
+    pub fn summary(&self) -> FrameSummary {
+        let mut view_ids: Vec<ViewId> = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.views.iter().map(|view| view.view))
+            .collect();
+        view_ids.sort_unstable();
+        view_ids.dedup();
+
+        let views = view_ids
+            .into_iter()
+            .map(|id| {
+                let samples = || {
+                    self.frames
+                        .iter()
+                        .flat_map(move |frame| frame.views.iter().filter(move |v| v.view == id))
+                };
+                ViewSummary {
+                    view: id,
+                    name: samples().last().map(|v| v.name.clone()).unwrap_or_default(),
+                    frames: samples().count(),
+                    cpu_time: Summary::<Duration>::of(samples().map(|v| v.cpu_time)),
+                    gpu_time: Summary::<Duration>::of(samples().map(|v| v.gpu_time)),
+                }
+            })
+            .collect();
+
+        FrameSummary {
+            frames: self.frames.len(),
+            cpu_frame_time: Summary::<Duration>::of(self.frames().map(|f| f.cpu_frame_time)),
+            cpu_submit_time: Summary::<Duration>::of(self.frames().map(|f| f.cpu_submit_time)),
+            gpu_frame_time: Summary::<Duration>::of(self.frames().map(|f| f.gpu_frame_time)),
+            num_draw: Summary::<u32>::of(self.frames().map(|f| f.num_draw)),
+            num_compute: Summary::<u32>::of(self.frames().map(|f| f.num_compute)),
+            num_blit: Summary::<u32>::of(self.frames().map(|f| f.num_blit)),
+            views,
+        }
+    }
+
+    /// Writes the recorded frames in the Chrome trace event format, viewable in
+    /// `chrome://tracing` or Perfetto.
+    ///
+    /// CPU spans (frames and views) are on one track and GPU spans on another. Both timelines
+    /// start at the oldest recorded frame, the CPU and GPU clocks aren't synchronized.
+    pub fn write_chrome_trace<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        const CPU: u32 = 1;
+        const GPU: u32 = 2;
+
+        let cpu_start = self.frames.front().map(|f| f.cpu_begin).unwrap_or_default();
+        let gpu_start = self.frames.front().map(|f| f.gpu_begin).unwrap_or_default();
+        let first_frame = self.total_frames - self.frames.len() as u64;
+
+        let mut trace = TraceWriter::new(writer)?;
+        trace.thread_name(CPU, "CPU")?;
+        trace.thread_name(GPU, "GPU")?;
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            let name = format!("Frame {}", first_frame + i as u64);
+            let cpu_ts = frame.cpu_begin.saturating_sub(cpu_start);
+            let gpu_ts = frame.gpu_begin.saturating_sub(gpu_start);
+
+            trace.span(CPU, "frame", &name, cpu_ts, frame.cpu_submit_time)?;
+            trace.span(GPU, "frame", &name, gpu_ts, frame.gpu_frame_time)?;
+            trace.counter(
+                "Submits",
+                cpu_ts,
+                &[
+                    ("draw", frame.num_draw),
+                    ("compute", frame.num_compute),
+                    ("blit", frame.num_blit),
+                ],
+            )?;
+
+            for view in &frame.views {
+                let name = match view.name.is_empty() {
+                    true => format!("View {}", view.view),
+                    false => view.name.clone(),
+                };
+                let cpu_ts = view.cpu_begin.saturating_sub(cpu_start);
+                let gpu_ts = view.gpu_begin.saturating_sub(gpu_start);
+                trace.span(CPU, "view", &name, cpu_ts, view.cpu_time)?;
+                trace.span(GPU, "view", &name, gpu_ts, view.gpu_time)?;
+            }
+        }
+
+        trace.finish()
+    }
+
+    /// Writes [FrameProfiler::write_chrome_trace] to a file.
+    pub fn save_chrome_trace(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_chrome_trace(&mut writer)?;
+        writer.flush()
+    }
+}
+
+impl Default for FrameProfiler {
+    /// Records the last 120 frames.
+    fn default() -> FrameProfiler {
+        FrameProfiler::new(120)
+    }
+}
+
+/// Minimal writer for the JSON object format of the trace event format.
+struct TraceWriter<'a, W: Write> {
+    writer: &'a mut W,
+    first: bool,
+}
+
+impl<'a, W: Write> TraceWriter<'a, W> {
+    fn new(writer: &'a mut W) -> io::Result<Self> {
+        write!(writer, "{{\"displayTimeUnit\":\"ms\",\"traceEvents\":[")?;
+        Ok(TraceWriter {
+            writer,
+            first: true,
+        })
+    }
+
+    fn separator(&mut self) -> io::Result<()> {
+        if !self.first {
+            write!(self.writer, ",")?;
+        }
+        self.first = false;
+        writeln!(self.writer)
+    }
+
+    fn thread_name(&mut self, tid: u32, name: &str) -> io::Result<()> {
+        self.separator()?;
+        write!(
+            self.writer,
+            "{{\"name\":\"thread_name\",\"ph\":\"M\",\"pid\":1,\"tid\":{},\"args\":{{\"name\":\"{}\"}}}}",
+            tid,
+            JsonStr(name)
+        )
+    }
+
+    fn span(
+        &mut self,
+        tid: u32,
+        cat: &str,
+        name: &str,
+        ts: Duration,
+        dur: Duration,
+    ) -> io::Result<()> {
+        self.separator()?;
+        write!(
+            self.writer,
+            "{{\"name\":\"{}\",\"cat\":\"{}\",\"ph\":\"X\",\"pid\":1,\"tid\":{},\"ts\":{:.3},\"dur\":{:.3}}}",
+            JsonStr(name),
+            cat,
+            tid,
+            micros(ts),
+            micros(dur)
+        )
+    }
+
+    fn counter(&mut self, name: &str, ts: Duration, values: &[(&str, u32)]) -> io::Result<()> {
+        self.separator()?;
+        write!(
+            self.writer,
+            "{{\"name\":\"{}\",\"ph\":\"C\",\"pid\":1,\"ts\":{:.3},\"args\":{{",
+            JsonStr(name),
+            micros(ts)
+        )?;
+        for (i, (key, value)) in values.iter().enumerate() {
+            if i > 0 {
+                write!(self.writer, ",")?;
+            }
+            write!(self.writer, "\"{}\":{}", JsonStr(key), value)?;
+        }
+        write!(self.writer, "}}}}")
+    }
+
+    fn finish(self) -> io::Result<()> {
+        writeln!(self.writer, "\n]}}")
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+/// Escapes a string for use inside JSON quotes.
+struct JsonStr<'a>(&'a str);
+
+impl std::fmt::Display for JsonStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,252 @@
+use std::ptr;
+
+use bgfx_sys::*;
+
+use crate::bgfx::{TextureFormat, TextureInfo};
+
+// Rust versions of the bgfx functions that only compute on their arguments, so a
+// [MockBackend](crate::MockBackend) doesn't need bgfx for them. They give the same results as
+// bgfx, see `bgfx.cpp` and `vertexlayout.cpp`.
+
+/// `Attachment::init`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn attachment_init(
+    this: *mut bgfx_attachment_t,
+    handle: bgfx_texture_handle_t,
+    access: bgfx_access_t,
+    layer: u16,
+    num_layers: u16,
+    mip: u16,
+    resolve: u8,
+) {
+    *this = bgfx_attachment_t {
+        access,
+        handle,
+        mip,
+        layer,
+        numLayers: num_layers,
+        resolve,
+    };
+}
+
+/// `calcTextureSize`, through [TextureInfo::calc].
+#[allow(clippy::too_many_arguments)]
+pub(crate) unsafe fn calc_texture_size(
+    info: *mut bgfx_texture_info_t,
+    width: u16,
+    height: u16,
+    depth: u16,
+    cube_map: bool,
+    has_mips: bool,
+    num_layers: u16,
+    format: bgfx_texture_format_t,
+) {
+    let format = TextureFormat::all()
+        .nth(format as usize)
+        .unwrap_or(TextureFormat::Unknown);
+    let calc = TextureInfo::calc(width, height, depth, cube_map, has_mips, num_layers, format);
+    *info = bgfx_texture_info_t {
+        format: calc.format as bgfx_texture_format_t,
+        storageSize: calc.storage_size,
+        width: calc.width,
+        height: calc.height,
+        depth: calc.depth,
+        numLayers: calc.num_layers,
+        numMips: calc.num_mips,
+        bitsPerPixel: calc.bits_per_pixel,
+        cubeMap: calc.cube_map,
+    };
+}
+
+/// The defaults of `Init::Init` with bgfx's default limits.
+pub(crate) unsafe fn init_ctor(init: *mut bgfx_init_t) {
+    let debug = cfg!(feature = "debug");
+    *init = bgfx_init_t {
+        type_: BGFX_RENDERER_TYPE_COUNT,
+        vendorId: BGFX_PCI_ID_NONE as u16,
+        deviceId: 0,
+        capabilities: u64::MAX,
+        debug,
+        profile: debug,
+        platformData: bgfx_platform_data_t {
+            ndt: ptr::null_mut(),
+            nwh: ptr::null_mut(),
+            context: ptr::null_mut(),
+            backBuffer: ptr::null_mut(),
+            backBufferDS: ptr::null_mut(),
+        },
+        resolution: bgfx_resolution_t {
+            format: BGFX_TEXTURE_FORMAT_RGBA8,
+            width: 1280,
+            height: 720,
+            reset: BGFX_RESET_NONE,
+            numBackBuffers: 2,
+            maxFrameLatency: 0,
+        },
+        limits: bgfx_init_limits_t {
+            maxEncoders: if cfg!(feature = "multithreaded") {
+                8
+            } else {
+                1
+            },
+            minResourceCbSize: 64 << 10,
+            transientVbSize: 6 << 20,
+            transientIbSize: 2 << 20,
+        },
+        callback: ptr::null_mut(),
+        allocator: ptr::null_mut(),
+    };
+}
+
+/// Size in bytes of 1 to 4 components of each attribute type, for Direct3D like renderers and
+/// for OpenGL like ones.
+const ATTRIB_TYPE_SIZE_D3D: [[u8; 4]; 5] = [
+    [4, 4, 4, 4],   // Uint8
+    [4, 4, 4, 4],   // Uint10
+    [4, 4, 8, 8],   // Int16
+    [4, 4, 8, 8],   // Half
+    [4, 8, 12, 16], // Float
+];
+const ATTRIB_TYPE_SIZE_GL: [[u8; 4]; 5] = [
+    [1, 2, 4, 4],   // Uint8
+    [4, 4, 4, 4],   // Uint10
+    [2, 4, 8, 8],   // Int16
+    [2, 4, 8, 8],   // Half
+    [4, 8, 12, 16], // Float
+];
+
+/// `VertexLayout::begin`, the renderer is kept in `hash` until `end`.
+pub(crate) unsafe fn vertex_layout_begin(
+    this: *mut bgfx_vertex_layout_t,
+    renderer_type: bgfx_renderer_type_t,
+) -> *mut bgfx_vertex_layout_t {
+    let layout = &mut *this;
+    layout.hash = renderer_type;
+    layout.stride = 0;
+    layout.attributes = [u16::MAX; BGFX_ATTRIB_COUNT as usize];
+    layout.offset = [0; BGFX_ATTRIB_COUNT as usize];
+    this
+}
+
+/// `VertexLayout::add`.
+pub(crate) unsafe fn vertex_layout_add(
+    this: *mut bgfx_vertex_layout_t,
+    attrib: bgfx_attrib_t,
+    num: u8,
+    type_: bgfx_attrib_type_t,
+    normalized: bool,
+    as_int: bool,
+) -> *mut bgfx_vertex_layout_t {
+    let layout = &mut *this;
+    // only the integer types can be read as integers
+    let as_int = as_int && type_ <= BGFX_ATTRIB_TYPE_INT16;
+    layout.attributes[attrib as usize] = (normalized as u16) << 7
+        | ((type_ as u16) & 7) << 3
+        | (num.wrapping_sub(1) as u16 & 3)
+        | (as_int as u16) << 8;
+
+    let sizes = match layout.hash {
+        BGFX_RENDERER_TYPE_METAL
+        | BGFX_RENDERER_TYPE_NVN
+        | BGFX_RENDERER_TYPE_OPENGLES
+        | BGFX_RENDERER_TYPE_OPENGL => &ATTRIB_TYPE_SIZE_GL,
+        _ => &ATTRIB_TYPE_SIZE_D3D,
+    };
+    layout.offset[attrib as usize] = layout.stride;
+    layout.stride += sizes[type_ as usize][(num as usize).wrapping_sub(1) & 3] as u16;
+    this
+}
+
+/// `VertexLayout::skip`.
+pub(crate) unsafe fn vertex_layout_skip(
+    this: *mut bgfx_vertex_layout_t,
+    num: u8,
+) -> *mut bgfx_vertex_layout_t {
+    (*this).stride += num as u16;
+    this
+}
+
+/// `VertexLayout::end`, hashes the layout like bx's `HashMurmur2A`.
+pub(crate) unsafe fn vertex_layout_end(this: *mut bgfx_vertex_layout_t) {
+    let layout = &mut *this;
+    let mut hash = Murmur2A::new();
+    for value in layout.attributes.iter().chain(&layout.offset) {
+        hash.add(&value.to_ne_bytes());
+    }
+    hash.add(&layout.stride.to_ne_bytes());
+    layout.hash = hash.end();
+}
+
+/// `VertexLayout::decode`.
+pub(crate) unsafe fn vertex_layout_decode(
+    this: *const bgfx_vertex_layout_t,
+    attrib: bgfx_attrib_t,
+    num: *mut u8,
+    type_: *mut bgfx_attrib_type_t,
+    normalized: *mut bool,
+    as_int: *mut bool,
+) {
+    let value = (*this).attributes[attrib as usize];
+    *num = (value & 3) as u8 + 1;
+    *type_ = ((value >> 3) & 7) as bgfx_attrib_type_t;
+    *normalized = value & (1 << 7) != 0;
+    *as_int = value & (1 << 8) != 0;
+}
+
+/// `VertexLayout::has`.
+pub(crate) unsafe fn vertex_layout_has(
+    this: *const bgfx_vertex_layout_t,
+    attrib: bgfx_attrib_t,
+) -> bool {
+    (*this).attributes[attrib as usize] != u16::MAX
+}
+
+/// bx's incremental MurmurHash2A with a seed of 0.
+struct Murmur2A {
+    hash: u32,
+    tail: u32,
+    count: u32,
+    size: u32,
+}
+
+impl Murmur2A {
+    const M: u32 = 0x5bd1e995;
+
+    fn new() -> Murmur2A {
+        Murmur2A {
+            hash: 0,
+            tail: 0,
+            count: 0,
+            size: 0,
+        }
+    }
+
+    fn mix(hash: &mut u32, mut k: u32) {
+        k = k.wrapping_mul(Murmur2A::M);
+        k ^= k >> 24;
+        k = k.wrapping_mul(Murmur2A::M);
+        *hash = hash.wrapping_mul(Murmur2A::M) ^ k;
+    }
+
+    fn add(&mut self, data: &[u8]) {
+        self.size += data.len() as u32;
+        for &byte in data {
+            self.tail |= (byte as u32) << (self.count * 8);
+            self.count += 1;
+            if self.count == 4 {
+                Murmur2A::mix(&mut self.hash, self.tail);
+                self.tail = 0;
+                self.count = 0;
+            }
+        }
+    }
+
+    fn end(mut self) -> u32 {
+        Murmur2A::mix(&mut self.hash, self.tail);
+        Murmur2A::mix(&mut self.hash, self.size);
+        self.hash ^= self.hash >> 13;
+        self.hash = self.hash.wrapping_mul(Murmur2A::M);
+        self.hash ^= self.hash >> 15;
+        self.hash
+    }
+}
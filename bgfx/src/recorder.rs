@@ -0,0 +1,1461 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::ffi::CStr;
+use std::mem;
+use std::os::raw::{c_char, c_void};
+use std::slice;
+
+use bgfx_sys::*;
+
+use crate::backend::{Backend, FfiBackend};
+use crate::recording::{uniform_size, Command, HandleKind, LayoutData, Recording};
+
+/// [Backend] forwarding every call to another backend while keeping what's needed to capture a
+/// frame as a [Recording].
+///
+/// Resource creation is tracked from the moment the recorder is installed, so install it before
+/// loading anything. The data of live resources is kept in memory, which makes the recorder a
+/// debugging tool rather than something to leave on in shipping builds.
+///
+/// Calls that aren't recorded go to the inner backend unchanged. Views, state, buffers, uniforms,
+/// textures, submits and dispatches are recorded, including the encoder variants on the
+/// recording thread. Compute bindings, indirect buffers, occlusion queries and cached scissors
+/// and transforms aren't.
+///
+/// ```ignore
+/// let recorder = Rc::new(Recorder::new());
+/// let _guard = set_backend(recorder.clone());
+///
+/// loop {
+///     if key_pressed(Key::F12) {
+///         recorder.capture_next_frame();
+///     }
+///     draw_scene();
+///     frame(false);
+///
+///     if let Some(recording) = recorder.take_recording() {
+///         recording.save("frame.bgfxrec")?;
+///     }
+/// }
+/// ```
+pub struct Recorder<B: Backend = FfiBackend> {
+    inner: B,
+    state: RefCell<State>,
+}
+
+impl Recorder {
+    /// Records the calls going to bgfx.
+    pub fn new() -> Recorder {
+        Recorder::with_backend(FfiBackend)
+    }
+}
+
+impl Default for Recorder {
+    fn default() -> Recorder {
+        Recorder::new()
+    }
+}
+
+impl<B: Backend> Recorder<B> {
+    /// Records the calls going to `inner`.
+    pub fn with_backend(inner: B) -> Recorder<B> {
+        Recorder {
+            inner,
+            state: RefCell::new(State::default()),
+        }
+    }
+
+    /// Captures the frame starting with the next `frame` call. The recording is available from
+    /// [Recorder::take_recording] once that frame is submitted.
+    pub fn capture_next_frame(&self) {
+        let mut state = self.state.borrow_mut();
+        if matches!(state.capture, Capture::Idle | Capture::Done(_)) {
+            state.capture = Capture::Pending;
+        }
+    }
+
+    /// The last captured frame, if it's complete.
+    pub fn take_recording(&self) -> Option<Recording> {
+        let mut state = self.state.borrow_mut();
+        match mem::replace(&mut state.capture, Capture::Idle) {
+            Capture::Done(recording) => Some(recording),
+            capture => {
+                state.capture = capture;
+                None
+            }
+        }
+    }
+
+    fn record(&self, command: Command) {
+        self.state.borrow_mut().record(command);
+    }
+
+    fn view(&self, view: u16, command: Command) {
+        self.state.borrow_mut().view(view, command);
+    }
+
+    fn create(&self, kind: HandleKind, handle: u16, deps: &[(HandleKind, u16)], command: Command) {
+        self.state.borrow_mut().create(kind, handle, deps, command);
+    }
+
+    fn update(&self, kind: HandleKind, handle: u16, command: Command) {
+        self.state.borrow_mut().update(kind, handle, command);
+    }
+
+    fn destroy(&self, kind: HandleKind, handle: u16) {
+        self.state.borrow_mut().destroy(kind, handle);
+    }
+
+    unsafe fn set_uniform_command(
+        &self,
+        handle: bgfx_uniform_handle_t,
+        value: *const c_void,
+        num: u16,
+    ) {
+        let mut state = self.state.borrow_mut();
+        // Unknown when the uniform was created before the recorder.
+        if let Some(&(uniform_type, created_num)) = state.uniforms.get(&handle.idx) {
+            let count = if num == u16::MAX { created_num } else { num };
+            let size = uniform_size(uniform_type) * count as usize;
+            let data = bytes(value as *const u8, size);
+            state.record(Command::SetUniform {
+                handle: handle.idx,
+                data,
+                num,
+            });
+        }
+    }
+
+    unsafe fn set_transient_vertex_buffer_command(
+        &self,
+        stream: u8,
+        tvb: *const bgfx_transient_vertex_buffer_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        let tvb = &*tvb;
+        let mut state = self.state.borrow_mut();
+        if let Some(&layout) = state.transient_layouts.get(&(tvb.data as usize)) {
+            state.record(Command::SetTransientVertexBuffer {
+                stream,
+                layout,
+                data: bytes(tvb.data, tvb.size as usize),
+                start_vertex,
+                num_vertices,
+            });
+        }
+    }
+
+    unsafe fn set_transient_index_buffer_command(
+        &self,
+        tib: *const bgfx_transient_index_buffer_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        let tib = &*tib;
+        self.record(Command::SetTransientIndexBuffer {
+            data: bytes(tib.data, tib.size as usize),
+            index32: !tib.isIndex16,
+            first_index,
+            num_indices,
+        });
+    }
+
+    unsafe fn set_instance_data_buffer_command(
+        &self,
+        idb: *const bgfx_instance_data_buffer_t,
+        start: u32,
+        num: u32,
+    ) {
+        let idb = &*idb;
+        self.record(Command::SetInstanceDataBuffer {
+            data: bytes(idb.data, idb.size as usize),
+            stride: idb.stride,
+            start,
+            num,
+        });
+    }
+}
+
+/// Resources alive in the recorded application, with the commands recreating them.
+#[derive(Default)]
+struct State {
+    next_seq: u64,
+    resources: HashMap<(HandleKind, u16), Resource>,
+    /// Type and number of elements of the live uniforms.
+    uniforms: HashMap<u16, (u32, u16)>,
+    reset: Option<Command>,
+    views: BTreeMap<u16, Vec<Command>>,
+    /// Layouts of the transient vertex buffers allocated this frame, by data pointer.
+    transient_layouts: HashMap<usize, LayoutData>,
+    capture: Capture,
+}
+
+struct Resource {
+    /// Creation order, resources are recreated in the same order.
+    seq: u64,
+    /// Uniforms created twice are the same handle.
+    refs: u32,
+    commands: Vec<Command>,
+}
+
+#[derive(Default)]
+enum Capture {
+    #[default]
+    Idle,
+    Pending,
+    Recording(Recording),
+    Done(Recording),
+}
+
+impl State {
+    fn record(&mut self, command: Command) {
+        if let Capture::Recording(recording) = &mut self.capture {
+            recording.frame.push(command);
+        }
+    }
+
+    fn view(&mut self, view: u16, command: Command) {
+        let commands = self.views.entry(view).or_default();
+        commands.retain(|previous| mem::discriminant(previous) != mem::discriminant(&command));
+        commands.push(command.clone());
+        self.record(command);
+    }
+
+    /// Tracks a new resource. Its commands include the commands of `deps`, f.e. the shaders of a
+    /// program, so it can be recreated after they are destroyed.
+    fn create(
+        &mut self,
+        kind: HandleKind,
+        handle: u16,
+        deps: &[(HandleKind, u16)],
+        command: Command,
+    ) {
+        self.record(command.clone());
+        if handle == u16::MAX {
+            return;
+        }
+
+        if let Some(resource) = self.resources.get_mut(&(kind, handle)) {
+            resource.refs += 1;
+            return;
+        }
+
+        let mut commands: Vec<Command> = deps
+            .iter()
+            .filter_map(|dep| self.resources.get(dep))
+            .flat_map(|dep| dep.commands.iter().cloned())
+            .collect();
+        commands.push(command);
+
+        self.next_seq += 1;
+        self.resources.insert(
+            (kind, handle),
+            Resource {
+                seq: self.next_seq,
+                refs: 1,
+                commands,
+            },
+        );
+    }
+
+    fn update(&mut self, kind: HandleKind, handle: u16, command: Command) {
+        if let Some(resource) = self.resources.get_mut(&(kind, handle)) {
+            resource.commands.push(command.clone());
+        }
+        self.record(command);
+    }
+
+    fn destroy(&mut self, kind: HandleKind, handle: u16) {
+        self.record(Command::Destroy { kind, handle });
+        if let Some(resource) = self.resources.get_mut(&(kind, handle)) {
+            resource.refs -= 1;
+            if resource.refs == 0 {
+                self.resources.remove(&(kind, handle));
+                if kind == HandleKind::Uniform {
+                    self.uniforms.remove(&handle);
+                }
+            }
+        }
+    }
+
+    fn frame(&mut self) {
+        self.transient_layouts.clear();
+        self.capture = match mem::take(&mut self.capture) {
+            Capture::Pending => Capture::Recording(Recording {
+                setup: self.setup(),
+                frame: Vec::new(),
+            }),
+            Capture::Recording(recording) => Capture::Done(recording),
+            capture => capture,
+        };
+    }
+
+    /// Commands recreating the current state.
+    fn setup(&self) -> Vec<Command> {
+        let mut resources: Vec<&Resource> = self.resources.values().collect();
+        resources.sort_by_key(|resource| resource.seq);
+
+        let resources = resources
+            .into_iter()
+            .flat_map(|resource| resource.commands.iter());
+        let views = self.views.values().flatten();
+        self.reset
+            .iter()
+            .chain(resources)
+            .chain(views)
+            .cloned()
+            .collect()
+    }
+}
+
+unsafe fn bytes(data: *const u8, size: usize) -> Vec<u8> {
+    match data.is_null() {
+        true => Vec::new(),
+        false => slice::from_raw_parts(data, size).to_vec(),
+    }
+}
+
+unsafe fn memory(mem: *const bgfx_memory_t) -> Vec<u8> {
+    bytes((*mem).data, (*mem).size as usize)
+}
+
+/// Contents of an optional memory, `None` for textures without initial data.
+unsafe fn optional_memory(mem: *const bgfx_memory_t) -> Option<Vec<u8>> {
+    match mem.is_null() {
+        true => None,
+        false => Some(memory(mem)),
+    }
+}
+
+unsafe fn layout(layout: *const bgfx_vertex_layout_t) -> LayoutData {
+    LayoutData::from_raw(&*layout)
+}
+
+unsafe fn matrix(mtx: *const c_void) -> Option<Vec<f32>> {
+    match mtx.is_null() {
+        true => None,
+        false => Some(slice::from_raw_parts(mtx as *const f32, 16).to_vec()),
+    }
+}
+
+impl<B: Backend> Backend for Recorder<B> {
+    fn inner(&self) -> Option<&dyn Backend> {
+        Some(&self.inner)
+    }
+
+    unsafe fn frame(&self, capture: bool) -> u32 {
+        self.state.borrow_mut().frame();
+        self.inner.frame(capture)
+    }
+
+    unsafe fn reset(&self, width: u32, height: u32, flags: u32, format: bgfx_texture_format_t) {
+        let command = Command::Reset {
+            width,
+            height,
+            flags,
+            format,
+        };
+        let mut state = self.state.borrow_mut();
+        state.reset = Some(command.clone());
+        state.record(command);
+        drop(state);
+        self.inner.reset(width, height, flags, format)
+    }
+
+    unsafe fn create_vertex_buffer(
+        &self,
+        mem: *const bgfx_memory_t,
+        layout_ptr: *const bgfx_vertex_layout_t,
+        flags: u16,
+    ) -> bgfx_vertex_buffer_handle_t {
+        let data = memory(mem);
+        let handle = self.inner.create_vertex_buffer(mem, layout_ptr, flags);
+        let command = Command::CreateVertexBuffer {
+            handle: handle.idx,
+            data,
+            layout: layout(layout_ptr),
+            flags,
+        };
+        self.create(HandleKind::VertexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_index_buffer(
+        &self,
+        mem: *const bgfx_memory_t,
+        flags: u16,
+    ) -> bgfx_index_buffer_handle_t {
+        let data = memory(mem);
+        let handle = self.inner.create_index_buffer(mem, flags);
+        let command = Command::CreateIndexBuffer {
+            handle: handle.idx,
+            data,
+            flags,
+        };
+        self.create(HandleKind::IndexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_dynamic_vertex_buffer(
+        &self,
+        num: u32,
+        layout_ptr: *const bgfx_vertex_layout_t,
+        flags: u16,
+    ) -> bgfx_dynamic_vertex_buffer_handle_t {
+        let handle = self
+            .inner
+            .create_dynamic_vertex_buffer(num, layout_ptr, flags);
+        let command = Command::CreateDynamicVertexBuffer {
+            handle: handle.idx,
+            num,
+            layout: layout(layout_ptr),
+            flags,
+        };
+        self.create(HandleKind::DynamicVertexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_dynamic_vertex_buffer_mem(
+        &self,
+        mem: *const bgfx_memory_t,
+        layout_ptr: *const bgfx_vertex_layout_t,
+        flags: u16,
+    ) -> bgfx_dynamic_vertex_buffer_handle_t {
+        let data = memory(mem);
+        let handle = self
+            .inner
+            .create_dynamic_vertex_buffer_mem(mem, layout_ptr, flags);
+        let command = Command::CreateDynamicVertexBufferMem {
+            handle: handle.idx,
+            data,
+            layout: layout(layout_ptr),
+            flags,
+        };
+        self.create(HandleKind::DynamicVertexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_dynamic_index_buffer(
+        &self,
+        num: u32,
+        flags: u16,
+    ) -> bgfx_dynamic_index_buffer_handle_t {
+        let handle = self.inner.create_dynamic_index_buffer(num, flags);
+        let command = Command::CreateDynamicIndexBuffer {
+            handle: handle.idx,
+            num,
+            flags,
+        };
+        self.create(HandleKind::DynamicIndexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_dynamic_index_buffer_mem(
+        &self,
+        mem: *const bgfx_memory_t,
+        flags: u16,
+    ) -> bgfx_dynamic_index_buffer_handle_t {
+        let data = memory(mem);
+        let handle = self.inner.create_dynamic_index_buffer_mem(mem, flags);
+        let command = Command::CreateDynamicIndexBufferMem {
+            handle: handle.idx,
+            data,
+            flags,
+        };
+        self.create(HandleKind::DynamicIndexBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn update_dynamic_vertex_buffer(
+        &self,
+        handle: bgfx_dynamic_vertex_buffer_handle_t,
+        start_vertex: u32,
+        mem: *const bgfx_memory_t,
+    ) {
+        let command = Command::UpdateDynamicVertexBuffer {
+            handle: handle.idx,
+            start_vertex,
+            data: memory(mem),
+        };
+        self.update(HandleKind::DynamicVertexBuffer, handle.idx, command);
+        self.inner
+            .update_dynamic_vertex_buffer(handle, start_vertex, mem)
+    }
+
+    unsafe fn update_dynamic_index_buffer(
+        &self,
+        handle: bgfx_dynamic_index_buffer_handle_t,
+        start_index: u32,
+        mem: *const bgfx_memory_t,
+    ) {
+        let command = Command::UpdateDynamicIndexBuffer {
+            handle: handle.idx,
+            start_index,
+            data: memory(mem),
+        };
+        self.update(HandleKind::DynamicIndexBuffer, handle.idx, command);
+        self.inner
+            .update_dynamic_index_buffer(handle, start_index, mem)
+    }
+
+    unsafe fn create_shader(&self, mem: *const bgfx_memory_t) -> bgfx_shader_handle_t {
+        let data = memory(mem);
+        let handle = self.inner.create_shader(mem);
+        let command = Command::CreateShader {
+            handle: handle.idx,
+            data,
+        };
+        self.create(HandleKind::Shader, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_program(
+        &self,
+        vsh: bgfx_shader_handle_t,
+        fsh: bgfx_shader_handle_t,
+        destroy_shaders: bool,
+    ) -> bgfx_program_handle_t {
+        let handle = self.inner.create_program(vsh, fsh, destroy_shaders);
+        let command = Command::CreateProgram {
+            handle: handle.idx,
+            vsh: vsh.idx,
+            fsh: fsh.idx,
+        };
+        let deps = [(HandleKind::Shader, vsh.idx), (HandleKind::Shader, fsh.idx)];
+        self.create(HandleKind::Program, handle.idx, &deps, command);
+        handle
+    }
+
+    unsafe fn create_compute_program(
+        &self,
+        csh: bgfx_shader_handle_t,
+        destroy_shaders: bool,
+    ) -> bgfx_program_handle_t {
+        let handle = self.inner.create_compute_program(csh, destroy_shaders);
+        let command = Command::CreateComputeProgram {
+            handle: handle.idx,
+            csh: csh.idx,
+        };
+        let deps = [(HandleKind::Shader, csh.idx)];
+        self.create(HandleKind::Program, handle.idx, &deps, command);
+        handle
+    }
+
+    unsafe fn create_uniform(
+        &self,
+        name: *const c_char,
+        type_: bgfx_uniform_type_t,
+        num: u16,
+    ) -> bgfx_uniform_handle_t {
+        let handle = self.inner.create_uniform(name, type_, num);
+        let command = Command::CreateUniform {
+            handle: handle.idx,
+            name: CStr::from_ptr(name).to_string_lossy().into_owned(),
+            uniform_type: type_,
+            num,
+        };
+        self.create(HandleKind::Uniform, handle.idx, &[], command);
+        self.state
+            .borrow_mut()
+            .uniforms
+            .entry(handle.idx)
+            .or_insert((type_, num));
+        handle
+    }
+
+    unsafe fn create_texture(
+        &self,
+        mem: *const bgfx_memory_t,
+        flags: u64,
+        skip: u8,
+        info: *mut bgfx_texture_info_t,
+    ) -> bgfx_texture_handle_t {
+        let data = memory(mem);
+        let handle = self.inner.create_texture(mem, flags, skip, info);
+        let command = Command::CreateTexture {
+            handle: handle.idx,
+            data,
+            flags,
+            skip,
+        };
+        self.create(HandleKind::Texture, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_texture_2d(
+        &self,
+        width: u16,
+        height: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: bgfx_texture_format_t,
+        flags: u64,
+        mem: *const bgfx_memory_t,
+    ) -> bgfx_texture_handle_t {
+        let data = optional_memory(mem);
+        let handle = self
+            .inner
+            .create_texture_2d(width, height, has_mips, num_layers, format, flags, mem);
+        let command = Command::CreateTexture2d {
+            handle: handle.idx,
+            width,
+            height,
+            has_mips,
+            num_layers,
+            format,
+            flags,
+            data,
+        };
+        self.create(HandleKind::Texture, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_texture_2d_scaled(
+        &self,
+        ratio: bgfx_backbuffer_ratio_t,
+        has_mips: bool,
+        num_layers: u16,
+        format: bgfx_texture_format_t,
+        flags: u64,
+    ) -> bgfx_texture_handle_t {
+        let handle = self
+            .inner
+            .create_texture_2d_scaled(ratio, has_mips, num_layers, format, flags);
+        let command = Command::CreateTexture2dScaled {
+            handle: handle.idx,
+            ratio,
+            has_mips,
+            num_layers,
+            format,
+            flags,
+        };
+        self.create(HandleKind::Texture, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_texture_3d(
+        &self,
+        width: u16,
+        height: u16,
+        depth: u16,
+        has_mips: bool,
+        format: bgfx_texture_format_t,
+        flags: u64,
+        mem: *const bgfx_memory_t,
+    ) -> bgfx_texture_handle_t {
+        let data = optional_memory(mem);
+        let handle = self
+            .inner
+            .create_texture_3d(width, height, depth, has_mips, format, flags, mem);
+        let command = Command::CreateTexture3d {
+            handle: handle.idx,
+            width,
+            height,
+            depth,
+            has_mips,
+            format,
+            flags,
+            data,
+        };
+        self.create(HandleKind::Texture, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_texture_cube(
+        &self,
+        size: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: bgfx_texture_format_t,
+        flags: u64,
+        mem: *const bgfx_memory_t,
+    ) -> bgfx_texture_handle_t {
+        let data = optional_memory(mem);
+        let handle = self
+            .inner
+            .create_texture_cube(size, has_mips, num_layers, format, flags, mem);
+        let command = Command::CreateTextureCube {
+            handle: handle.idx,
+            size,
+            has_mips,
+            num_layers,
+            format,
+            flags,
+            data,
+        };
+        self.create(HandleKind::Texture, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn update_texture_2d(
+        &self,
+        handle: bgfx_texture_handle_t,
+        layer: u16,
+        mip: u8,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        mem: *const bgfx_memory_t,
+        pitch: u16,
+    ) {
+        let command = Command::UpdateTexture2d {
+            handle: handle.idx,
+            layer,
+            mip,
+            x,
+            y,
+            width,
+            height,
+            data: memory(mem),
+            pitch,
+        };
+        self.update(HandleKind::Texture, handle.idx, command);
+        self.inner
+            .update_texture_2d(handle, layer, mip, x, y, width, height, mem, pitch)
+    }
+
+    unsafe fn update_texture_3d(
+        &self,
+        handle: bgfx_texture_handle_t,
+        mip: u8,
+        x: u16,
+        y: u16,
+        z: u16,
+        width: u16,
+        height: u16,
+        depth: u16,
+        mem: *const bgfx_memory_t,
+    ) {
+        let command = Command::UpdateTexture3d {
+            handle: handle.idx,
+            mip,
+            x,
+            y,
+            z,
+            width,
+            height,
+            depth,
+            data: memory(mem),
+        };
+        self.update(HandleKind::Texture, handle.idx, command);
+        self.inner
+            .update_texture_3d(handle, mip, x, y, z, width, height, depth, mem)
+    }
+
+    unsafe fn update_texture_cube(
+        &self,
+        handle: bgfx_texture_handle_t,
+        layer: u16,
+        side: u8,
+        mip: u8,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        mem: *const bgfx_memory_t,
+        pitch: u16,
+    ) {
+        let command = Command::UpdateTextureCube {
+            handle: handle.idx,
+            layer,
+            side,
+            mip,
+            x,
+            y,
+            width,
+            height,
+            data: memory(mem),
+            pitch,
+        };
+        self.update(HandleKind::Texture, handle.idx, command);
+        self.inner
+            .update_texture_cube(handle, layer, side, mip, x, y, width, height, mem, pitch)
+    }
+
+    unsafe fn create_frame_buffer(
+        &self,
+        width: u16,
+        height: u16,
+        format: bgfx_texture_format_t,
+        texture_flags: u64,
+    ) -> bgfx_frame_buffer_handle_t {
+        let handle = self
+            .inner
+            .create_frame_buffer(width, height, format, texture_flags);
+        let command = Command::CreateFrameBuffer {
+            handle: handle.idx,
+            width,
+            height,
+            format,
+            texture_flags,
+        };
+        self.create(HandleKind::FrameBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_frame_buffer_scaled(
+        &self,
+        ratio: bgfx_backbuffer_ratio_t,
+        format: bgfx_texture_format_t,
+        texture_flags: u64,
+    ) -> bgfx_frame_buffer_handle_t {
+        let handle = self
+            .inner
+            .create_frame_buffer_scaled(ratio, format, texture_flags);
+        let command = Command::CreateFrameBufferScaled {
+            handle: handle.idx,
+            ratio,
+            format,
+            texture_flags,
+        };
+        self.create(HandleKind::FrameBuffer, handle.idx, &[], command);
+        handle
+    }
+
+    unsafe fn create_frame_buffer_from_handles(
+        &self,
+        num: u8,
+        handles: *const bgfx_texture_handle_t,
+        destroy_texture: bool,
+    ) -> bgfx_frame_buffer_handle_t {
+        let textures: Vec<u16> = slice::from_raw_parts(handles, num as usize)
+            .iter()
+            .map(|texture| texture.idx)
+            .collect();
+        let handle = self
+            .inner
+            .create_frame_buffer_from_handles(num, handles, destroy_texture);
+        let deps: Vec<_> = textures
+            .iter()
+            .map(|&texture| (HandleKind::Texture, texture))
+            .collect();
+        let command = Command::CreateFrameBufferFromHandles {
+            handle: handle.idx,
+            textures,
+        };
+        self.create(HandleKind::FrameBuffer, handle.idx, &deps, command);
+        handle
+    }
+
+    unsafe fn destroy_vertex_buffer(&self, handle: bgfx_vertex_buffer_handle_t) {
+        self.destroy(HandleKind::VertexBuffer, handle.idx);
+        self.inner.destroy_vertex_buffer(handle)
+    }
+
+    unsafe fn destroy_index_buffer(&self, handle: bgfx_index_buffer_handle_t) {
+        self.destroy(HandleKind::IndexBuffer, handle.idx);
+        self.inner.destroy_index_buffer(handle)
+    }
+
+    unsafe fn destroy_dynamic_vertex_buffer(&self, handle: bgfx_dynamic_vertex_buffer_handle_t) {
+        self.destroy(HandleKind::DynamicVertexBuffer, handle.idx);
+        self.inner.destroy_dynamic_vertex_buffer(handle)
+    }
+
+    unsafe fn destroy_dynamic_index_buffer(&self, handle: bgfx_dynamic_index_buffer_handle_t) {
+        self.destroy(HandleKind::DynamicIndexBuffer, handle.idx);
+        self.inner.destroy_dynamic_index_buffer(handle)
+    }
+
+    unsafe fn destroy_shader(&self, handle: bgfx_shader_handle_t) {
+        self.destroy(HandleKind::Shader, handle.idx);
+        self.inner.destroy_shader(handle)
+    }
+
+    unsafe fn destroy_program(&self, handle: bgfx_program_handle_t) {
+        self.destroy(HandleKind::Program, handle.idx);
+        self.inner.destroy_program(handle)
+    }
+
+    unsafe fn destroy_uniform(&self, handle: bgfx_uniform_handle_t) {
+        self.destroy(HandleKind::Uniform, handle.idx);
+        self.inner.destroy_uniform(handle)
+    }
+
+    unsafe fn destroy_texture(&self, handle: bgfx_texture_handle_t) {
+        self.destroy(HandleKind::Texture, handle.idx);
+        self.inner.destroy_texture(handle)
+    }
+
+    unsafe fn destroy_frame_buffer(&self, handle: bgfx_frame_buffer_handle_t) {
+        self.destroy(HandleKind::FrameBuffer, handle.idx);
+        self.inner.destroy_frame_buffer(handle)
+    }
+
+    unsafe fn set_view_rect(&self, id: bgfx_view_id_t, x: u16, y: u16, width: u16, height: u16) {
+        let command = Command::SetViewRect {
+            view: id,
+            x,
+            y,
+            width,
+            height,
+        };
+        self.view(id, command);
+        self.inner.set_view_rect(id, x, y, width, height)
+    }
+
+    unsafe fn set_view_rect_ratio(
+        &self,
+        id: bgfx_view_id_t,
+        x: u16,
+        y: u16,
+        ratio: bgfx_backbuffer_ratio_t,
+    ) {
+        let command = Command::SetViewRectRatio {
+            view: id,
+            x,
+            y,
+            ratio,
+        };
+        self.view(id, command);
+        self.inner.set_view_rect_ratio(id, x, y, ratio)
+    }
+
+    unsafe fn set_view_scissor(&self, id: bgfx_view_id_t, x: u16, y: u16, width: u16, height: u16) {
+        let command = Command::SetViewScissor {
+            view: id,
+            x,
+            y,
+            width,
+            height,
+        };
+        self.view(id, command);
+        self.inner.set_view_scissor(id, x, y, width, height)
+    }
+
+    unsafe fn set_view_clear(
+        &self,
+        id: bgfx_view_id_t,
+        flags: u16,
+        rgba: u32,
+        depth: f32,
+        stencil: u8,
+    ) {
+        let command = Command::SetViewClear {
+            view: id,
+            flags,
+            rgba,
+            depth,
+            stencil,
+        };
+        self.view(id, command);
+        self.inner.set_view_clear(id, flags, rgba, depth, stencil)
+    }
+
+    unsafe fn set_view_mode(&self, id: bgfx_view_id_t, mode: bgfx_view_mode_t) {
+        self.view(id, Command::SetViewMode { view: id, mode });
+        self.inner.set_view_mode(id, mode)
+    }
+
+    unsafe fn set_view_frame_buffer(&self, id: bgfx_view_id_t, handle: bgfx_frame_buffer_handle_t) {
+        let command = Command::SetViewFrameBuffer {
+            view: id,
+            handle: handle.idx,
+        };
+        self.view(id, command);
+        self.inner.set_view_frame_buffer(id, handle)
+    }
+
+    unsafe fn set_view_transform(
+        &self,
+        id: bgfx_view_id_t,
+        view: *const c_void,
+        proj: *const c_void,
+    ) {
+        let command = Command::SetViewTransform {
+            view: id,
+            view_mtx: matrix(view),
+            proj_mtx: matrix(proj),
+        };
+        self.view(id, command);
+        self.inner.set_view_transform(id, view, proj)
+    }
+
+    unsafe fn reset_view(&self, id: bgfx_view_id_t) {
+        let mut state = self.state.borrow_mut();
+        state.views.remove(&id);
+        state.record(Command::ResetView { view: id });
+        drop(state);
+        self.inner.reset_view(id)
+    }
+
+    unsafe fn alloc_transient_vertex_buffer(
+        &self,
+        tvb: *mut bgfx_transient_vertex_buffer_t,
+        num: u32,
+        layout_ptr: *const bgfx_vertex_layout_t,
+    ) {
+        self.inner
+            .alloc_transient_vertex_buffer(tvb, num, layout_ptr);
+        let data = (*tvb).data as usize;
+        let mut state = self.state.borrow_mut();
+        state.transient_layouts.insert(data, layout(layout_ptr));
+    }
+
+    unsafe fn alloc_transient_buffers(
+        &self,
+        tvb: *mut bgfx_transient_vertex_buffer_t,
+        layout_ptr: *const bgfx_vertex_layout_t,
+        num_vertices: u32,
+        tib: *mut bgfx_transient_index_buffer_t,
+        num_indices: u32,
+        index32: bool,
+    ) -> bool {
+        let allocated = self.inner.alloc_transient_buffers(
+            tvb,
+            layout_ptr,
+            num_vertices,
+            tib,
+            num_indices,
+            index32,
+        );
+        if allocated {
+            let data = (*tvb).data as usize;
+            let mut state = self.state.borrow_mut();
+            state.transient_layouts.insert(data, layout(layout_ptr));
+        }
+        allocated
+    }
+
+    unsafe fn touch(&self, id: bgfx_view_id_t) {
+        self.record(Command::Touch { view: id });
+        self.inner.touch(id)
+    }
+
+    unsafe fn encoder_touch(&self, this: *mut bgfx_encoder_t, id: bgfx_view_id_t) {
+        self.record(Command::Touch { view: id });
+        self.inner.encoder_touch(this, id)
+    }
+
+    unsafe fn set_state(&self, state: u64, rgba: u32) {
+        self.record(Command::SetState { state, rgba });
+        self.inner.set_state(state, rgba)
+    }
+
+    unsafe fn encoder_set_state(&self, this: *mut bgfx_encoder_t, state: u64, rgba: u32) {
+        self.record(Command::SetState { state, rgba });
+        self.inner.encoder_set_state(this, state, rgba)
+    }
+
+    unsafe fn set_stencil(&self, fstencil: u32, bstencil: u32) {
+        let command = Command::SetStencil {
+            front: fstencil,
+            back: bstencil,
+        };
+        self.record(command);
+        self.inner.set_stencil(fstencil, bstencil)
+    }
+
+    unsafe fn encoder_set_stencil(&self, this: *mut bgfx_encoder_t, fstencil: u32, bstencil: u32) {
+        let command = Command::SetStencil {
+            front: fstencil,
+            back: bstencil,
+        };
+        self.record(command);
+        self.inner.encoder_set_stencil(this, fstencil, bstencil)
+    }
+
+    unsafe fn set_scissor(&self, x: u16, y: u16, width: u16, height: u16) -> u16 {
+        self.record(Command::SetScissor {
+            x,
+            y,
+            width,
+            height,
+        });
+        self.inner.set_scissor(x, y, width, height)
+    }
+
+    unsafe fn encoder_set_scissor(
+        &self,
+        this: *mut bgfx_encoder_t,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+    ) -> u16 {
+        self.record(Command::SetScissor {
+            x,
+            y,
+            width,
+            height,
+        });
+        self.inner.encoder_set_scissor(this, x, y, width, height)
+    }
+
+    unsafe fn set_transform(&self, mtx: *const c_void, num: u16) -> u32 {
+        let matrices = slice::from_raw_parts(mtx as *const f32, 16 * num as usize);
+        self.record(Command::SetTransform {
+            matrices: matrices.to_vec(),
+        });
+        self.inner.set_transform(mtx, num)
+    }
+
+    unsafe fn set_uniform(&self, handle: bgfx_uniform_handle_t, value: *const c_void, num: u16) {
+        self.set_uniform_command(handle, value, num);
+        self.inner.set_uniform(handle, value, num)
+    }
+
+    unsafe fn encoder_set_uniform(
+        &self,
+        this: *mut bgfx_encoder_t,
+        handle: bgfx_uniform_handle_t,
+        value: *const c_void,
+        num: u16,
+    ) {
+        self.set_uniform_command(handle, value, num);
+        self.inner.encoder_set_uniform(this, handle, value, num)
+    }
+
+    unsafe fn set_texture(
+        &self,
+        stage: u8,
+        sampler: bgfx_uniform_handle_t,
+        handle: bgfx_texture_handle_t,
+        flags: u32,
+    ) {
+        self.record(Command::SetTexture {
+            stage,
+            sampler: sampler.idx,
+            handle: handle.idx,
+            flags,
+        });
+        self.inner.set_texture(stage, sampler, handle, flags)
+    }
+
+    unsafe fn encoder_set_texture(
+        &self,
+        this: *mut bgfx_encoder_t,
+        stage: u8,
+        sampler: bgfx_uniform_handle_t,
+        handle: bgfx_texture_handle_t,
+        flags: u32,
+    ) {
+        self.record(Command::SetTexture {
+            stage,
+            sampler: sampler.idx,
+            handle: handle.idx,
+            flags,
+        });
+        self.inner
+            .encoder_set_texture(this, stage, sampler, handle, flags)
+    }
+
+    unsafe fn set_vertex_buffer(
+        &self,
+        stream: u8,
+        handle: bgfx_vertex_buffer_handle_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.record(Command::SetVertexBuffer {
+            stream,
+            handle: handle.idx,
+            start_vertex,
+            num_vertices,
+        });
+        self.inner
+            .set_vertex_buffer(stream, handle, start_vertex, num_vertices)
+    }
+
+    unsafe fn encoder_set_vertex_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        stream: u8,
+        handle: bgfx_vertex_buffer_handle_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.record(Command::SetVertexBuffer {
+            stream,
+            handle: handle.idx,
+            start_vertex,
+            num_vertices,
+        });
+        self.inner
+            .encoder_set_vertex_buffer(this, stream, handle, start_vertex, num_vertices)
+    }
+
+    unsafe fn set_index_buffer(
+        &self,
+        handle: bgfx_index_buffer_handle_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.record(Command::SetIndexBuffer {
+            handle: handle.idx,
+            first_index,
+            num_indices,
+        });
+        self.inner
+            .set_index_buffer(handle, first_index, num_indices)
+    }
+
+    unsafe fn encoder_set_index_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        handle: bgfx_index_buffer_handle_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.record(Command::SetIndexBuffer {
+            handle: handle.idx,
+            first_index,
+            num_indices,
+        });
+        self.inner
+            .encoder_set_index_buffer(this, handle, first_index, num_indices)
+    }
+
+    unsafe fn set_dynamic_vertex_buffer(
+        &self,
+        stream: u8,
+        handle: bgfx_dynamic_vertex_buffer_handle_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.record(Command::SetDynamicVertexBuffer {
+            stream,
+            handle: handle.idx,
+            start_vertex,
+            num_vertices,
+        });
+        self.inner
+            .set_dynamic_vertex_buffer(stream, handle, start_vertex, num_vertices)
+    }
+
+    unsafe fn encoder_set_dynamic_vertex_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        stream: u8,
+        handle: bgfx_dynamic_vertex_buffer_handle_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.record(Command::SetDynamicVertexBuffer {
+            stream,
+            handle: handle.idx,
+            start_vertex,
+            num_vertices,
+        });
+        self.inner.encoder_set_dynamic_vertex_buffer(
+            this,
+            stream,
+            handle,
+            start_vertex,
+            num_vertices,
+        )
+    }
+
+    unsafe fn set_dynamic_index_buffer(
+        &self,
+        handle: bgfx_dynamic_index_buffer_handle_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.record(Command::SetDynamicIndexBuffer {
+            handle: handle.idx,
+            first_index,
+            num_indices,
+        });
+        self.inner
+            .set_dynamic_index_buffer(handle, first_index, num_indices)
+    }
+
+    unsafe fn encoder_set_dynamic_index_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        handle: bgfx_dynamic_index_buffer_handle_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.record(Command::SetDynamicIndexBuffer {
+            handle: handle.idx,
+            first_index,
+            num_indices,
+        });
+        self.inner
+            .encoder_set_dynamic_index_buffer(this, handle, first_index, num_indices)
+    }
+
+    unsafe fn set_transient_vertex_buffer(
+        &self,
+        stream: u8,
+        tvb: *const bgfx_transient_vertex_buffer_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.set_transient_vertex_buffer_command(stream, tvb, start_vertex, num_vertices);
+        self.inner
+            .set_transient_vertex_buffer(stream, tvb, start_vertex, num_vertices)
+    }
+
+    unsafe fn encoder_set_transient_vertex_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        stream: u8,
+        tvb: *const bgfx_transient_vertex_buffer_t,
+        start_vertex: u32,
+        num_vertices: u32,
+    ) {
+        self.set_transient_vertex_buffer_command(stream, tvb, start_vertex, num_vertices);
+        self.inner.encoder_set_transient_vertex_buffer(
+            this,
+            stream,
+            tvb,
+            start_vertex,
+            num_vertices,
+        )
+    }
+
+    unsafe fn set_transient_index_buffer(
+        &self,
+        tib: *const bgfx_transient_index_buffer_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.set_transient_index_buffer_command(tib, first_index, num_indices);
+        self.inner
+            .set_transient_index_buffer(tib, first_index, num_indices)
+    }
+
+    unsafe fn encoder_set_transient_index_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        tib: *const bgfx_transient_index_buffer_t,
+        first_index: u32,
+        num_indices: u32,
+    ) {
+        self.set_transient_index_buffer_command(tib, first_index, num_indices);
+        self.inner
+            .encoder_set_transient_index_buffer(this, tib, first_index, num_indices)
+    }
+
+    unsafe fn set_instance_data_buffer(
+        &self,
+        idb: *const bgfx_instance_data_buffer_t,
+        start: u32,
+        num: u32,
+    ) {
+        self.set_instance_data_buffer_command(idb, start, num);
+        self.inner.set_instance_data_buffer(idb, start, num)
+    }
+
+    unsafe fn encoder_set_instance_data_buffer(
+        &self,
+        this: *mut bgfx_encoder_t,
+        idb: *const bgfx_instance_data_buffer_t,
+        start: u32,
+        num: u32,
+    ) {
+        self.set_instance_data_buffer_command(idb, start, num);
+        self.inner
+            .encoder_set_instance_data_buffer(this, idb, start, num)
+    }
+
+    unsafe fn set_vertex_count(&self, num_vertices: u32) {
+        self.record(Command::SetVertexCount { num_vertices });
+        self.inner.set_vertex_count(num_vertices)
+    }
+
+    unsafe fn encoder_set_vertex_count(&self, this: *mut bgfx_encoder_t, num_vertices: u32) {
+        self.record(Command::SetVertexCount { num_vertices });
+        self.inner.encoder_set_vertex_count(this, num_vertices)
+    }
+
+    unsafe fn set_instance_count(&self, num_instances: u32) {
+        self.record(Command::SetInstanceCount { num_instances });
+        self.inner.set_instance_count(num_instances)
+    }
+
+    unsafe fn encoder_set_instance_count(&self, this: *mut bgfx_encoder_t, num_instances: u32) {
+        self.record(Command::SetInstanceCount { num_instances });
+        self.inner.encoder_set_instance_count(this, num_instances)
+    }
+
+    unsafe fn submit(
+        &self,
+        id: bgfx_view_id_t,
+        program: bgfx_program_handle_t,
+        depth: u32,
+        flags: u8,
+    ) {
+        self.record(Command::Submit {
+            view: id,
+            program: program.idx,
+            depth,
+            flags,
+        });
+        self.inner.submit(id, program, depth, flags)
+    }
+
+    unsafe fn encoder_submit(
+        &self,
+        this: *mut bgfx_encoder_t,
+        id: bgfx_view_id_t,
+        program: bgfx_program_handle_t,
+        depth: u32,
+        flags: u8,
+    ) {
+        self.record(Command::Submit {
+            view: id,
+            program: program.idx,
+            depth,
+            flags,
+        });
+        self.inner.encoder_submit(this, id, program, depth, flags)
+    }
+
+    unsafe fn dispatch(
+        &self,
+        id: bgfx_view_id_t,
+        program: bgfx_program_handle_t,
+        num_x: u32,
+        num_y: u32,
+        num_z: u32,
+        flags: u8,
+    ) {
+        self.record(Command::Dispatch {
+            view: id,
+            program: program.idx,
+            num_x,
+            num_y,
+            num_z,
+            flags,
+        });
+        self.inner.dispatch(id, program, num_x, num_y, num_z, flags)
+    }
+
+    unsafe fn encoder_dispatch(
+        &self,
+        this: *mut bgfx_encoder_t,
+        id: bgfx_view_id_t,
+        program: bgfx_program_handle_t,
+        num_x: u32,
+        num_y: u32,
+        num_z: u32,
+        flags: u8,
+    ) {
+        self.record(Command::Dispatch {
+            view: id,
+            program: program.idx,
+            num_x,
+            num_y,
+            num_z,
+            flags,
+        });
+        self.inner
+            .encoder_dispatch(this, id, program, num_x, num_y, num_z, flags)
+    }
+
+    unsafe fn discard(&self, flags: u8) {
+        self.record(Command::Discard { flags });
+        self.inner.discard(flags)
+    }
+
+    unsafe fn encoder_discard(&self, this: *mut bgfx_encoder_t, flags: u8) {
+        self.record(Command::Discard { flags });
+        self.inner.encoder_discard(this, flags)
+    }
+}
@@ -0,0 +1,445 @@
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use bgfx_sys::{
+    bgfx_vertex_layout_t, BGFX_UNIFORM_TYPE_MAT3, BGFX_UNIFORM_TYPE_MAT4, BGFX_UNIFORM_TYPE_VEC4,
+};
+
+/// Type of a recorded handle. Handle indices are only unique per type.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum HandleKind {
+    VertexBuffer,
+    IndexBuffer,
+    DynamicVertexBuffer,
+    DynamicIndexBuffer,
+    Shader,
+    Program,
+    Uniform,
+    Texture,
+    FrameBuffer,
+}
+
+impl HandleKind {
+    const ALL: [HandleKind; 9] = [
+        HandleKind::VertexBuffer,
+        HandleKind::IndexBuffer,
+        HandleKind::DynamicVertexBuffer,
+        HandleKind::DynamicIndexBuffer,
+        HandleKind::Shader,
+        HandleKind::Program,
+        HandleKind::Uniform,
+        HandleKind::Texture,
+        HandleKind::FrameBuffer,
+    ];
+}
+
+/// Copy of a vertex layout.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct LayoutData {
+    pub hash: u32,
+    pub stride: u16,
+    pub offset: [u16; 18],
+    pub attributes: [u16; 18],
+}
+
+impl LayoutData {
+    pub(crate) fn from_raw(layout: &bgfx_vertex_layout_t) -> LayoutData {
+        LayoutData {
+            hash: layout.hash,
+            stride: layout.stride,
+            offset: layout.offset,
+            attributes: layout.attributes,
+        }
+    }
+
+    pub(crate) fn to_raw(self) -> bgfx_vertex_layout_t {
+        bgfx_vertex_layout_t {
+            hash: self.hash,
+            stride: self.stride,
+            offset: self.offset,
+            attributes: self.attributes,
+        }
+    }
+}
+
+/// Size in bytes of one element of a uniform type.
+pub(crate) fn uniform_size(uniform_type: u32) -> usize {
+    match uniform_type {
+        BGFX_UNIFORM_TYPE_VEC4 => 16,
+        BGFX_UNIFORM_TYPE_MAT3 => 36,
+        BGFX_UNIFORM_TYPE_MAT4 => 64,
+        // Samplers hold the texture stage.
+        _ => 4,
+    }
+}
+
+macro_rules! commands {
+    ($($(#[$doc:meta])* $tag:literal => $variant:ident { $($field:ident: $ty:ty),* $(,)? },)*) => {
+        /// One call captured by a [Recorder](crate::Recorder).
+        ///
+        /// Handles are the indices the recording application got from bgfx, the
+        /// [Replayer](crate::Replayer) maps them to its own. Enums and flags are kept as their
+        /// bgfx values. Encoder calls are recorded as the matching call without encoder.
+        #[derive(Clone, PartialEq, Debug)]
+        pub enum Command {
+            $($(#[$doc])* $variant { $($field: $ty),* },)*
+        }
+
+        impl Command {
+            fn write(&self, out: &mut Vec<u8>) {
+                match self {
+                    $(Command::$variant { $($field),* } => {
+                        out.push($tag);
+                        $(Field::write($field, out);)*
+                    })*
+                }
+            }
+
+            fn read(input: &mut &[u8]) -> io::Result<Command> {
+                match u8::read(input)? {
+                    $($tag => Ok(Command::$variant { $($field: Field::read(input)?),* }),)*
+                    tag => Err(invalid(format!("unknown command {}", tag))),
+                }
+            }
+        }
+    };
+}
+
+commands! {
+    1 => CreateVertexBuffer { handle: u16, data: Vec<u8>, layout: LayoutData, flags: u16 },
+    2 => CreateIndexBuffer { handle: u16, data: Vec<u8>, flags: u16 },
+    3 => CreateDynamicVertexBuffer { handle: u16, num: u32, layout: LayoutData, flags: u16 },
+    4 => CreateDynamicVertexBufferMem { handle: u16, data: Vec<u8>, layout: LayoutData, flags: u16 },
+    5 => CreateDynamicIndexBuffer { handle: u16, num: u32, flags: u16 },
+    6 => CreateDynamicIndexBufferMem { handle: u16, data: Vec<u8>, flags: u16 },
+    7 => UpdateDynamicVertexBuffer { handle: u16, start_vertex: u32, data: Vec<u8> },
+    8 => UpdateDynamicIndexBuffer { handle: u16, start_index: u32, data: Vec<u8> },
+    9 => CreateShader { handle: u16, data: Vec<u8> },
+    /// Replayed without destroying the shaders, the replayer destroys them.
+    10 => CreateProgram { handle: u16, vsh: u16, fsh: u16 },
+    11 => CreateComputeProgram { handle: u16, csh: u16 },
+    12 => CreateUniform { handle: u16, name: String, uniform_type: u32, num: u16 },
+    13 => CreateTexture { handle: u16, data: Vec<u8>, flags: u64, skip: u8 },
+    14 => CreateTexture2d {
+        handle: u16,
+        width: u16,
+        height: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: u32,
+        flags: u64,
+        data: Option<Vec<u8>>,
+    },
+    15 => CreateTexture2dScaled {
+        handle: u16,
+        ratio: u32,
+        has_mips: bool,
+        num_layers: u16,
+        format: u32,
+        flags: u64,
+    },
+    16 => CreateTexture3d {
+        handle: u16,
+        width: u16,
+        height: u16,
+        depth: u16,
+        has_mips: bool,
+        format: u32,
+        flags: u64,
+        data: Option<Vec<u8>>,
+    },
+    17 => CreateTextureCube {
+        handle: u16,
+        size: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: u32,
+        flags: u64,
+        data: Option<Vec<u8>>,
+    },
+    18 => UpdateTexture2d {
+        handle: u16,
+        layer: u16,
+        mip: u8,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        data: Vec<u8>,
+        pitch: u16,
+    },
+    19 => UpdateTexture3d {
+        handle: u16,
+        mip: u8,
+        x: u16,
+        y: u16,
+        z: u16,
+        width: u16,
+        height: u16,
+        depth: u16,
+        data: Vec<u8>,
+    },
+    20 => UpdateTextureCube {
+        handle: u16,
+        layer: u16,
+        side: u8,
+        mip: u8,
+        x: u16,
+        y: u16,
+        width: u16,
+        height: u16,
+        data: Vec<u8>,
+        pitch: u16,
+    },
+    21 => CreateFrameBuffer { handle: u16, width: u16, height: u16, format: u32, texture_flags: u64 },
+    22 => CreateFrameBufferScaled { handle: u16, ratio: u32, format: u32, texture_flags: u64 },
+    /// Replayed without handing the textures to the frame buffer, the replayer destroys them.
+    23 => CreateFrameBufferFromHandles { handle: u16, textures: Vec<u16> },
+    24 => Destroy { kind: HandleKind, handle: u16 },
+
+    30 => Reset { width: u32, height: u32, flags: u32, format: u32 },
+    31 => SetViewRect { view: u16, x: u16, y: u16, width: u16, height: u16 },
+    32 => SetViewRectRatio { view: u16, x: u16, y: u16, ratio: u32 },
+    33 => SetViewScissor { view: u16, x: u16, y: u16, width: u16, height: u16 },
+    34 => SetViewClear { view: u16, flags: u16, rgba: u32, depth: f32, stencil: u8 },
+    35 => SetViewMode { view: u16, mode: u32 },
+    36 => SetViewFrameBuffer { view: u16, handle: u16 },
+    37 => SetViewTransform { view: u16, view_mtx: Option<Vec<f32>>, proj_mtx: Option<Vec<f32>> },
+    38 => ResetView { view: u16 },
+
+    40 => Touch { view: u16 },
+    41 => SetState { state: u64, rgba: u32 },
+    42 => SetStencil { front: u32, back: u32 },
+    43 => SetScissor { x: u16, y: u16, width: u16, height: u16 },
+    /// `num` matrices of 16 floats.
+    44 => SetTransform { matrices: Vec<f32> },
+    /// The raw values, `num` elements of the uniform type.
+    45 => SetUniform { handle: u16, data: Vec<u8>, num: u16 },
+    46 => SetTexture { stage: u8, sampler: u16, handle: u16, flags: u32 },
+    47 => SetVertexBuffer { stream: u8, handle: u16, start_vertex: u32, num_vertices: u32 },
+    48 => SetIndexBuffer { handle: u16, first_index: u32, num_indices: u32 },
+    49 => SetDynamicVertexBuffer { stream: u8, handle: u16, start_vertex: u32, num_vertices: u32 },
+    50 => SetDynamicIndexBuffer { handle: u16, first_index: u32, num_indices: u32 },
+    /// The whole transient buffer, replayed by allocating and filling a new one.
+    51 => SetTransientVertexBuffer {
+        stream: u8,
+        layout: LayoutData,
+        data: Vec<u8>,
+        start_vertex: u32,
+        num_vertices: u32,
+    },
+    52 => SetTransientIndexBuffer { data: Vec<u8>, index32: bool, first_index: u32, num_indices: u32 },
+    53 => SetInstanceDataBuffer { data: Vec<u8>, stride: u16, start: u32, num: u32 },
+    54 => SetVertexCount { num_vertices: u32 },
+    55 => SetInstanceCount { num_instances: u32 },
+    56 => Submit { view: u16, program: u16, depth: u32, flags: u8 },
+    57 => Dispatch { view: u16, program: u16, num_x: u32, num_y: u32, num_z: u32, flags: u8 },
+    58 => Discard { flags: u8 },
+}
+
+const MAGIC: &[u8; 8] = b"BGFXREC\0";
+const VERSION: u32 = 1;
+
+/// A frame captured by a [Recorder](crate::Recorder).
+///
+/// `setup` recreates what the frame depends on: the backbuffer size, the resources alive at the
+/// start of the frame with their data, and the view settings. `frame` holds the calls of the
+/// frame itself.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Recording {
+    pub setup: Vec<Command>,
+    pub frame: Vec<Command>,
+}
+
+impl Recording {
+    /// Writes the recording in a compact binary format.
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut out = Vec::new();
+        out.extend_from_slice(MAGIC);
+        VERSION.write(&mut out);
+        for commands in [&self.setup, &self.frame] {
+            (commands.len() as u32).write(&mut out);
+            for command in commands {
+                command.write(&mut out);
+            }
+        }
+        writer.write_all(&out)
+    }
+
+    /// Reads a recording written by [Recording::write].
+    pub fn read<R: Read>(reader: &mut R) -> io::Result<Recording> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+        let mut input = data.as_slice();
+
+        if take(&mut input, MAGIC.len())? != MAGIC {
+            return Err(invalid("not a bgfx recording".to_owned()));
+        }
+        let version = u32::read(&mut input)?;
+        if version != VERSION {
+            return Err(invalid(format!(
+                "unsupported recording version {}",
+                version
+            )));
+        }
+
+        let mut commands = || -> io::Result<Vec<Command>> {
+            let len = u32::read(&mut input)?;
+            (0..len).map(|_| Command::read(&mut input)).collect()
+        };
+        let setup = commands()?;
+        let frame = commands()?;
+        Ok(Recording { setup, frame })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Recording> {
+        Recording::read(&mut BufReader::new(File::open(path)?))
+    }
+}
+
+fn invalid(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> io::Result<&'a [u8]> {
+    if input.len() < len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+/// Little endian encoding of a [Command] field.
+trait Field: Sized {
+    fn write(&self, out: &mut Vec<u8>);
+    fn read(input: &mut &[u8]) -> io::Result<Self>;
+}
+
+macro_rules! le_field {
+    ($($ty:ty),*) => {
+        $(impl Field for $ty {
+            fn write(&self, out: &mut Vec<u8>) {
+                out.extend_from_slice(&self.to_le_bytes());
+            }
+
+            fn read(input: &mut &[u8]) -> io::Result<$ty> {
+                let bytes = take(input, std::mem::size_of::<$ty>())?;
+                Ok(<$ty>::from_le_bytes(bytes.try_into().unwrap()))
+            }
+        })*
+    };
+}
+
+le_field!(u8, u16, u32, u64, f32);
+
+impl Field for bool {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<bool> {
+        Ok(u8::read(input)? != 0)
+    }
+}
+
+impl<T: Field> Field for Vec<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        (self.len() as u32).write(out);
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<Vec<T>> {
+        let len = u32::read(input)? as usize;
+        // Every value takes at least a byte, don't trust the length beyond that.
+        let mut values = Vec::with_capacity(len.min(input.len()));
+        for _ in 0..len {
+            values.push(T::read(input)?);
+        }
+        Ok(values)
+    }
+}
+
+impl<T: Field> Field for Option<T> {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.is_some().write(out);
+        if let Some(value) = self {
+            value.write(out);
+        }
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<Option<T>> {
+        match bool::read(input)? {
+            true => Ok(Some(T::read(input)?)),
+            false => Ok(None),
+        }
+    }
+}
+
+impl<T: Field + Copy + Default, const N: usize> Field for [T; N] {
+    fn write(&self, out: &mut Vec<u8>) {
+        for value in self {
+            value.write(out);
+        }
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<[T; N]> {
+        let mut values = [T::default(); N];
+        for value in &mut values {
+            *value = T::read(input)?;
+        }
+        Ok(values)
+    }
+}
+
+impl Field for String {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.as_bytes().to_vec().write(out);
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<String> {
+        String::from_utf8(Vec::read(input)?).map_err(|err| invalid(err.to_string()))
+    }
+}
+
+impl Field for HandleKind {
+    fn write(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<HandleKind> {
+        let index = u8::read(input)?;
+        HandleKind::ALL
+            .get(index as usize)
+            .copied()
+            .ok_or_else(|| invalid(format!("unknown handle kind {}", index)))
+    }
+}
+
+impl Field for LayoutData {
+    fn write(&self, out: &mut Vec<u8>) {
+        self.hash.write(out);
+        self.stride.write(out);
+        self.offset.write(out);
+        self.attributes.write(out);
+    }
+
+    fn read(input: &mut &[u8]) -> io::Result<LayoutData> {
+        Ok(LayoutData {
+            hash: Field::read(input)?,
+            stride: Field::read(input)?,
+            offset: Field::read(input)?,
+            attributes: Field::read(input)?,
+        })
+    }
+}
@@ -0,0 +1,786 @@
+use std::collections::HashMap;
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::ptr;
+
+use bgfx_sys::*;
+
+use crate::backend;
+use crate::bgfx::{TextureFormat, TextureInfo};
+use crate::recording::{uniform_size, Command, HandleKind, Recording};
+use crate::texture::Image;
+
+/// Reissues a [Recording] against the current bgfx context, f.e. one initialized with
+/// [RendererType::Noop](crate::RendererType) to reproduce a bug without the application.
+///
+/// [Replayer::new] recreates the resources and view settings of the recording, each
+/// [Replayer::replay_frame] call then submits the recorded frame again. The resources created by
+/// the replayer are destroyed when it's dropped. Commands referring to resources the recording
+/// doesn't know, f.e. created before the recorder was installed, are skipped, as are commands
+/// with less data than the call reads, f.e. from a damaged file.
+///
+/// ```ignore
+/// let recording = Recording::load("frame.bgfxrec")?;
+/// let mut replayer = Replayer::new(recording);
+/// loop {
+///     replayer.replay_frame();
+///     frame(false);
+/// }
+/// ```
+pub struct Replayer {
+    recording: Recording,
+    /// Recorded handle to the handle created by the replayer.
+    handles: HashMap<(HandleKind, u16), u16>,
+    /// Type and number of elements of the recorded uniforms.
+    uniforms: HashMap<u16, (u32, u16)>,
+    /// Format of the recorded textures, to check the size of updates.
+    textures: HashMap<u16, TextureFormat>,
+}
+
+impl Replayer {
+    pub fn new(recording: Recording) -> Replayer {
+        let mut replayer = Replayer {
+            recording,
+            handles: HashMap::new(),
+            uniforms: HashMap::new(),
+            textures: HashMap::new(),
+        };
+        let setup = std::mem::take(&mut replayer.recording.setup);
+        for command in &setup {
+            replayer.replay(command);
+        }
+        replayer.recording.setup = setup;
+        replayer
+    }
+
+    pub fn recording(&self) -> &Recording {
+        &self.recording
+    }
+
+    /// Issues the calls of the recorded frame, without the final `frame`.
+    pub fn replay_frame(&mut self) {
+        let frame = std::mem::take(&mut self.recording.frame);
+        for command in &frame {
+            self.replay(command);
+        }
+        self.recording.frame = frame;
+    }
+
+    fn get(&self, kind: HandleKind, handle: u16) -> Option<u16> {
+        self.handles.get(&(kind, handle)).copied()
+    }
+
+    fn insert(&mut self, kind: HandleKind, recorded: u16, created: u16) {
+        if created == u16::MAX {
+            return;
+        }
+        // A resource recreated with a program or frame buffer that depends on it replaces the
+        // earlier copy.
+        if let Some(previous) = self.handles.insert((kind, recorded), created) {
+            unsafe { destroy(kind, previous) };
+        }
+    }
+
+    fn remove(&mut self, kind: HandleKind, handle: u16) {
+        match kind {
+            HandleKind::Uniform => {
+                self.uniforms.remove(&handle);
+            }
+            HandleKind::Texture => {
+                self.textures.remove(&handle);
+            }
+            _ => {}
+        }
+        if let Some(created) = self.handles.remove(&(kind, handle)) {
+            unsafe { destroy(kind, created) };
+        }
+    }
+
+    /// Replays a single command, `None` if it refers to an unknown resource or its data is too
+    /// short.
+    fn replay(&mut self, command: &Command) -> Option<()> {
+        use HandleKind::*;
+
+        unsafe {
+            match command {
+                Command::CreateVertexBuffer {
+                    handle,
+                    data,
+                    layout,
+                    flags,
+                } => {
+                    let layout = layout.to_raw();
+                    let created = backend::bgfx_create_vertex_buffer(memory(data), &layout, *flags);
+                    self.insert(VertexBuffer, *handle, created.idx);
+                }
+                Command::CreateIndexBuffer {
+                    handle,
+                    data,
+                    flags,
+                } => {
+                    let created = backend::bgfx_create_index_buffer(memory(data), *flags);
+                    self.insert(IndexBuffer, *handle, created.idx);
+                }
+                Command::CreateDynamicVertexBuffer {
+                    handle,
+                    num,
+                    layout,
+                    flags,
+                } => {
+                    let layout = layout.to_raw();
+                    let created = backend::bgfx_create_dynamic_vertex_buffer(*num, &layout, *flags);
+                    self.insert(DynamicVertexBuffer, *handle, created.idx);
+                }
+                Command::CreateDynamicVertexBufferMem {
+                    handle,
+                    data,
+                    layout,
+                    flags,
+                } => {
+                    let layout = layout.to_raw();
+                    let created = backend::bgfx_create_dynamic_vertex_buffer_mem(
+                        memory(data),
+                        &layout,
+                        *flags,
+                    );
+                    self.insert(DynamicVertexBuffer, *handle, created.idx);
+                }
+                Command::CreateDynamicIndexBuffer { handle, num, flags } => {
+                    let created = backend::bgfx_create_dynamic_index_buffer(*num, *flags);
+                    self.insert(DynamicIndexBuffer, *handle, created.idx);
+                }
+                Command::CreateDynamicIndexBufferMem {
+                    handle,
+                    data,
+                    flags,
+                } => {
+                    let created =
+                        backend::bgfx_create_dynamic_index_buffer_mem(memory(data), *flags);
+                    self.insert(DynamicIndexBuffer, *handle, created.idx);
+                }
+                Command::UpdateDynamicVertexBuffer {
+                    handle,
+                    start_vertex,
+                    data,
+                } => {
+                    let handle = self.get(DynamicVertexBuffer, *handle)?;
+                    backend::bgfx_update_dynamic_vertex_buffer(
+                        bgfx_dynamic_vertex_buffer_handle_t { idx: handle },
+                        *start_vertex,
+                        memory(data),
+                    );
+                }
+                Command::UpdateDynamicIndexBuffer {
+                    handle,
+                    start_index,
+                    data,
+                } => {
+                    let handle = self.get(DynamicIndexBuffer, *handle)?;
+                    backend::bgfx_update_dynamic_index_buffer(
+                        bgfx_dynamic_index_buffer_handle_t { idx: handle },
+                        *start_index,
+                        memory(data),
+                    );
+                }
+                Command::CreateShader { handle, data } => {
+                    let created = backend::bgfx_create_shader(memory(data));
+                    self.insert(Shader, *handle, created.idx);
+                }
+                Command::CreateProgram { handle, vsh, fsh } => {
+                    let vsh = self.get(Shader, *vsh)?;
+                    let fsh = self.get(Shader, *fsh)?;
+                    let created = backend::bgfx_create_program(
+                        bgfx_shader_handle_t { idx: vsh },
+                        bgfx_shader_handle_t { idx: fsh },
+                        false,
+                    );
+                    self.insert(Program, *handle, created.idx);
+                }
+                Command::CreateComputeProgram { handle, csh } => {
+                    let csh = self.get(Shader, *csh)?;
+                    let created = backend::bgfx_create_compute_program(
+                        bgfx_shader_handle_t { idx: csh },
+                        false,
+                    );
+                    self.insert(Program, *handle, created.idx);
+                }
+                Command::CreateUniform {
+                    handle,
+                    name,
+                    uniform_type,
+                    num,
+                } => {
+                    let name = CString::new(name.as_str()).ok()?;
+                    let created = backend::bgfx_create_uniform(name.as_ptr(), *uniform_type, *num);
+                    self.insert(Uniform, *handle, created.idx);
+                    self.uniforms.insert(*handle, (*uniform_type, *num));
+                }
+                Command::CreateTexture {
+                    handle,
+                    data,
+                    flags,
+                    skip,
+                } => {
+                    let created =
+                        backend::bgfx_create_texture(memory(data), *flags, *skip, ptr::null_mut());
+                    self.insert(Texture, *handle, created.idx);
+                    if let Some(format) = Image::parse(data)
+                        .ok()
+                        .and_then(|image| image.format().ok())
+                    {
+                        self.textures.insert(*handle, format);
+                    }
+                }
+                Command::CreateTexture2d {
+                    handle,
+                    width,
+                    height,
+                    has_mips,
+                    num_layers,
+                    format,
+                    flags,
+                    data,
+                } => {
+                    let format = texture_format(*format)?;
+                    let info = TextureInfo::calc(
+                        *width,
+                        *height,
+                        1,
+                        false,
+                        *has_mips,
+                        *num_layers,
+                        format,
+                    );
+                    check_texture_data(data, &info)?;
+                    let created = backend::bgfx_create_texture_2d(
+                        *width,
+                        *height,
+                        *has_mips,
+                        *num_layers,
+                        format as u32,
+                        *flags,
+                        optional_memory(data),
+                    );
+                    self.insert(Texture, *handle, created.idx);
+                    self.textures.insert(*handle, format);
+                }
+                Command::CreateTexture2dScaled {
+                    handle,
+                    ratio,
+                    has_mips,
+                    num_layers,
+                    format,
+                    flags,
+                } => {
+                    let format = texture_format(*format)?;
+                    let created = backend::bgfx_create_texture_2d_scaled(
+                        *ratio,
+                        *has_mips,
+                        *num_layers,
+                        format as u32,
+                        *flags,
+                    );
+                    self.insert(Texture, *handle, created.idx);
+                    self.textures.insert(*handle, format);
+                }
+                Command::CreateTexture3d {
+                    handle,
+                    width,
+                    height,
+                    depth,
+                    has_mips,
+                    format,
+                    flags,
+                    data,
+                } => {
+                    let format = texture_format(*format)?;
+                    let info =
+                        TextureInfo::calc(*width, *height, *depth, false, *has_mips, 1, format);
+                    check_texture_data(data, &info)?;
+                    let created = backend::bgfx_create_texture_3d(
+                        *width,
+                        *height,
+                        *depth,
+                        *has_mips,
+                        format as u32,
+                        *flags,
+                        optional_memory(data),
+                    );
+                    self.insert(Texture, *handle, created.idx);
+                    self.textures.insert(*handle, format);
+                }
+                Command::CreateTextureCube {
+                    handle,
+                    size,
+                    has_mips,
+                    num_layers,
+                    format,
+                    flags,
+                    data,
+                } => {
+                    let format = texture_format(*format)?;
+                    let info =
+                        TextureInfo::calc(*size, *size, 1, true, *has_mips, *num_layers, format);
+                    check_texture_data(data, &info)?;
+                    let created = backend::bgfx_create_texture_cube(
+                        *size,
+                        *has_mips,
+                        *num_layers,
+                        format as u32,
+                        *flags,
+                        optional_memory(data),
+                    );
+                    self.insert(Texture, *handle, created.idx);
+                    self.textures.insert(*handle, format);
+                }
+                Command::UpdateTexture2d {
+                    handle,
+                    layer,
+                    mip,
+                    x,
+                    y,
+                    width,
+                    height,
+                    data,
+                    pitch,
+                } => {
+                    let format = *self.textures.get(handle)?;
+                    if data.len() < update_size(format, *width, *height, 1, *pitch) {
+                        return None;
+                    }
+                    let handle = self.get(Texture, *handle)?;
+                    backend::bgfx_update_texture_2d(
+                        bgfx_texture_handle_t { idx: handle },
+                        *layer,
+                        *mip,
+                        *x,
+                        *y,
+                        *width,
+                        *height,
+                        memory(data),
+                        *pitch,
+                    );
+                }
+                Command::UpdateTexture3d {
+                    handle,
+                    mip,
+                    x,
+                    y,
+                    z,
+                    width,
+                    height,
+                    depth,
+                    data,
+                } => {
+                    let format = *self.textures.get(handle)?;
+                    if data.len() < update_size(format, *width, *height, *depth, u16::MAX) {
+                        return None;
+                    }
+                    let handle = self.get(Texture, *handle)?;
+                    backend::bgfx_update_texture_3d(
+                        bgfx_texture_handle_t { idx: handle },
+                        *mip,
+                        *x,
+                        *y,
+                        *z,
+                        *width,
+                        *height,
+                        *depth,
+                        memory(data),
+                    );
+                }
+                Command::UpdateTextureCube {
+                    handle,
+                    layer,
+                    side,
+                    mip,
+                    x,
+                    y,
+                    width,
+                    height,
+                    data,
+                    pitch,
+                } => {
+                    let format = *self.textures.get(handle)?;
+                    if data.len() < update_size(format, *width, *height, 1, *pitch) {
+                        return None;
+                    }
+                    let handle = self.get(Texture, *handle)?;
+                    backend::bgfx_update_texture_cube(
+                        bgfx_texture_handle_t { idx: handle },
+                        *layer,
+                        *side,
+                        *mip,
+                        *x,
+                        *y,
+                        *width,
+                        *height,
+                        memory(data),
+                        *pitch,
+                    );
+                }
+                Command::CreateFrameBuffer {
+                    handle,
+                    width,
+                    height,
+                    format,
+                    texture_flags,
+                } => {
+                    let created =
+                        backend::bgfx_create_frame_buffer(*width, *height, *format, *texture_flags);
+                    self.insert(FrameBuffer, *handle, created.idx);
+                }
+                Command::CreateFrameBufferScaled {
+                    handle,
+                    ratio,
+                    format,
+                    texture_flags,
+                } => {
+                    let created =
+                        backend::bgfx_create_frame_buffer_scaled(*ratio, *format, *texture_flags);
+                    self.insert(FrameBuffer, *handle, created.idx);
+                }
+                Command::CreateFrameBufferFromHandles { handle, textures } => {
+                    let textures = textures
+                        .iter()
+                        .map(|&texture| {
+                            let idx = self.get(Texture, texture)?;
+                            Some(bgfx_texture_handle_t { idx })
+                        })
+                        .collect::<Option<Vec<_>>>()?;
+                    let created = backend::bgfx_create_frame_buffer_from_handles(
+                        textures.len() as u8,
+                        textures.as_ptr(),
+                        false,
+                    );
+                    self.insert(FrameBuffer, *handle, created.idx);
+                }
+                Command::Destroy { kind, handle } => self.remove(*kind, *handle),
+
+                Command::Reset {
+                    width,
+                    height,
+                    flags,
+                    format,
+                } => backend::bgfx_reset(*width, *height, *flags, *format),
+                Command::SetViewRect {
+                    view,
+                    x,
+                    y,
+                    width,
+                    height,
+                } => backend::bgfx_set_view_rect(*view, *x, *y, *width, *height),
+                Command::SetViewRectRatio { view, x, y, ratio } => {
+                    backend::bgfx_set_view_rect_ratio(*view, *x, *y, *ratio)
+                }
+                Command::SetViewScissor {
+                    view,
+                    x,
+                    y,
+                    width,
+                    height,
+                } => backend::bgfx_set_view_scissor(*view, *x, *y, *width, *height),
+                Command::SetViewClear {
+                    view,
+                    flags,
+                    rgba,
+                    depth,
+                    stencil,
+                } => backend::bgfx_set_view_clear(*view, *flags, *rgba, *depth, *stencil),
+                Command::SetViewMode { view, mode } => backend::bgfx_set_view_mode(*view, *mode),
+                Command::SetViewFrameBuffer { view, handle } => {
+                    // An invalid handle is the backbuffer.
+                    let idx = match *handle {
+                        u16::MAX => u16::MAX,
+                        handle => self.get(FrameBuffer, handle)?,
+                    };
+                    backend::bgfx_set_view_frame_buffer(*view, bgfx_frame_buffer_handle_t { idx });
+                }
+                Command::SetViewTransform {
+                    view,
+                    view_mtx,
+                    proj_mtx,
+                } => backend::bgfx_set_view_transform(*view, matrix(view_mtx)?, matrix(proj_mtx)?),
+                Command::ResetView { view } => backend::bgfx_reset_view(*view),
+
+                Command::Touch { view } => backend::bgfx_touch(*view),
+                Command::SetState { state, rgba } => backend::bgfx_set_state(*state, *rgba),
+                Command::SetStencil { front, back } => backend::bgfx_set_stencil(*front, *back),
+                Command::SetScissor {
+                    x,
+                    y,
+                    width,
+                    height,
+                } => {
+                    backend::bgfx_set_scissor(*x, *y, *width, *height);
+                }
+                Command::SetTransform { matrices } => {
+                    let num = (matrices.len() / 16) as u16;
+                    backend::bgfx_set_transform(matrices.as_ptr() as *const c_void, num);
+                }
+                Command::SetUniform { handle, data, num } => {
+                    let &(uniform_type, created_num) = self.uniforms.get(handle)?;
+                    let count = if *num == u16::MAX { created_num } else { *num };
+                    if data.len() < uniform_size(uniform_type) * count as usize {
+                        return None;
+                    }
+                    let handle = self.get(Uniform, *handle)?;
+                    backend::bgfx_set_uniform(
+                        bgfx_uniform_handle_t { idx: handle },
+                        data.as_ptr() as *const c_void,
+                        *num,
+                    );
+                }
+                Command::SetTexture {
+                    stage,
+                    sampler,
+                    handle,
+                    flags,
+                } => {
+                    let sampler = self.get(Uniform, *sampler)?;
+                    let handle = self.get(Texture, *handle)?;
+                    backend::bgfx_set_texture(
+                        *stage,
+                        bgfx_uniform_handle_t { idx: sampler },
+                        bgfx_texture_handle_t { idx: handle },
+                        *flags,
+                    );
+                }
+                Command::SetVertexBuffer {
+                    stream,
+                    handle,
+                    start_vertex,
+                    num_vertices,
+                } => {
+                    let handle = self.get(VertexBuffer, *handle)?;
+                    backend::bgfx_set_vertex_buffer(
+                        *stream,
+                        bgfx_vertex_buffer_handle_t { idx: handle },
+                        *start_vertex,
+                        *num_vertices,
+                    );
+                }
+                Command::SetIndexBuffer {
+                    handle,
+                    first_index,
+                    num_indices,
+                } => {
+                    let handle = self.get(IndexBuffer, *handle)?;
+                    backend::bgfx_set_index_buffer(
+                        bgfx_index_buffer_handle_t { idx: handle },
+                        *first_index,
+                        *num_indices,
+                    );
+                }
+                Command::SetDynamicVertexBuffer {
+                    stream,
+                    handle,
+                    start_vertex,
+                    num_vertices,
+                } => {
+                    let handle = self.get(DynamicVertexBuffer, *handle)?;
+                    backend::bgfx_set_dynamic_vertex_buffer(
+                        *stream,
+                        bgfx_dynamic_vertex_buffer_handle_t { idx: handle },
+                        *start_vertex,
+                        *num_vertices,
+                    );
+                }
+                Command::SetDynamicIndexBuffer {
+                    handle,
+                    first_index,
+                    num_indices,
+                } => {
+                    let handle = self.get(DynamicIndexBuffer, *handle)?;
+                    backend::bgfx_set_dynamic_index_buffer(
+                        bgfx_dynamic_index_buffer_handle_t { idx: handle },
+                        *first_index,
+                        *num_indices,
+                    );
+                }
+                Command::SetTransientVertexBuffer {
+                    stream,
+                    layout,
+                    data,
+                    start_vertex,
+                    num_vertices,
+                } => {
+                    let layout = layout.to_raw();
+                    let num = data.len() as u32 / (layout.stride as u32).max(1);
+                    if backend::bgfx_get_avail_transient_vertex_buffer(num, &layout) < num {
+                        return None;
+                    }
+                    let mut tvb = std::mem::zeroed::<bgfx_transient_vertex_buffer_t>();
+                    backend::bgfx_alloc_transient_vertex_buffer(&mut tvb, num, &layout);
+                    fill(tvb.data, tvb.size, data);
+                    backend::bgfx_set_transient_vertex_buffer(
+                        *stream,
+                        &tvb,
+                        *start_vertex,
+                        *num_vertices,
+                    );
+                }
+                Command::SetTransientIndexBuffer {
+                    data,
+                    index32,
+                    first_index,
+                    num_indices,
+                } => {
+                    let num = data.len() as u32 / if *index32 { 4 } else { 2 };
+                    if backend::bgfx_get_avail_transient_index_buffer(num, *index32) < num {
+                        return None;
+                    }
+                    let mut tib = std::mem::zeroed::<bgfx_transient_index_buffer_t>();
+                    backend::bgfx_alloc_transient_index_buffer(&mut tib, num, *index32);
+                    fill(tib.data, tib.size, data);
+                    backend::bgfx_set_transient_index_buffer(&tib, *first_index, *num_indices);
+                }
+                Command::SetInstanceDataBuffer {
+                    data,
+                    stride,
+                    start,
+                    num,
+                } => {
+                    let count = data.len() as u32 / (*stride as u32).max(1);
+                    if backend::bgfx_get_avail_instance_data_buffer(count, *stride) < count {
+                        return None;
+                    }
+                    let mut idb = std::mem::zeroed::<bgfx_instance_data_buffer_t>();
+                    backend::bgfx_alloc_instance_data_buffer(&mut idb, count, *stride);
+                    fill(idb.data, idb.size, data);
+                    backend::bgfx_set_instance_data_buffer(&idb, *start, *num);
+                }
+                Command::SetVertexCount { num_vertices } => {
+                    backend::bgfx_set_vertex_count(*num_vertices)
+                }
+                Command::SetInstanceCount { num_instances } => {
+                    backend::bgfx_set_instance_count(*num_instances)
+                }
+                Command::Submit {
+                    view,
+                    program,
+                    depth,
+                    flags,
+                } => {
+                    let program = self.get(Program, *program)?;
+                    backend::bgfx_submit(
+                        *view,
+                        bgfx_program_handle_t { idx: program },
+                        *depth,
+                        *flags,
+                    );
+                }
+                Command::Dispatch {
+                    view,
+                    program,
+                    num_x,
+                    num_y,
+                    num_z,
+                    flags,
+                } => {
+                    let program = self.get(Program, *program)?;
+                    backend::bgfx_dispatch(
+                        *view,
+                        bgfx_program_handle_t { idx: program },
+                        *num_x,
+                        *num_y,
+                        *num_z,
+                        *flags,
+                    );
+                }
+                Command::Discard { flags } => backend::bgfx_discard(*flags),
+            }
+        }
+        Some(())
+    }
+}
+
+impl Drop for Replayer {
+    fn drop(&mut self) {
+        for ((kind, _), created) in self.handles.drain() {
+            unsafe { destroy(kind, created) };
+        }
+    }
+}
+
+unsafe fn memory(data: &[u8]) -> *const bgfx_memory_t {
+    backend::bgfx_copy(data.as_ptr() as *const c_void, data.len() as u32)
+}
+
+unsafe fn optional_memory(data: &Option<Vec<u8>>) -> *const bgfx_memory_t {
+    match data {
+        Some(data) => memory(data),
+        None => ptr::null(),
+    }
+}
+
+/// The recorded texture format, `None` for values that aren't a [TextureFormat].
+fn texture_format(format: u32) -> Option<TextureFormat> {
+    TextureFormat::all().nth(format as usize)
+}
+
+/// `None` if `data` is shorter than the storage size of the texture, no data is fine.
+fn check_texture_data(data: &Option<Vec<u8>>, info: &TextureInfo) -> Option<()> {
+    match data {
+        Some(data) if data.len() < info.storage_size as usize => None,
+        _ => Some(()),
+    }
+}
+
+/// Bytes bgfx reads to update `width` x `height` x `depth` texels with rows `pitch` bytes apart,
+/// `u16::MAX` for tightly packed rows. Rows of compressed formats are rows of blocks.
+fn update_size(format: TextureFormat, width: u16, height: u16, depth: u16, pitch: u16) -> usize {
+    let (block_width, block_height) = format.block_size();
+    let (block_width, block_height) = (block_width.max(1) as usize, block_height.max(1) as usize);
+    let row = ((width as usize).div_ceil(block_width)
+        * block_width
+        * block_height
+        * format.bits_per_pixel() as usize)
+        .div_ceil(8);
+    let pitch = if pitch == u16::MAX {
+        row
+    } else {
+        pitch as usize
+    };
+    match (height as usize).div_ceil(block_height) * depth as usize {
+        0 => 0,
+        rows => pitch * (rows - 1) + row,
+    }
+}
+
+/// The 4x4 matrix or null, `None` if there are less than 16 floats.
+fn matrix(mtx: &Option<Vec<f32>>) -> Option<*const c_void> {
+    match mtx {
+        Some(mtx) if mtx.len() < 16 => None,
+        Some(mtx) => Some(mtx.as_ptr() as *const c_void),
+        None => Some(ptr::null()),
+    }
+}
+
+unsafe fn fill(dst: *mut u8, size: u32, data: &[u8]) {
+    ptr::copy_nonoverlapping(data.as_ptr(), dst, data.len().min(size as usize));
+}
+
+unsafe fn destroy(kind: HandleKind, idx: u16) {
+    match kind {
+        HandleKind::VertexBuffer => {
+            backend::bgfx_destroy_vertex_buffer(bgfx_vertex_buffer_handle_t { idx })
+        }
+        HandleKind::IndexBuffer => {
+            backend::bgfx_destroy_index_buffer(bgfx_index_buffer_handle_t { idx })
+        }
+        HandleKind::DynamicVertexBuffer => {
+            backend::bgfx_destroy_dynamic_vertex_buffer(bgfx_dynamic_vertex_buffer_handle_t { idx })
+        }
+        HandleKind::DynamicIndexBuffer => {
+            backend::bgfx_destroy_dynamic_index_buffer(bgfx_dynamic_index_buffer_handle_t { idx })
+        }
+        HandleKind::Shader => backend::bgfx_destroy_shader(bgfx_shader_handle_t { idx }),
+        HandleKind::Program => backend::bgfx_destroy_program(bgfx_program_handle_t { idx }),
+        HandleKind::Uniform => backend::bgfx_destroy_uniform(bgfx_uniform_handle_t { idx }),
+        HandleKind::Texture => backend::bgfx_destroy_texture(bgfx_texture_handle_t { idx }),
+        HandleKind::FrameBuffer => {
+            backend::bgfx_destroy_frame_buffer(bgfx_frame_buffer_handle_t { idx })
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::bgfx::{get_renderer_type, Memory, Program, RendererType, Shader};
+
+/// Returns the directory name used by bgfx's `shaderc` output tree for `renderer`.
+///
+/// This matches the layout of `examples/runtime/shaders` in bgfx (`dx11`, `glsl`, `metal`, ...).
+/// Returns `None` for renderers that have no shader binaries (f.e. [RendererType::Noop]).
+pub fn shader_dir_name(renderer: RendererType) -> Option<&'static str> {
+    match renderer {
+        RendererType::Direct3D9 => Some("dx9"),
+        RendererType::Direct3D11 | RendererType::Direct3D12 => Some("dx11"),
+        RendererType::Agc | RendererType::Gnm => Some("pssl"),
+        RendererType::Metal => Some("metal"),
+        RendererType::Nvn => Some("nvn"),
+        RendererType::OpenGL => Some("glsl"),
+        RendererType::OpenGLES => Some("essl"),
+        RendererType::Vulkan => Some("spirv"),
+        RendererType::WebGPU => Some("wgsl"),
+        RendererType::Noop | RendererType::Count => None,
+    }
+}
+
+/// Error returned when a shader can't be loaded from a [ShaderLibrary].
+#[derive(Debug)]
+pub enum ShaderError {
+    /// The renderer has no shader backend (f.e. [RendererType::Noop]).
+    UnsupportedRenderer(RendererType),
+    /// The library has no variant of `name` compiled for `renderer`.
+    MissingVariant {
+        name: String,
+        renderer: RendererType,
+        /// Path that was probed when loading from a directory.
+        path: Option<PathBuf>,
+    },
+    /// Reading the shader binary failed.
+    Io { path: PathBuf, error: std::io::Error },
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ShaderError::UnsupportedRenderer(renderer) => {
+                write!(f, "renderer {:?} has no shader backend", renderer)
+            }
+            ShaderError::MissingVariant {
+                name,
+                renderer,
+                path: Some(path),
+            } => write!(
+                f,
+                "shader `{}` has no {:?} variant (looked for {})",
+                name,
+                renderer,
+                path.display()
+            ),
+            ShaderError::MissingVariant { name, renderer, .. } => {
+                write!(f, "shader `{}` has no {:?} variant", name, renderer)
+            }
+            ShaderError::Io { path, error } => {
+                write!(f, "failed to read shader {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ShaderError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+enum ShaderSource {
+    Directory(PathBuf),
+    Memory(HashMap<(&'static str, String), Vec<u8>>),
+}
+
+/// Loads compiled shader binaries for the active renderer and caches the created handles.
+///
+/// Shaders are looked up by name (without extension), either from a directory laid out as
+/// `<root>/<backend>/<name>.bin` (see [shader_dir_name]) or from binaries registered in memory
+/// with [ShaderLibrary::insert]. The backend is picked from [get_renderer_type] so the library
+/// must be used after `bgfx::init`.
+///
+/// Created shaders are cached by name only, for the backend that was current when they were
+/// created. The handles don't survive `bgfx::shutdown` anyway, create a new library after
+/// initializing bgfx again, f.e. with another renderer.
+pub struct ShaderLibrary {
+    source: ShaderSource,
+    shaders: HashMap<String, Shader>,
+}
+
+impl ShaderLibrary {
+    /// Creates a library that reads `<root>/<backend>/<name>.bin` files.
+    pub fn from_dir<P: AsRef<Path>>(root: P) -> ShaderLibrary {
+        ShaderLibrary {
+            source: ShaderSource::Directory(root.as_ref().to_path_buf()),
+            shaders: HashMap::new(),
+        }
+    }
+
+    /// Creates an empty in-memory library. Add binaries with [ShaderLibrary::insert].
+    pub fn from_memory() -> ShaderLibrary {
+        ShaderLibrary {
+            source: ShaderSource::Memory(HashMap::new()),
+            shaders: HashMap::new(),
+        }
+    }
+
+    /// Registers the `renderer` variant of shader `name`.
+    ///
+    /// Variants for renderers sharing a backend (f.e. Direct3D11 and Direct3D12) only need to be
+    /// inserted once. Does nothing for libraries created with [ShaderLibrary::from_dir].
+    pub fn insert(&mut self, renderer: RendererType, name: &str, data: Vec<u8>) -> &mut Self {
+        if let Some(dir) = shader_dir_name(renderer) {
+            self.insert_variant(dir, name, data);
+        }
+        self
+    }
+
+    pub(crate) fn insert_variant(&mut self, dir: &'static str, name: &str, data: Vec<u8>) {
+        if let ShaderSource::Memory(variants) = &mut self.source {
+            variants.insert((dir, name.to_owned()), data);
+        }
+    }
+
+    /// Returns the shader `name` for the current renderer, creating it on first use. Later calls
+    /// return the cached shader even if the renderer changed.
+    pub fn shader(&mut self, name: &str) -> Result<&Shader, ShaderError> {
+        if !self.shaders.contains_key(name) {
+            let data = self.load(name, get_renderer_type())?;
+            self.shaders.insert(name.to_owned(), create_named_shader(name, data));
+        }
+
+        Ok(&self.shaders[name])
+    }
+
+    /// Creates a program from the vertex shader `vs` and fragment shader `fs`.
+    ///
+    /// The shaders stay cached in the library, so several programs can share them.
+    pub fn program(&mut self, vs: &str, fs: &str) -> Result<Program, ShaderError> {
+        self.shader(vs)?;
+        self.shader(fs)?;
+        Ok(Program::create_program(&self.shaders[vs], &self.shaders[fs], false))
+    }
+
+    /// Creates a compute program from the compute shader `cs`.
+    pub fn compute_program(&mut self, cs: &str) -> Result<Program, ShaderError> {
+        let shader = self.shader(cs)?;
+        Ok(Program::create_compute_program(shader, false))
+    }
+
+    /// Returns true if a variant of `name` is available for `renderer`, without creating it.
+    pub fn contains(&self, renderer: RendererType, name: &str) -> bool {
+        match (&self.source, shader_dir_name(renderer)) {
+            (ShaderSource::Directory(root), Some(dir)) => variant_path(root, dir, name).is_file(),
+            (ShaderSource::Memory(variants), Some(dir)) => {
+                variants.contains_key(&(dir, name.to_owned()))
+            }
+            (_, None) => false,
+        }
+    }
+
+    fn load(&self, name: &str, renderer: RendererType) -> Result<Vec<u8>, ShaderError> {
+        let dir = shader_dir_name(renderer).ok_or(ShaderError::UnsupportedRenderer(renderer))?;
+
+        let data = match &self.source {
+            ShaderSource::Directory(root) => {
+                let path = variant_path(root, dir, name);
+                match std::fs::read(&path) {
+                    Ok(data) => data,
+                    Err(error) if error.kind() == std::io::ErrorKind::NotFound => {
+                        return Err(ShaderError::MissingVariant {
+                            name: name.to_owned(),
+                            renderer,
+                            path: Some(path),
+                        })
+                    }
+                    Err(error) => return Err(ShaderError::Io { path, error }),
+                }
+            }
+            ShaderSource::Memory(variants) => variants
+                .get(&(dir, name.to_owned()))
+                .cloned()
+                .ok_or_else(|| ShaderError::MissingVariant {
+                    name: name.to_owned(),
+                    renderer,
+                    path: None,
+                })?,
+        };
+
+        Ok(data)
+    }
+}
+
+/// Creates the shader `name` from a shader binary, terminated the way bgfx's shader parser
+/// expects.
+pub(crate) fn create_named_shader(name: &str, mut data: Vec<u8>) -> Shader {
+    data.push(0); // this is to terminate the data
+    let shader = Shader::create_shader(&Memory::copy(&data));
+    shader.set_name(name);
+    shader
+}
+
+fn variant_path(root: &Path, dir: &str, name: &str) -> PathBuf {
+    let mut path = root.join(dir);
+    path.push(format!("{}.bin", name));
+    path
+}
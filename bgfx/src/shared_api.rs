@@ -0,0 +1,134 @@
+//! bgfx loaded at runtime from a shared library, enabled with the `shared-api` feature.
+//!
+//! bgfx-sys doesn't compile bgfx in this mode, every call goes through the `bgfx_interface_vtbl_t`
+//! returned by `bgfx_get_interface` of `libbgfx-shared`. Plugins and hot reloaded game code loading
+//! the same library, or given the interface with [set_interface], share one bgfx instance with
+//! the host.
+//!
+//! ```ignore
+//! bgfx_rs::shared_api::load()?;
+//! bgfx::init(&init);
+//!
+//! // in the plugin, with the pointer handed over by the host
+//! unsafe { bgfx_rs::shared_api::set_interface(interface)? };
+//! ```
+
+use std::ffi::OsStr;
+use std::fmt;
+use std::sync::OnceLock;
+
+use bgfx_sys::{bgfx_interface_vtbl_t, BGFX_API_VERSION, PFN_BGFX_GET_INTERFACE};
+use libloading::Library;
+
+/// Error returned when loading the shared library fails.
+#[derive(Debug)]
+pub enum SharedApiError {
+    /// The library could not be opened or has no `bgfx_get_interface`.
+    Load(libloading::Error),
+    /// The library was built from bgfx with a different `BGFX_API_VERSION` than bgfx-sys.
+    Version(u32),
+    /// [set_interface] was given a null pointer.
+    NullInterface,
+    /// An interface was already set, it can't be replaced while handles may point into it.
+    AlreadyLoaded,
+}
+
+impl fmt::Display for SharedApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SharedApiError::Load(error) => write!(f, "failed to load bgfx: {}", error),
+            SharedApiError::Version(version) => {
+                write!(f, "bgfx does not provide API version {}", version)
+            }
+            SharedApiError::NullInterface => write!(f, "the bgfx interface is null"),
+            SharedApiError::AlreadyLoaded => write!(f, "the bgfx interface is already loaded"),
+        }
+    }
+}
+
+impl std::error::Error for SharedApiError {}
+
+impl From<libloading::Error> for SharedApiError {
+    fn from(error: libloading::Error) -> SharedApiError {
+        SharedApiError::Load(error)
+    }
+}
+
+struct Interface {
+    vtbl: &'static bgfx_interface_vtbl_t,
+    // Keeps the library loaded for as long as the process runs.
+    _library: Option<Library>,
+}
+
+static INTERFACE: OnceLock<Interface> = OnceLock::new();
+
+/// File name of the library built by bgfx's `shared-lib` target on the current platform.
+pub const LIBRARY_NAME: &str = if cfg!(target_os = "windows") {
+    "bgfx-shared.dll"
+} else if cfg!(target_os = "macos") {
+    "libbgfx-shared.dylib"
+} else {
+    "libbgfx-shared.so"
+};
+
+/// Loads [LIBRARY_NAME] from the library search path of the platform, see [load_from].
+pub fn load() -> Result<(), SharedApiError> {
+    load_from(LIBRARY_NAME)
+}
+
+/// Loads bgfx from the shared library at `path` and routes all calls through its interface.
+///
+/// Has to be called once before any other call, the interface can't be replaced afterwards.
+pub fn load_from(path: impl AsRef<OsStr>) -> Result<(), SharedApiError> {
+    if INTERFACE.get().is_some() {
+        return Err(SharedApiError::AlreadyLoaded);
+    }
+
+    unsafe {
+        let library = Library::new(path.as_ref())?;
+        let get_interface = *library.get::<PFN_BGFX_GET_INTERFACE>(b"bgfx_get_interface\0")?;
+        let vtbl = get_interface
+            .and_then(|get_interface| get_interface(BGFX_API_VERSION).as_ref())
+            .ok_or(SharedApiError::Version(BGFX_API_VERSION))?;
+
+        set(Interface {
+            vtbl,
+            _library: Some(library),
+        })
+    }
+}
+
+/// Uses an interface obtained by someone else, f.e. the host application of a plugin, instead of
+/// loading the library.
+///
+/// # Safety
+///
+/// `vtbl` has to be a `bgfx_interface_vtbl_t` of `BGFX_API_VERSION` that stays valid for the rest
+/// of the process.
+pub unsafe fn set_interface(vtbl: *mut bgfx_interface_vtbl_t) -> Result<(), SharedApiError> {
+    let vtbl = vtbl.as_ref().ok_or(SharedApiError::NullInterface)?;
+    set(Interface {
+        vtbl,
+        _library: None,
+    })
+}
+
+/// The loaded interface, to hand over to plugins with [set_interface].
+pub fn interface() -> Option<*mut bgfx_interface_vtbl_t> {
+    INTERFACE
+        .get()
+        .map(|interface| interface.vtbl as *const _ as *mut _)
+}
+
+fn set(interface: Interface) -> Result<(), SharedApiError> {
+    INTERFACE
+        .set(interface)
+        .map_err(|_| SharedApiError::AlreadyLoaded)
+}
+
+pub(crate) fn vtbl() -> &'static bgfx_interface_vtbl_t {
+    match INTERFACE.get() {
+        Some(interface) => interface.vtbl,
+        None => panic!("bgfx is not loaded, call shared_api::load first"),
+    }
+}
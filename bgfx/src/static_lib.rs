@@ -1,7 +1,23 @@
+// Generated by `cargo run -p codegen` from bgfx.idl (API version 115), don't edit.
+// Hand-written parts go into `static_lib/`.
+
 use core::ffi::c_void;
 use std::mem::MaybeUninit;
 
-use cfixed_string::CFixedString;
+use crate::backend;
+
+mod dbg_text;
+pub use dbg_text::*;
+
+mod memory;
+pub use memory::*;
+
+mod transform;
+pub use transform::*;
+
+mod uniform;
+pub use uniform::*;
+
 /// Fatal error enum.
 #[repr(u32)]
 #[derive(Clone, Copy, PartialEq, Debug)]
@@ -1132,6 +1148,8 @@ pub struct VertexLayout {
     handle: bgfx_sys::bgfx_vertex_layout_handle_t,
 }
 
+pub type ViewId = u16;
+
 pub struct InitArgs {
     /// Access. See [Access].
     pub access: Access,
@@ -1568,7 +1586,7 @@ pub struct Caps {
     /// Enumerated GPUs.
     pub gpu: [GPU; 4usize],
     /// Renderer runtime limits.
-    pub limits: Limits,
+    pub limits: CapsLimits,
     /// Supported texture format capabilities flags:
     ///   - [CapsFormatFlags::TEXTURE_NONE] - Texture format is not supported.
     ///   - [CapsFormatFlags::TEXTURE_2D] - Texture format is supported.
@@ -1930,7 +1948,7 @@ impl DynamicIndexBuffer {
     ///       index buffers.
     pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags);
+            let _ret = backend::bgfx_create_dynamic_index_buffer(num, flags);
             DynamicIndexBuffer { handle: _ret }
         }
     }
@@ -1951,7 +1969,7 @@ impl DynamicIndexBuffer {
     ///       index buffers.
     pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
+            let _ret = backend::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
             DynamicIndexBuffer { handle: _ret }
         }
     }
@@ -1963,7 +1981,7 @@ impl DynamicIndexBuffer {
     /// Index buffer data.
     pub fn update_dynamic_index_buffer(&self, start_index: u32, mem: &Memory) {
         unsafe {
-            bgfx_sys::bgfx_update_dynamic_index_buffer(self.handle, start_index, mem.handle);
+            backend::bgfx_update_dynamic_index_buffer(self.handle, start_index, mem.handle);
         }
     }
     /// * `handle`:
@@ -1974,7 +1992,7 @@ impl DynamicIndexBuffer {
     /// Number of indices to render.
     pub fn set_dynamic_index_buffer(&self, first_index: u32, num_indices: u32) {
         unsafe {
-            bgfx_sys::bgfx_set_dynamic_index_buffer(self.handle, first_index, num_indices);
+            backend::bgfx_set_dynamic_index_buffer(self.handle, first_index, num_indices);
         }
     }
 }
@@ -1982,7 +2000,7 @@ impl DynamicIndexBuffer {
 impl Drop for DynamicIndexBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_dynamic_index_buffer(self.handle);
+            backend::bgfx_destroy_dynamic_index_buffer(self.handle);
         }
     }
 }
@@ -2012,7 +2030,7 @@ impl DynamicVertexBuffer {
     ) -> DynamicVertexBuffer {
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
+            let _ret = backend::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
             DynamicVertexBuffer { handle: _ret }
         }
     }
@@ -2040,7 +2058,7 @@ impl DynamicVertexBuffer {
     ) -> DynamicVertexBuffer {
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
+            let _ret = backend::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
             DynamicVertexBuffer { handle: _ret }
         }
     }
@@ -2052,7 +2070,7 @@ impl DynamicVertexBuffer {
     /// Vertex buffer data.
     pub fn update_dynamic_vertex_buffer(&self, start_vertex: u32, mem: &Memory) {
         unsafe {
-            bgfx_sys::bgfx_update_dynamic_vertex_buffer(self.handle, start_vertex, mem.handle);
+            backend::bgfx_update_dynamic_vertex_buffer(self.handle, start_vertex, mem.handle);
         }
     }
     /// * `handle`:
@@ -2063,7 +2081,7 @@ impl DynamicVertexBuffer {
     /// Number of data instances.
     pub fn set_instance_data_from_dynamic_vertex_buffer(&self, start_vertex: u32, num: u32) {
         unsafe {
-            bgfx_sys::bgfx_set_instance_data_from_dynamic_vertex_buffer(
+            backend::bgfx_set_instance_data_from_dynamic_vertex_buffer(
                 self.handle,
                 start_vertex,
                 num,
@@ -2075,7 +2093,7 @@ impl DynamicVertexBuffer {
 impl Drop for DynamicVertexBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_dynamic_vertex_buffer(self.handle);
+            backend::bgfx_destroy_dynamic_vertex_buffer(self.handle);
         }
     }
 }
@@ -2102,7 +2120,7 @@ impl FrameBuffer {
     ) -> FrameBuffer {
         unsafe {
             let _ret =
-                bgfx_sys::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
+                backend::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
             FrameBuffer { handle: _ret }
         }
     }
@@ -2125,7 +2143,7 @@ impl FrameBuffer {
     ) -> FrameBuffer {
         unsafe {
             let _ret =
-                bgfx_sys::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
+                backend::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
             FrameBuffer { handle: _ret }
         }
     }
@@ -2142,7 +2160,7 @@ impl FrameBuffer {
         destroy_texture: bool,
     ) -> FrameBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_handles(
+            let _ret = backend::bgfx_create_frame_buffer_from_handles(
                 num,
                 &handles.handle,
                 destroy_texture,
@@ -2164,7 +2182,7 @@ impl FrameBuffer {
     ) -> FrameBuffer {
         unsafe {
             let _attachment = std::mem::transmute(attachment);
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_attachment(
+            let _ret = backend::bgfx_create_frame_buffer_from_attachment(
                 num,
                 _attachment,
                 destroy_texture,
@@ -2189,7 +2207,7 @@ impl FrameBuffer {
         params: CreateFrameBufferFromNwhArgs,
     ) -> FrameBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
+            let _ret = backend::bgfx_create_frame_buffer_from_nwh(
                 nwh as _,
                 width,
                 height,
@@ -2208,7 +2226,7 @@ impl FrameBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_frame_buffer_name(self.handle, name.as_ptr() as _, name.len() as i32)
+            backend::bgfx_set_frame_buffer_name(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `handle`:
@@ -2216,7 +2234,7 @@ impl FrameBuffer {
     /// * `attachment`:
     pub fn get_texture(&self, attachment: u8) -> Texture {
         unsafe {
-            let _ret = bgfx_sys::bgfx_get_texture(self.handle, attachment);
+            let _ret = backend::bgfx_get_texture(self.handle, attachment);
             Texture { handle: _ret }
         }
     }
@@ -2227,7 +2245,7 @@ impl FrameBuffer {
     /// Will be passed to `bgfx::CallbackI::screenShot` callback.
     pub fn request_screen_shot(&self, file_path: &i8) {
         unsafe {
-            bgfx_sys::bgfx_request_screen_shot(self.handle, file_path);
+            backend::bgfx_request_screen_shot(self.handle, file_path);
         }
     }
 }
@@ -2235,7 +2253,7 @@ impl FrameBuffer {
 impl Drop for FrameBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_frame_buffer(self.handle);
+            backend::bgfx_destroy_frame_buffer(self.handle);
         }
     }
 }
@@ -2258,7 +2276,7 @@ impl IndexBuffer {
     ///       index buffers.
     pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags);
+            let _ret = backend::bgfx_create_index_buffer(mem.handle, flags);
             IndexBuffer { handle: _ret }
         }
     }
@@ -2271,7 +2289,7 @@ impl IndexBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_index_buffer_name(self.handle, name.as_ptr() as _, name.len() as i32)
+            backend::bgfx_set_index_buffer_name(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `handle`:
@@ -2282,7 +2300,7 @@ impl IndexBuffer {
     /// Number of indices to render.
     pub fn set_index_buffer(&self, first_index: u32, num_indices: u32) {
         unsafe {
-            bgfx_sys::bgfx_set_index_buffer(self.handle, first_index, num_indices);
+            backend::bgfx_set_index_buffer(self.handle, first_index, num_indices);
         }
     }
 }
@@ -2290,7 +2308,7 @@ impl IndexBuffer {
 impl Drop for IndexBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_index_buffer(self.handle);
+            backend::bgfx_destroy_index_buffer(self.handle);
         }
     }
 }
@@ -2300,7 +2318,7 @@ impl IndirectBuffer {
     /// Number of indirect calls.
     pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_indirect_buffer(num);
+            let _ret = backend::bgfx_create_indirect_buffer(num);
             IndirectBuffer { handle: _ret }
         }
     }
@@ -2309,7 +2327,7 @@ impl IndirectBuffer {
 impl Drop for IndirectBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_indirect_buffer(self.handle);
+            backend::bgfx_destroy_indirect_buffer(self.handle);
         }
     }
 }
@@ -2317,7 +2335,7 @@ impl Drop for IndirectBuffer {
 impl OcclusionQuery {
     pub fn create_occlusion_query() -> OcclusionQuery {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_occlusion_query();
+            let _ret = backend::bgfx_create_occlusion_query();
             OcclusionQuery { handle: _ret }
         }
     }
@@ -2328,7 +2346,7 @@ impl OcclusionQuery {
     /// can be `NULL` if result of occlusion query is not needed.
     pub fn get_result(&self, result: &mut i32) -> OcclusionQueryResult {
         unsafe {
-            let _ret = bgfx_sys::bgfx_get_result(self.handle, result);
+            let _ret = backend::bgfx_get_result(self.handle, result);
             std::mem::transmute(_ret)
         }
     }
@@ -2338,7 +2356,7 @@ impl OcclusionQuery {
     /// Render if occlusion query is visible.
     pub fn set_condition(&self, visible: bool) {
         unsafe {
-            bgfx_sys::bgfx_set_condition(self.handle, visible);
+            backend::bgfx_set_condition(self.handle, visible);
         }
     }
 }
@@ -2346,7 +2364,7 @@ impl OcclusionQuery {
 impl Drop for OcclusionQuery {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_occlusion_query(self.handle);
+            backend::bgfx_destroy_occlusion_query(self.handle);
         }
     }
 }
@@ -2360,7 +2378,7 @@ impl Program {
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
+            let _ret = backend::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
             Program { handle: _ret }
         }
     }
@@ -2370,7 +2388,7 @@ impl Program {
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_compute_program(csh.handle, destroy_shaders);
+            let _ret = backend::bgfx_create_compute_program(csh.handle, destroy_shaders);
             Program { handle: _ret }
         }
     }
@@ -2379,7 +2397,7 @@ impl Program {
 impl Drop for Program {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_program(self.handle);
+            backend::bgfx_destroy_program(self.handle);
         }
     }
 }
@@ -2389,7 +2407,7 @@ impl Shader {
     /// Shader binary.
     pub fn create_shader(mem: &Memory) -> Shader {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_shader(mem.handle);
+            let _ret = backend::bgfx_create_shader(mem.handle);
             Shader { handle: _ret }
         }
     }
@@ -2402,7 +2420,7 @@ impl Shader {
     /// that _name is zero terminated string).
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_shader_name(self.handle, name.as_ptr() as _, name.len() as i32)
+            backend::bgfx_set_shader_name(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `vsh`:
@@ -2413,7 +2431,7 @@ impl Shader {
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(&self, fsh: &Shader, destroy_shaders: bool) -> Program {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_program(self.handle, fsh.handle, destroy_shaders);
+            let _ret = backend::bgfx_create_program(self.handle, fsh.handle, destroy_shaders);
             Program { handle: _ret }
         }
     }
@@ -2423,7 +2441,7 @@ impl Shader {
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(&self, destroy_shaders: bool) -> Program {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_compute_program(self.handle, destroy_shaders);
+            let _ret = backend::bgfx_create_compute_program(self.handle, destroy_shaders);
             Program { handle: _ret }
         }
     }
@@ -2432,7 +2450,7 @@ impl Shader {
 impl Drop for Shader {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_shader(self.handle);
+            backend::bgfx_destroy_shader(self.handle);
         }
     }
 }
@@ -2454,7 +2472,7 @@ impl Texture {
     pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
         unsafe {
             let _info = std::mem::transmute(info);
-            let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags, skip, _info);
+            let _ret = backend::bgfx_create_texture(mem.handle, flags, skip, _info);
             Texture { handle: _ret }
         }
     }
@@ -2490,7 +2508,7 @@ impl Texture {
         mem: &Memory,
     ) -> Texture {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_texture_2d(
+            let _ret = backend::bgfx_create_texture_2d(
                 width,
                 height,
                 has_mips,
@@ -2526,7 +2544,7 @@ impl Texture {
         flags: u64,
     ) -> Texture {
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
+            let _ret = backend::bgfx_create_texture_2d_scaled(
                 ratio as _,
                 has_mips,
                 num_layers,
@@ -2571,7 +2589,7 @@ impl Texture {
             } else {
                 std::ptr::null()
             };
-            let _ret = bgfx_sys::bgfx_create_texture_3d(
+            let _ret = backend::bgfx_create_texture_3d(
                 width,
                 height,
                 depth,
@@ -2616,7 +2634,7 @@ impl Texture {
             } else {
                 std::ptr::null()
             };
-            let _ret = bgfx_sys::bgfx_create_texture_cube(
+            let _ret = backend::bgfx_create_texture_cube(
                 size,
                 has_mips,
                 num_layers,
@@ -2658,7 +2676,7 @@ impl Texture {
         pitch: u16,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_2d(
+            backend::bgfx_update_texture_2d(
                 self.handle,
                 layer,
                 mip,
@@ -2701,7 +2719,7 @@ impl Texture {
         mem: &Memory,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_3d(
+            backend::bgfx_update_texture_3d(
                 self.handle,
                 mip,
                 x,
@@ -2766,7 +2784,7 @@ impl Texture {
         pitch: u16,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_cube(
+            backend::bgfx_update_texture_cube(
                 self.handle,
                 layer,
                 side,
@@ -2789,7 +2807,7 @@ impl Texture {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_texture_name(self.handle, name.as_ptr() as _, name.len() as i32)
+            backend::bgfx_set_texture_name(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `handle`:
@@ -2797,7 +2815,7 @@ impl Texture {
     /// * `attachment`:
     pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
         unsafe {
-            let _ret = bgfx_sys::bgfx_get_texture(handle.handle, attachment);
+            let _ret = backend::bgfx_get_texture(handle.handle, attachment);
             Texture { handle: _ret }
         }
     }
@@ -2806,7 +2824,7 @@ impl Texture {
 impl Drop for Texture {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_texture(self.handle);
+            backend::bgfx_destroy_texture(self.handle);
         }
     }
 }
@@ -2819,7 +2837,7 @@ impl Uniform {
     pub fn get_uniform_info(&self, info: &mut UniformInfo) {
         unsafe {
             let _info = std::mem::transmute(info);
-            bgfx_sys::bgfx_get_uniform_info(self.handle, _info);
+            backend::bgfx_get_uniform_info(self.handle, _info);
         }
     }
 }
@@ -2827,7 +2845,7 @@ impl Uniform {
 impl Drop for Uniform {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_uniform(self.handle);
+            backend::bgfx_destroy_uniform(self.handle);
         }
     }
 }
@@ -2855,7 +2873,7 @@ impl VertexBuffer {
     ) -> VertexBuffer {
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
+            let _ret = backend::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
             VertexBuffer { handle: _ret }
         }
     }
@@ -2868,7 +2886,7 @@ impl VertexBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_vertex_buffer_name(
+            backend::bgfx_set_vertex_buffer_name(
                 self.handle,
                 name.as_ptr() as _,
                 name.len() as i32,
@@ -2885,7 +2903,7 @@ impl VertexBuffer {
     /// Dynamic vertex buffer.
     pub fn set_instance_data_from_vertex_buffer(&self, start_vertex: u32, num: u32) {
         unsafe {
-            bgfx_sys::bgfx_set_instance_data_from_vertex_buffer(self.handle, start_vertex, num);
+            backend::bgfx_set_instance_data_from_vertex_buffer(self.handle, start_vertex, num);
         }
     }
 }
@@ -2893,7 +2911,7 @@ impl VertexBuffer {
 impl Drop for VertexBuffer {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_vertex_buffer(self.handle);
+            backend::bgfx_destroy_vertex_buffer(self.handle);
         }
     }
 }
@@ -2904,7 +2922,7 @@ impl VertexLayout {
     pub fn create_vertex_layout(layout: &VertexLayoutBuilder) -> VertexLayout {
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_vertex_layout(_layout);
+            let _ret = backend::bgfx_create_vertex_layout(_layout);
             VertexLayout { handle: _ret }
         }
     }
@@ -2913,7 +2931,7 @@ impl VertexLayout {
 impl Drop for VertexLayout {
     fn drop(&mut self) {
         unsafe {
-            bgfx_sys::bgfx_destroy_vertex_layout(self.handle);
+            backend::bgfx_destroy_vertex_layout(self.handle);
         }
     }
 }
@@ -2980,20 +2998,12 @@ impl Init {
         let t = unsafe { t.assume_init() };
         unsafe {
             let _init = std::mem::transmute(&t);
-            bgfx_sys::bgfx_init_ctor(_init);
+            backend::bgfx_init_ctor(_init);
         }
         t
     }
 }
 
-impl Memory {
-    pub fn new() -> Memory {
-        let t = MaybeUninit::<Memory>::zeroed();
-        let t = unsafe { t.assume_init() };
-        t
-    }
-}
-
 impl TransientIndexBuffer {
     pub fn new() -> TransientIndexBuffer {
         let t = MaybeUninit::<TransientIndexBuffer>::zeroed();
@@ -3056,7 +3066,7 @@ impl Attachment {
     pub fn init(&self, handle: &Texture, params: InitArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_attachment_init(
+            backend::bgfx_attachment_init(
                 _self,
                 handle.handle,
                 params.access as _,
@@ -3113,7 +3123,7 @@ impl VertexLayoutBuilder {
     pub fn begin(&self, renderer_type: RendererType) -> &Self {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = bgfx_sys::bgfx_vertex_layout_begin(_self, renderer_type as _);
+            let _ret = backend::bgfx_vertex_layout_begin(_self, renderer_type as _);
             self
         }
     }
@@ -3135,7 +3145,7 @@ impl VertexLayoutBuilder {
     pub fn add(&self, attrib: Attrib, num: u8, type_r: AttribType, params: AddArgs) -> &Self {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = bgfx_sys::bgfx_vertex_layout_add(
+            let _ret = backend::bgfx_vertex_layout_add(
                 _self,
                 attrib as _,
                 num,
@@ -3151,23 +3161,47 @@ impl VertexLayoutBuilder {
     pub fn has(&self, attrib: Attrib) -> bool {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = bgfx_sys::bgfx_vertex_layout_has(_self, attrib as _);
+            let _ret = backend::bgfx_vertex_layout_has(_self, attrib as _);
             _ret
         }
     }
+    /// * `attrib`:
+    /// Attribute semantics. See: `bgfx::Attrib`
+    /// * `num`:
+    /// Number of elements.
+    /// * `type_r`:
+    /// Element type.
+    /// * `normalized`:
+    /// Attribute is normalized.
+    /// * `as_int`:
+    /// Attribute is packed as int.
+    pub fn decode(
+        &self,
+        attrib: Attrib,
+        num: &mut u8,
+        type_r: &mut AttribType,
+        normalized: &mut bool,
+        as_int: &mut bool,
+    ) {
+        unsafe {
+            let _self = std::mem::transmute(self);
+            let _type_r = std::mem::transmute(type_r);
+            backend::bgfx_vertex_layout_decode(_self, attrib as _, num, _type_r, normalized, as_int);
+        }
+    }
     /// * `num`:
     /// Number of bytes to skip.
     pub fn skip(&self, num: u8) -> &Self {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = bgfx_sys::bgfx_vertex_layout_skip(_self, num);
+            let _ret = backend::bgfx_vertex_layout_skip(_self, num);
             self
         }
     }
     pub fn end(&self) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_vertex_layout_end(_self);
+            backend::bgfx_vertex_layout_end(_self);
         }
     }
 }
@@ -3184,7 +3218,7 @@ impl Encoder {
     pub fn set_marker(&self, marker: &i8) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_marker(_self, marker);
+            backend::bgfx_encoder_set_marker(_self, marker);
         }
     }
     /// * `state`:
@@ -3203,7 +3237,7 @@ impl Encoder {
     pub fn set_state(&self, state: u64, rgba: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_state(_self, state, rgba);
+            backend::bgfx_encoder_set_state(_self, state, rgba);
         }
     }
     /// * `handle`:
@@ -3213,7 +3247,7 @@ impl Encoder {
     pub fn set_condition(&self, handle: &OcclusionQuery, visible: bool) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_condition(_self, handle.handle, visible);
+            backend::bgfx_encoder_set_condition(_self, handle.handle, visible);
         }
     }
     /// * `fstencil`:
@@ -3224,7 +3258,7 @@ impl Encoder {
     pub fn set_stencil(&self, fstencil: u32, bstencil: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_stencil(_self, fstencil, bstencil);
+            backend::bgfx_encoder_set_stencil(_self, fstencil, bstencil);
         }
     }
     /// * `x`:
@@ -3238,7 +3272,7 @@ impl Encoder {
     pub fn set_scissor(&self, x: u16, y: u16, width: u16, height: u16) -> u16 {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = bgfx_sys::bgfx_encoder_set_scissor(_self, x, y, width, height);
+            let _ret = backend::bgfx_encoder_set_scissor(_self, x, y, width, height);
             _ret
         }
     }
@@ -3247,7 +3281,7 @@ impl Encoder {
     pub fn set_scissor_cached(&self, cache: u16) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_scissor_cached(_self, cache);
+            backend::bgfx_encoder_set_scissor_cached(_self, cache);
         }
     }
     /// * `transform`:
@@ -3258,7 +3292,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _transform = std::mem::transmute(transform);
-            let _ret = bgfx_sys::bgfx_encoder_alloc_transform(_self, _transform, num);
+            let _ret = backend::bgfx_encoder_alloc_transform(_self, _transform, num);
             _ret
         }
     }
@@ -3271,7 +3305,7 @@ impl Encoder {
     pub fn set_index_buffer(&self, handle: &IndexBuffer, first_index: u32, num_indices: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_index_buffer(_self, handle.handle, first_index, num_indices);
+            backend::bgfx_encoder_set_index_buffer(_self, handle.handle, first_index, num_indices);
         }
     }
     /// * `handle`:
@@ -3288,7 +3322,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_index_buffer(
+            backend::bgfx_encoder_set_dynamic_index_buffer(
                 _self,
                 handle.handle,
                 first_index,
@@ -3311,7 +3345,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tib = std::mem::transmute(tib);
-            bgfx_sys::bgfx_encoder_set_transient_index_buffer(
+            backend::bgfx_encoder_set_transient_index_buffer(
                 _self,
                 _tib,
                 first_index,
@@ -3336,7 +3370,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_vertex_buffer(
+            backend::bgfx_encoder_set_vertex_buffer(
                 _self,
                 stream,
                 handle.handle,
@@ -3367,7 +3401,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_vertex_buffer_with_layout(
+            backend::bgfx_encoder_set_vertex_buffer_with_layout(
                 _self,
                 stream,
                 handle.handle,
@@ -3401,7 +3435,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_vertex_buffer(
+            backend::bgfx_encoder_set_dynamic_vertex_buffer(
                 _self,
                 stream,
                 handle.handle,
@@ -3432,7 +3466,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_vertex_buffer_with_layout(
+            backend::bgfx_encoder_set_dynamic_vertex_buffer_with_layout(
                 _self,
                 stream,
                 handle.handle,
@@ -3460,7 +3494,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tvb = std::mem::transmute(tvb);
-            bgfx_sys::bgfx_encoder_set_transient_vertex_buffer(
+            backend::bgfx_encoder_set_transient_vertex_buffer(
                 _self,
                 stream,
                 _tvb,
@@ -3492,7 +3526,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tvb = std::mem::transmute(tvb);
-            bgfx_sys::bgfx_encoder_set_transient_vertex_buffer_with_layout(
+            backend::bgfx_encoder_set_transient_vertex_buffer_with_layout(
                 _self,
                 stream,
                 _tvb,
@@ -3507,7 +3541,7 @@ impl Encoder {
     pub fn set_vertex_count(&self, num_vertices: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_vertex_count(_self, num_vertices);
+            backend::bgfx_encoder_set_vertex_count(_self, num_vertices);
         }
     }
     /// * `idb`:
@@ -3520,7 +3554,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _idb = std::mem::transmute(idb);
-            bgfx_sys::bgfx_encoder_set_instance_data_buffer(_self, _idb, start, num);
+            backend::bgfx_encoder_set_instance_data_buffer(_self, _idb, start, num);
         }
     }
     /// * `handle`:
@@ -3539,7 +3573,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_instance_data_from_vertex_buffer(
+            backend::bgfx_encoder_set_instance_data_from_vertex_buffer(
                 _self,
                 handle.handle,
                 start_vertex,
@@ -3561,7 +3595,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_instance_data_from_dynamic_vertex_buffer(
+            backend::bgfx_encoder_set_instance_data_from_dynamic_vertex_buffer(
                 _self,
                 handle.handle,
                 start_vertex,
@@ -3573,7 +3607,7 @@ impl Encoder {
     pub fn set_instance_count(&self, num_instances: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_instance_count(_self, num_instances);
+            backend::bgfx_encoder_set_instance_count(_self, num_instances);
         }
     }
     /// * `stage`:
@@ -3592,7 +3626,7 @@ impl Encoder {
     pub fn set_texture(&self, stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_texture(_self, stage, sampler.handle, handle.handle, flags);
+            backend::bgfx_encoder_set_texture(_self, stage, sampler.handle, handle.handle, flags);
         }
     }
     /// * `id`:
@@ -3600,7 +3634,7 @@ impl Encoder {
     pub fn touch(&self, id: ViewId) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_touch(_self, id);
+            backend::bgfx_encoder_touch(_self, id);
         }
     }
     /// * `id`:
@@ -3614,7 +3648,7 @@ impl Encoder {
     pub fn submit(&self, id: ViewId, program: &Program, params: SubmitArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit(_self, id, program.handle, params.depth, params.flags);
+            backend::bgfx_encoder_submit(_self, id, program.handle, params.depth, params.flags);
         }
     }
     /// * `id`:
@@ -3636,7 +3670,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit_occlusion_query(
+            backend::bgfx_encoder_submit_occlusion_query(
                 _self,
                 id,
                 program.handle,
@@ -3669,7 +3703,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit_indirect(
+            backend::bgfx_encoder_submit_indirect(
                 _self,
                 id,
                 program.handle,
@@ -3690,7 +3724,7 @@ impl Encoder {
     pub fn set_compute_index_buffer(&self, stage: u8, handle: &IndexBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_index_buffer(
+            backend::bgfx_encoder_set_compute_index_buffer(
                 _self,
                 stage,
                 handle.handle,
@@ -3707,7 +3741,7 @@ impl Encoder {
     pub fn set_compute_vertex_buffer(&self, stage: u8, handle: &VertexBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_vertex_buffer(
+            backend::bgfx_encoder_set_compute_vertex_buffer(
                 _self,
                 stage,
                 handle.handle,
@@ -3729,7 +3763,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_dynamic_index_buffer(
+            backend::bgfx_encoder_set_compute_dynamic_index_buffer(
                 _self,
                 stage,
                 handle.handle,
@@ -3751,7 +3785,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_dynamic_vertex_buffer(
+            backend::bgfx_encoder_set_compute_dynamic_vertex_buffer(
                 _self,
                 stage,
                 handle.handle,
@@ -3768,7 +3802,7 @@ impl Encoder {
     pub fn set_compute_indirect_buffer(&self, stage: u8, handle: &IndirectBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_indirect_buffer(
+            backend::bgfx_encoder_set_compute_indirect_buffer(
                 _self,
                 stage,
                 handle.handle,
@@ -3796,7 +3830,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_image(
+            backend::bgfx_encoder_set_image(
                 _self,
                 stage,
                 handle.handle,
@@ -3821,7 +3855,7 @@ impl Encoder {
     pub fn dispatch(&self, id: ViewId, program: &Program, params: DispatchArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_dispatch(
+            backend::bgfx_encoder_dispatch(
                 _self,
                 id,
                 program.handle,
@@ -3853,7 +3887,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_dispatch_indirect(
+            backend::bgfx_encoder_dispatch_indirect(
                 _self,
                 id,
                 program.handle,
@@ -3869,7 +3903,7 @@ impl Encoder {
     pub fn discard(&self, flags: u8) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_discard(_self, flags);
+            backend::bgfx_encoder_discard(_self, flags);
         }
     }
     /// * `id`:
@@ -3918,7 +3952,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_blit(
+            backend::bgfx_encoder_blit(
                 _self,
                 id,
                 dst.handle,
@@ -3944,12 +3978,12 @@ impl Encoder {
 pub fn init(init: &Init) -> bool {
     unsafe {
         let _init = std::mem::transmute(init);
-        bgfx_sys::bgfx_init(_init)
+        backend::bgfx_init(_init)
     }
 }
 pub fn shutdown() {
     unsafe {
-        bgfx_sys::bgfx_shutdown();
+        backend::bgfx_shutdown();
     }
 }
 /// * `width`:
@@ -3973,31 +4007,31 @@ pub fn shutdown() {
 /// Texture format. See: [TextureFormat].
 pub fn reset(width: u32, height: u32, params: ResetArgs) {
     unsafe {
-        bgfx_sys::bgfx_reset(width, height, params.flags, params.format as _);
+        backend::bgfx_reset(width, height, params.flags, params.format as _);
     }
 }
 /// * `capture`:
 /// Capture frame with graphics debugger.
 pub fn frame(capture: bool) -> u32 {
     unsafe {
-        bgfx_sys::bgfx_frame(capture)
+        backend::bgfx_frame(capture)
     }
 }
 pub fn get_renderer_type() -> RendererType {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_renderer_type();
+        let _ret = backend::bgfx_get_renderer_type();
         std::mem::transmute(_ret)
     }
 }
 pub fn get_caps() -> &'static Caps {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_caps();
+        let _ret = backend::bgfx_get_caps();
         std::mem::transmute(_ret)
     }
 }
 pub fn get_stats() -> &'static Stats {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_stats();
+        let _ret = backend::bgfx_get_stats();
         std::mem::transmute(_ret)
     }
 }
@@ -4013,7 +4047,7 @@ pub fn get_stats() -> &'static Stats {
 ///     primitives will be rendered as lines.
 pub fn set_debug(debug: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_debug(debug);
+        backend::bgfx_set_debug(debug);
     }
 }
 /// * `attr`:
@@ -4022,7 +4056,7 @@ pub fn set_debug(debug: u32) {
 /// Default 8x16 or 8x8 font.
 pub fn dbg_text_clear(params: DbgTextClearArgs) {
     unsafe {
-        bgfx_sys::bgfx_dbg_text_clear(params.attr, params.small);
+        backend::bgfx_dbg_text_clear(params.attr, params.small);
     }
 }
 /// * `x`:
@@ -4039,7 +4073,7 @@ pub fn dbg_text_clear(params: DbgTextClearArgs) {
 /// Image pitch in bytes.
 pub fn dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: &c_void, pitch: u16) {
     unsafe {
-        bgfx_sys::bgfx_dbg_text_image(x, y, width, height, data, pitch);
+        backend::bgfx_dbg_text_image(x, y, width, height, data, pitch);
     }
 }
 /// * `mem`:
@@ -4059,7 +4093,7 @@ pub fn dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: &c_void, pi
 ///       index buffers.
 pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags);
+        let _ret = backend::bgfx_create_index_buffer(mem.handle, flags);
         IndexBuffer { handle: _ret }
     }
 }
@@ -4068,7 +4102,7 @@ pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
 pub fn create_vertex_layout(layout: &VertexLayoutBuilder) -> VertexLayout {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_vertex_layout(_layout);
+        let _ret = backend::bgfx_create_vertex_layout(_layout);
         VertexLayout { handle: _ret }
     }
 }
@@ -4094,7 +4128,7 @@ pub fn create_vertex_buffer(
 ) -> VertexBuffer {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
+        let _ret = backend::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
         VertexBuffer { handle: _ret }
     }
 }
@@ -4115,7 +4149,7 @@ pub fn create_vertex_buffer(
 ///       index buffers.
 pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags);
+        let _ret = backend::bgfx_create_dynamic_index_buffer(num, flags);
         DynamicIndexBuffer { handle: _ret }
     }
 }
@@ -4136,7 +4170,7 @@ pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
 ///       index buffers.
 pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
+        let _ret = backend::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
         DynamicIndexBuffer { handle: _ret }
     }
 }
@@ -4148,7 +4182,7 @@ pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndex
 /// Index buffer data.
 pub fn update_dynamic_index_buffer(handle: &DynamicIndexBuffer, start_index: u32, mem: &Memory) {
     unsafe {
-        bgfx_sys::bgfx_update_dynamic_index_buffer(handle.handle, start_index, mem.handle);
+        backend::bgfx_update_dynamic_index_buffer(handle.handle, start_index, mem.handle);
     }
 }
 /// * `num`:
@@ -4175,7 +4209,7 @@ pub fn create_dynamic_vertex_buffer(
 ) -> DynamicVertexBuffer {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
+        let _ret = backend::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
         DynamicVertexBuffer { handle: _ret }
     }
 }
@@ -4203,7 +4237,7 @@ pub fn create_dynamic_vertex_buffer_mem(
 ) -> DynamicVertexBuffer {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
+        let _ret = backend::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
         DynamicVertexBuffer { handle: _ret }
     }
 }
@@ -4215,7 +4249,7 @@ pub fn create_dynamic_vertex_buffer_mem(
 /// Vertex buffer data.
 pub fn update_dynamic_vertex_buffer(handle: &DynamicVertexBuffer, start_vertex: u32, mem: &Memory) {
     unsafe {
-        bgfx_sys::bgfx_update_dynamic_vertex_buffer(handle.handle, start_vertex, mem.handle);
+        backend::bgfx_update_dynamic_vertex_buffer(handle.handle, start_vertex, mem.handle);
     }
 }
 /// * `num`:
@@ -4224,7 +4258,7 @@ pub fn update_dynamic_vertex_buffer(handle: &DynamicVertexBuffer, start_vertex:
 /// Set to `true` if input indices will be 32-bit.
 pub fn get_avail_transient_index_buffer(num: u32, index_32: bool) -> u32 {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_avail_transient_index_buffer(num, index_32);
+        let _ret = backend::bgfx_get_avail_transient_index_buffer(num, index_32);
         _ret
     }
 }
@@ -4235,7 +4269,7 @@ pub fn get_avail_transient_index_buffer(num: u32, index_32: bool) -> u32 {
 pub fn get_avail_transient_vertex_buffer(num: u32, layout: &VertexLayoutBuilder) -> u32 {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_get_avail_transient_vertex_buffer(num, _layout);
+        let _ret = backend::bgfx_get_avail_transient_vertex_buffer(num, _layout);
         _ret
     }
 }
@@ -4245,7 +4279,7 @@ pub fn get_avail_transient_vertex_buffer(num: u32, layout: &VertexLayoutBuilder)
 /// Stride per instance.
 pub fn get_avail_instance_data_buffer(num: u32, stride: u16) -> u32 {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_avail_instance_data_buffer(num, stride);
+        let _ret = backend::bgfx_get_avail_instance_data_buffer(num, stride);
         _ret
     }
 }
@@ -4260,7 +4294,7 @@ pub fn get_avail_instance_data_buffer(num: u32, stride: u16) -> u32 {
 pub fn alloc_transient_index_buffer(tib: &mut TransientIndexBuffer, num: u32, index_32: bool) {
     unsafe {
         let _tib = std::mem::transmute(tib);
-        bgfx_sys::bgfx_alloc_transient_index_buffer(_tib, num, index_32);
+        backend::bgfx_alloc_transient_index_buffer(_tib, num, index_32);
     }
 }
 /// * `tvb`:
@@ -4279,7 +4313,7 @@ pub fn alloc_transient_vertex_buffer(
     unsafe {
         let _tvb = std::mem::transmute(tvb);
         let _layout = std::mem::transmute(layout);
-        bgfx_sys::bgfx_alloc_transient_vertex_buffer(_tvb, num, _layout);
+        backend::bgfx_alloc_transient_vertex_buffer(_tvb, num, _layout);
     }
 }
 /// * `tvb`:
@@ -4310,7 +4344,7 @@ pub fn alloc_transient_buffers(
         let _tvb = std::mem::transmute(tvb);
         let _layout = std::mem::transmute(layout);
         let _tib = std::mem::transmute(tib);
-        let _ret = bgfx_sys::bgfx_alloc_transient_buffers(
+        let _ret = backend::bgfx_alloc_transient_buffers(
             _tvb,
             _layout,
             num_vertices,
@@ -4332,14 +4366,14 @@ pub fn alloc_transient_buffers(
 pub fn alloc_instance_data_buffer(idb: &mut InstanceDataBuffer, num: u32, stride: u16) {
     unsafe {
         let _idb = std::mem::transmute(idb);
-        bgfx_sys::bgfx_alloc_instance_data_buffer(_idb, num, stride);
+        backend::bgfx_alloc_instance_data_buffer(_idb, num, stride);
     }
 }
 /// * `num`:
 /// Number of indirect calls.
 pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_indirect_buffer(num);
+        let _ret = backend::bgfx_create_indirect_buffer(num);
         IndirectBuffer { handle: _ret }
     }
 }
@@ -4347,7 +4381,7 @@ pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
 /// Shader binary.
 pub fn create_shader(mem: &Memory) -> Shader {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_shader(mem.handle);
+        let _ret = backend::bgfx_create_shader(mem.handle);
         Shader { handle: _ret }
     }
 }
@@ -4359,7 +4393,7 @@ pub fn create_shader(mem: &Memory) -> Shader {
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
+        let _ret = backend::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
         Program { handle: _ret }
     }
 }
@@ -4369,7 +4403,7 @@ pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Prog
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_compute_program(csh.handle, destroy_shaders);
+        let _ret = backend::bgfx_create_compute_program(csh.handle, destroy_shaders);
         Program { handle: _ret }
     }
 }
@@ -4391,7 +4425,7 @@ pub fn is_texture_valid(
     flags: u64,
 ) -> bool {
     unsafe {
-        let _ret = bgfx_sys::bgfx_is_texture_valid(depth, cube_map, num_layers, format as _, flags);
+        let _ret = backend::bgfx_is_texture_valid(depth, cube_map, num_layers, format as _, flags);
         _ret
     }
 }
@@ -4402,7 +4436,7 @@ pub fn is_texture_valid(
 pub fn is_frame_buffer_valid(num: u8, attachment: &Attachment) -> bool {
     unsafe {
         let _attachment = std::mem::transmute(attachment);
-        let _ret = bgfx_sys::bgfx_is_frame_buffer_valid(num, _attachment);
+        let _ret = backend::bgfx_is_frame_buffer_valid(num, _attachment);
         _ret
     }
 }
@@ -4434,7 +4468,7 @@ pub fn calc_texture_size(
 ) {
     unsafe {
         let _info = std::mem::transmute(info);
-        bgfx_sys::bgfx_calc_texture_size(
+        backend::bgfx_calc_texture_size(
             _info,
             width,
             height,
@@ -4462,7 +4496,7 @@ pub fn calc_texture_size(
 pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
     unsafe {
         let _info = std::mem::transmute(info);
-        let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags, skip, _info);
+        let _ret = backend::bgfx_create_texture(mem.handle, flags, skip, _info);
         Texture { handle: _ret }
     }
 }
@@ -4498,7 +4532,7 @@ pub fn create_texture_2d(
     mem: &Memory,
 ) -> Texture {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_texture_2d(
+        let _ret = backend::bgfx_create_texture_2d(
             width,
             height,
             has_mips,
@@ -4534,7 +4568,7 @@ pub fn create_texture_2d_scaled(
     flags: u64,
 ) -> Texture {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
+        let _ret = backend::bgfx_create_texture_2d_scaled(
             ratio as _,
             has_mips,
             num_layers,
@@ -4579,7 +4613,7 @@ pub fn create_texture_3d(
         } else {
             std::ptr::null()
         };
-        let _ret = bgfx_sys::bgfx_create_texture_3d(
+        let _ret = backend::bgfx_create_texture_3d(
             width,
             height,
             depth,
@@ -4624,7 +4658,7 @@ pub fn create_texture_cube(
         } else {
             std::ptr::null()
         };
-        let _ret = bgfx_sys::bgfx_create_texture_cube(
+        let _ret = backend::bgfx_create_texture_cube(
             size,
             has_mips,
             num_layers,
@@ -4666,7 +4700,7 @@ pub fn update_texture_2d(
     pitch: u16,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_2d(
+        backend::bgfx_update_texture_2d(
             handle.handle,
             layer,
             mip,
@@ -4709,7 +4743,7 @@ pub fn update_texture_3d(
     mem: &Memory,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_3d(
+        backend::bgfx_update_texture_3d(
             handle.handle,
             mip,
             x,
@@ -4774,7 +4808,7 @@ pub fn update_texture_cube(
     pitch: u16,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_cube(
+        backend::bgfx_update_texture_cube(
             handle.handle,
             layer,
             side,
@@ -4808,7 +4842,7 @@ pub fn create_frame_buffer(
     texture_flags: u64,
 ) -> FrameBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
+        let _ret = backend::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
         FrameBuffer { handle: _ret }
     }
 }
@@ -4831,7 +4865,7 @@ pub fn create_frame_buffer_scaled(
 ) -> FrameBuffer {
     unsafe {
         let _ret =
-            bgfx_sys::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
+            backend::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
         FrameBuffer { handle: _ret }
     }
 }
@@ -4849,7 +4883,7 @@ pub fn create_frame_buffer_from_handles(
 ) -> FrameBuffer {
     unsafe {
         let _ret =
-            bgfx_sys::bgfx_create_frame_buffer_from_handles(num, &handles.handle, destroy_texture);
+            backend::bgfx_create_frame_buffer_from_handles(num, &handles.handle, destroy_texture);
         FrameBuffer { handle: _ret }
     }
 }
@@ -4868,7 +4902,7 @@ pub fn create_frame_buffer_from_attachment(
     unsafe {
         let _attachment = std::mem::transmute(attachment);
         let _ret =
-            bgfx_sys::bgfx_create_frame_buffer_from_attachment(num, _attachment, destroy_texture);
+            backend::bgfx_create_frame_buffer_from_attachment(num, _attachment, destroy_texture);
         FrameBuffer { handle: _ret }
     }
 }
@@ -4889,7 +4923,7 @@ pub fn create_frame_buffer_from_nwh(
     params: CreateFrameBufferFromNwhArgs,
 ) -> FrameBuffer {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
+        let _ret = backend::bgfx_create_frame_buffer_from_nwh(
             nwh,
             width,
             height,
@@ -4904,7 +4938,7 @@ pub fn create_frame_buffer_from_nwh(
 /// * `attachment`:
 pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_texture(handle.handle, attachment);
+        let _ret = backend::bgfx_get_texture(handle.handle, attachment);
         Texture { handle: _ret }
     }
 }
@@ -4915,12 +4949,12 @@ pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
 pub fn get_uniform_info(handle: &Uniform, info: &mut UniformInfo) {
     unsafe {
         let _info = std::mem::transmute(info);
-        bgfx_sys::bgfx_get_uniform_info(handle.handle, _info);
+        backend::bgfx_get_uniform_info(handle.handle, _info);
     }
 }
 pub fn create_occlusion_query() -> OcclusionQuery {
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_occlusion_query();
+        let _ret = backend::bgfx_create_occlusion_query();
         OcclusionQuery { handle: _ret }
     }
 }
@@ -4931,7 +4965,7 @@ pub fn create_occlusion_query() -> OcclusionQuery {
 /// can be `NULL` if result of occlusion query is not needed.
 pub fn get_result(handle: &OcclusionQuery, result: &mut i32) -> OcclusionQueryResult {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_result(handle.handle, result);
+        let _ret = backend::bgfx_get_result(handle.handle, result);
         std::mem::transmute(_ret)
     }
 }
@@ -4947,7 +4981,7 @@ pub fn get_result(handle: &OcclusionQuery, result: &mut i32) -> OcclusionQueryRe
 /// Height of view port region.
 pub fn set_view_rect(id: ViewId, x: u16, y: u16, width: u16, height: u16) {
     unsafe {
-        bgfx_sys::bgfx_set_view_rect(id, x, y, width, height);
+        backend::bgfx_set_view_rect(id, x, y, width, height);
     }
 }
 /// * `id`:
@@ -4961,7 +4995,7 @@ pub fn set_view_rect(id: ViewId, x: u16, y: u16, width: u16, height: u16) {
 /// See: [BackbufferRatio].
 pub fn set_view_rect_ratio(id: ViewId, x: u16, y: u16, ratio: BackbufferRatio) {
     unsafe {
-        bgfx_sys::bgfx_set_view_rect_ratio(id, x, y, ratio as _);
+        backend::bgfx_set_view_rect_ratio(id, x, y, ratio as _);
     }
 }
 /// * `id`:
@@ -4976,7 +5010,7 @@ pub fn set_view_rect_ratio(id: ViewId, x: u16, y: u16, ratio: BackbufferRatio) {
 /// Height of view scissor region.
 pub fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) {
     unsafe {
-        bgfx_sys::bgfx_set_view_scissor(id, params.x, params.y, params.width, params.height);
+        backend::bgfx_set_view_scissor(id, params.x, params.y, params.width, params.height);
     }
 }
 /// * `id`:
@@ -4992,7 +5026,7 @@ pub fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) {
 /// Stencil clear value.
 pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
     unsafe {
-        bgfx_sys::bgfx_set_view_clear(id, flags, params.rgba, params.depth, params.stencil);
+        backend::bgfx_set_view_clear(id, flags, params.rgba, params.depth, params.stencil);
     }
 }
 /// * `id`:
@@ -5028,7 +5062,7 @@ pub fn set_view_clear_mrt(
     params: SetViewClearMrtArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_view_clear_mrt(
+        backend::bgfx_set_view_clear_mrt(
             id, flags, depth, stencil, params.c_0, params.c_1, params.c_2, params.c_3, params.c_4,
             params.c_5, params.c_6, params.c_7,
         );
@@ -5040,7 +5074,7 @@ pub fn set_view_clear_mrt(
 /// View sort mode. See [ViewMode].
 pub fn set_view_mode(id: ViewId, mode: ViewMode) {
     unsafe {
-        bgfx_sys::bgfx_set_view_mode(id, mode as _);
+        backend::bgfx_set_view_mode(id, mode as _);
     }
 }
 /// * `id`:
@@ -5051,20 +5085,20 @@ pub fn set_view_mode(id: ViewId, mode: ViewMode) {
 /// default back buffer.
 pub fn set_view_frame_buffer(id: ViewId, handle: &FrameBuffer) {
     unsafe {
-        bgfx_sys::bgfx_set_view_frame_buffer(id, handle.handle);
+        backend::bgfx_set_view_frame_buffer(id, handle.handle);
     }
 }
 /// * `id`:
 pub fn reset_view(id: ViewId) {
     unsafe {
-        bgfx_sys::bgfx_reset_view(id);
+        backend::bgfx_reset_view(id);
     }
 }
 /// * `for_thread`:
 /// Explicitly request an encoder for a worker thread.
 pub fn encoder_begin(for_thread: bool) -> &'static Encoder {
     unsafe {
-        let _ret = bgfx_sys::bgfx_encoder_begin(for_thread);
+        let _ret = backend::bgfx_encoder_begin(for_thread);
         std::mem::transmute(_ret)
     }
 }
@@ -5073,7 +5107,7 @@ pub fn encoder_begin(for_thread: bool) -> &'static Encoder {
 pub fn encoder_end(encoder: &Encoder) {
     unsafe {
         let _encoder = std::mem::transmute(encoder);
-        bgfx_sys::bgfx_encoder_end(_encoder);
+        backend::bgfx_encoder_end(_encoder);
     }
 }
 /// * `handle`:
@@ -5083,14 +5117,14 @@ pub fn encoder_end(encoder: &Encoder) {
 /// Will be passed to `bgfx::CallbackI::screenShot` callback.
 pub fn request_screen_shot(handle: &FrameBuffer, file_path: &i8) {
     unsafe {
-        bgfx_sys::bgfx_request_screen_shot(handle.handle, file_path);
+        backend::bgfx_request_screen_shot(handle.handle, file_path);
     }
 }
 /// * `msecs`:
 /// Timeout in milliseconds.
 pub fn render_frame(msecs: i32) -> RenderFrame {
     unsafe {
-        let _ret = bgfx_sys::bgfx_render_frame(msecs);
+        let _ret = backend::bgfx_render_frame(msecs);
         std::mem::transmute(_ret)
     }
 }
@@ -5099,12 +5133,12 @@ pub fn render_frame(msecs: i32) -> RenderFrame {
 pub fn set_platform_data(data: &PlatformData) {
     unsafe {
         let _data = std::mem::transmute(data);
-        bgfx_sys::bgfx_set_platform_data(_data);
+        backend::bgfx_set_platform_data(_data);
     }
 }
 pub fn get_internal_data() -> &'static InternalData {
     unsafe {
-        let _ret = bgfx_sys::bgfx_get_internal_data();
+        let _ret = backend::bgfx_get_internal_data();
         std::mem::transmute(_ret)
     }
 }
@@ -5112,7 +5146,7 @@ pub fn get_internal_data() -> &'static InternalData {
 /// Marker string.
 pub fn set_marker(marker: &i8) {
     unsafe {
-        bgfx_sys::bgfx_set_marker(marker);
+        backend::bgfx_set_marker(marker);
     }
 }
 /// * `state`:
@@ -5130,7 +5164,7 @@ pub fn set_marker(marker: &i8) {
 ///   [StateBlendFlags::INV_FACTOR] blend modes.
 pub fn set_state(state: u64, rgba: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_state(state, rgba);
+        backend::bgfx_set_state(state, rgba);
     }
 }
 /// * `handle`:
@@ -5139,7 +5173,7 @@ pub fn set_state(state: u64, rgba: u32) {
 /// Render if occlusion query is visible.
 pub fn set_condition(handle: &OcclusionQuery, visible: bool) {
     unsafe {
-        bgfx_sys::bgfx_set_condition(handle.handle, visible);
+        backend::bgfx_set_condition(handle.handle, visible);
     }
 }
 /// * `fstencil`:
@@ -5149,7 +5183,7 @@ pub fn set_condition(handle: &OcclusionQuery, visible: bool) {
 /// _fstencil is applied to both front and back facing primitives.
 pub fn set_stencil(fstencil: u32, bstencil: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_stencil(fstencil, bstencil);
+        backend::bgfx_set_stencil(fstencil, bstencil);
     }
 }
 /// * `x`:
@@ -5162,7 +5196,7 @@ pub fn set_stencil(fstencil: u32, bstencil: u32) {
 /// Height of view scissor region.
 pub fn set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16 {
     unsafe {
-        let _ret = bgfx_sys::bgfx_set_scissor(x, y, width, height);
+        let _ret = backend::bgfx_set_scissor(x, y, width, height);
         _ret
     }
 }
@@ -5170,7 +5204,7 @@ pub fn set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16 {
 /// Index in scissor cache.
 pub fn set_scissor_cached(cache: u16) {
     unsafe {
-        bgfx_sys::bgfx_set_scissor_cached(cache);
+        backend::bgfx_set_scissor_cached(cache);
     }
 }
 /// * `transform`:
@@ -5180,7 +5214,7 @@ pub fn set_scissor_cached(cache: u16) {
 pub fn alloc_transform(transform: &mut Transform, num: u16) -> u32 {
     unsafe {
         let _transform = std::mem::transmute(transform);
-        let _ret = bgfx_sys::bgfx_alloc_transform(_transform, num);
+        let _ret = backend::bgfx_alloc_transform(_transform, num);
         _ret
     }
 }
@@ -5192,7 +5226,7 @@ pub fn alloc_transform(transform: &mut Transform, num: u16) -> u32 {
 /// Number of indices to render.
 pub fn set_index_buffer(handle: &IndexBuffer, first_index: u32, num_indices: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_index_buffer(handle.handle, first_index, num_indices);
+        backend::bgfx_set_index_buffer(handle.handle, first_index, num_indices);
     }
 }
 /// * `handle`:
@@ -5203,7 +5237,7 @@ pub fn set_index_buffer(handle: &IndexBuffer, first_index: u32, num_indices: u32
 /// Number of indices to render.
 pub fn set_dynamic_index_buffer(handle: &DynamicIndexBuffer, first_index: u32, num_indices: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_dynamic_index_buffer(handle.handle, first_index, num_indices);
+        backend::bgfx_set_dynamic_index_buffer(handle.handle, first_index, num_indices);
     }
 }
 /// * `tib`:
@@ -5215,7 +5249,7 @@ pub fn set_dynamic_index_buffer(handle: &DynamicIndexBuffer, first_index: u32, n
 pub fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32, num_indices: u32) {
     unsafe {
         let _tib = std::mem::transmute(tib);
-        bgfx_sys::bgfx_set_transient_index_buffer(_tib, first_index, num_indices);
+        backend::bgfx_set_transient_index_buffer(_tib, first_index, num_indices);
     }
 }
 /// * `stream`:
@@ -5228,7 +5262,7 @@ pub fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32,
 /// Number of vertices to render.
 pub fn set_vertex_buffer(stream: u8, handle: &VertexBuffer, start_vertex: u32, num_vertices: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_vertex_buffer(stream, handle.handle, start_vertex, num_vertices);
+        backend::bgfx_set_vertex_buffer(stream, handle.handle, start_vertex, num_vertices);
     }
 }
 /// * `stream`:
@@ -5251,7 +5285,7 @@ pub fn set_vertex_buffer_with_layout(
     layout_handle: &VertexLayout,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_vertex_buffer_with_layout(
+        backend::bgfx_set_vertex_buffer_with_layout(
             stream,
             handle.handle,
             start_vertex,
@@ -5275,7 +5309,7 @@ pub fn set_dynamic_vertex_buffer(
     num_vertices: u32,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_dynamic_vertex_buffer(stream, handle.handle, start_vertex, num_vertices);
+        backend::bgfx_set_dynamic_vertex_buffer(stream, handle.handle, start_vertex, num_vertices);
     }
 }
 /// * `stream`:
@@ -5298,7 +5332,7 @@ pub fn set_dynamic_vertex_buffer_with_layout(
     layout_handle: &VertexLayout,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_dynamic_vertex_buffer_with_layout(
+        backend::bgfx_set_dynamic_vertex_buffer_with_layout(
             stream,
             handle.handle,
             start_vertex,
@@ -5323,7 +5357,7 @@ pub fn set_transient_vertex_buffer(
 ) {
     unsafe {
         let _tvb = std::mem::transmute(tvb);
-        bgfx_sys::bgfx_set_transient_vertex_buffer(stream, _tvb, start_vertex, num_vertices);
+        backend::bgfx_set_transient_vertex_buffer(stream, _tvb, start_vertex, num_vertices);
     }
 }
 /// * `stream`:
@@ -5347,7 +5381,7 @@ pub fn set_transient_vertex_buffer_with_layout(
 ) {
     unsafe {
         let _tvb = std::mem::transmute(tvb);
-        bgfx_sys::bgfx_set_transient_vertex_buffer_with_layout(
+        backend::bgfx_set_transient_vertex_buffer_with_layout(
             stream,
             _tvb,
             start_vertex,
@@ -5360,7 +5394,7 @@ pub fn set_transient_vertex_buffer_with_layout(
 /// Number of vertices.
 pub fn set_vertex_count(num_vertices: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_vertex_count(num_vertices);
+        backend::bgfx_set_vertex_count(num_vertices);
     }
 }
 /// * `idb`:
@@ -5372,7 +5406,7 @@ pub fn set_vertex_count(num_vertices: u32) {
 pub fn set_instance_data_buffer(idb: &InstanceDataBuffer, start: u32, num: u32) {
     unsafe {
         let _idb = std::mem::transmute(idb);
-        bgfx_sys::bgfx_set_instance_data_buffer(_idb, start, num);
+        backend::bgfx_set_instance_data_buffer(_idb, start, num);
     }
 }
 /// * `handle`:
@@ -5385,7 +5419,7 @@ pub fn set_instance_data_buffer(idb: &InstanceDataBuffer, start: u32, num: u32)
 /// Dynamic vertex buffer.
 pub fn set_instance_data_from_vertex_buffer(handle: &VertexBuffer, start_vertex: u32, num: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_instance_data_from_vertex_buffer(handle.handle, start_vertex, num);
+        backend::bgfx_set_instance_data_from_vertex_buffer(handle.handle, start_vertex, num);
     }
 }
 /// * `handle`:
@@ -5400,7 +5434,7 @@ pub fn set_instance_data_from_dynamic_vertex_buffer(
     num: u32,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_instance_data_from_dynamic_vertex_buffer(
+        backend::bgfx_set_instance_data_from_dynamic_vertex_buffer(
             handle.handle,
             start_vertex,
             num,
@@ -5410,7 +5444,7 @@ pub fn set_instance_data_from_dynamic_vertex_buffer(
 /// * `num_instances`:
 pub fn set_instance_count(num_instances: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_instance_count(num_instances);
+        backend::bgfx_set_instance_count(num_instances);
     }
 }
 /// * `stage`:
@@ -5428,14 +5462,14 @@ pub fn set_instance_count(num_instances: u32) {
 ///     sampling.
 pub fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_texture(stage, sampler.handle, handle.handle, flags);
+        backend::bgfx_set_texture(stage, sampler.handle, handle.handle, flags);
     }
 }
 /// * `id`:
 /// View id.
 pub fn touch(id: ViewId) {
     unsafe {
-        bgfx_sys::bgfx_touch(id);
+        backend::bgfx_touch(id);
     }
 }
 /// * `id`:
@@ -5448,7 +5482,7 @@ pub fn touch(id: ViewId) {
 /// Which states to discard for next draw. See [DiscardFlags].
 pub fn submit(id: ViewId, program: &Program, params: SubmitArgs) {
     unsafe {
-        bgfx_sys::bgfx_submit(id, program.handle, params.depth, params.flags);
+        backend::bgfx_submit(id, program.handle, params.depth, params.flags);
     }
 }
 /// * `id`:
@@ -5468,7 +5502,7 @@ pub fn submit_occlusion_query(
     params: SubmitOcclusionQueryArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_submit_occlusion_query(
+        backend::bgfx_submit_occlusion_query(
             id,
             program.handle,
             occlusion_query.handle,
@@ -5498,7 +5532,7 @@ pub fn submit_indirect(
     params: SubmitIndirectArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_submit_indirect(
+        backend::bgfx_submit_indirect(
             id,
             program.handle,
             indirect_handle.handle,
@@ -5517,7 +5551,7 @@ pub fn submit_indirect(
 /// Buffer access. See [Access].
 pub fn set_compute_index_buffer(stage: u8, handle: &IndexBuffer, access: Access) {
     unsafe {
-        bgfx_sys::bgfx_set_compute_index_buffer(stage, handle.handle, access as _);
+        backend::bgfx_set_compute_index_buffer(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5528,7 +5562,7 @@ pub fn set_compute_index_buffer(stage: u8, handle: &IndexBuffer, access: Access)
 /// Buffer access. See [Access].
 pub fn set_compute_vertex_buffer(stage: u8, handle: &VertexBuffer, access: Access) {
     unsafe {
-        bgfx_sys::bgfx_set_compute_vertex_buffer(stage, handle.handle, access as _);
+        backend::bgfx_set_compute_vertex_buffer(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5539,7 +5573,7 @@ pub fn set_compute_vertex_buffer(stage: u8, handle: &VertexBuffer, access: Acces
 /// Buffer access. See [Access].
 pub fn set_compute_dynamic_index_buffer(stage: u8, handle: &DynamicIndexBuffer, access: Access) {
     unsafe {
-        bgfx_sys::bgfx_set_compute_dynamic_index_buffer(stage, handle.handle, access as _);
+        backend::bgfx_set_compute_dynamic_index_buffer(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5550,7 +5584,7 @@ pub fn set_compute_dynamic_index_buffer(stage: u8, handle: &DynamicIndexBuffer,
 /// Buffer access. See [Access].
 pub fn set_compute_dynamic_vertex_buffer(stage: u8, handle: &DynamicVertexBuffer, access: Access) {
     unsafe {
-        bgfx_sys::bgfx_set_compute_dynamic_vertex_buffer(stage, handle.handle, access as _);
+        backend::bgfx_set_compute_dynamic_vertex_buffer(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5561,7 +5595,7 @@ pub fn set_compute_dynamic_vertex_buffer(stage: u8, handle: &DynamicVertexBuffer
 /// Buffer access. See [Access].
 pub fn set_compute_indirect_buffer(stage: u8, handle: &IndirectBuffer, access: Access) {
     unsafe {
-        bgfx_sys::bgfx_set_compute_indirect_buffer(stage, handle.handle, access as _);
+        backend::bgfx_set_compute_indirect_buffer(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5576,7 +5610,7 @@ pub fn set_compute_indirect_buffer(stage: u8, handle: &IndirectBuffer, access: A
 /// Texture format. See: [TextureFormat].
 pub fn set_image(stage: u8, handle: &Texture, mip: u8, access: Access, format: TextureFormat) {
     unsafe {
-        bgfx_sys::bgfx_set_image(stage, handle.handle, mip, access as _, format as _);
+        backend::bgfx_set_image(stage, handle.handle, mip, access as _, format as _);
     }
 }
 /// * `id`:
@@ -5593,7 +5627,7 @@ pub fn set_image(stage: u8, handle: &Texture, mip: u8, access: Access, format: T
 /// Discard or preserve states. See [DiscardFlags].
 pub fn dispatch(id: ViewId, program: &Program, params: DispatchArgs) {
     unsafe {
-        bgfx_sys::bgfx_dispatch(
+        backend::bgfx_dispatch(
             id,
             program.handle,
             params.num_x,
@@ -5622,7 +5656,7 @@ pub fn dispatch_indirect(
     params: DispatchIndirectArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_dispatch_indirect(
+        backend::bgfx_dispatch_indirect(
             id,
             program.handle,
             indirect_handle.handle,
@@ -5636,7 +5670,7 @@ pub fn dispatch_indirect(
 /// Draw/compute states to discard.
 pub fn discard(flags: u8) {
     unsafe {
-        bgfx_sys::bgfx_discard(flags);
+        backend::bgfx_discard(flags);
     }
 }
 /// * `id`:
@@ -5683,7 +5717,7 @@ pub fn blit(
     params: BlitArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_blit(
+        backend::bgfx_blit(
             id,
             dst.handle,
             dst_mip,
@@ -5701,147 +5735,3 @@ pub fn blit(
         );
     }
 }
-
-pub type ViewId = u16;
-
-/// Returns the number of uniforms and uniform handles used inside a shader.
-///
-/// Notice that only non-predefined uniforms are returned.
-
-impl Shader {
-    //pub fn get_uniforms(&self, uniforms: &mut [Uniform]) -> u16 {
-    //	unsafe { bgfx_sys::bgfx_get_shader_uniforms(self.handle, uniforms.as_ptr(), uniforms.len() as u16) }
-    //}
-}
-
-/// bgfx-managed buffer of memory.
-///
-/// It can be created by either copying existing data through [`copy(...)`], or by referencing
-/// existing memory directly through [`reference(...)`].
-///
-/// [`copy(...)`]: #method.copy
-/// [`reference(...)`]: #method.reference
-#[derive(Copy, Clone)]
-pub struct Memory {
-    handle: *const bgfx_sys::bgfx_memory_t,
-}
-
-impl Memory {
-    /// Copies the source data into a new bgfx-managed buffer.
-    ///
-    /// **IMPORTANT:** If this buffer is never passed into a bgfx call, the memory will never be
-    /// freed, and will leak.
-    #[inline]
-    pub fn copy<T>(data: &[T]) -> Memory {
-        unsafe {
-            let handle = bgfx_sys::bgfx_copy(
-                data.as_ptr() as *const c_void,
-                std::mem::size_of_val(data) as u32,
-            );
-            Memory { handle }
-        }
-    }
-
-    /// Creates a reference to the source data for passing into bgfx. When using this constructor
-    /// over the `copy` call, no copy will be created. bgfx will read the source memory directly.
-    ///
-    /// *Note* That the data passed to this function must be keep alive during the whole duration
-    /// of the program and is only really recommended for static data unless you know you know
-    /// what you are doing. Thus this function is marked as unsafe because of this reason.
-    #[inline]
-    pub fn reference<T>(data: &[T]) -> Memory {
-        unsafe {
-            let handle = bgfx_sys::bgfx_make_ref(
-                data.as_ptr() as *const c_void,
-                std::mem::size_of_val(data) as u32,
-            );
-            Memory { handle }
-        }
-    }
-}
-
-/// * `x`:
-/// Position x from the left corner of the window.
-/// * `y`:
-/// Position y from the top corner of the window.
-/// * `attr`:
-/// Color palette. Where top 4-bits represent index of background, and bottom
-/// 4-bits represent foreground color from standard VGA text palette (ANSI escape codes).
-/// * `text`: Text to be displayed
-pub fn dbg_text(x: u16, y: u16, attr: u8, text: &str) {
-    unsafe {
-        let c_text = std::ffi::CString::new(text).unwrap();
-        bgfx_sys::bgfx_dbg_text_printf(x, y, attr, c_text.as_ptr());
-    }
-}
-
-/// * `id`:
-/// View id.
-/// * `view`:
-/// View matrix.
-/// * `proj`:
-/// Projection matrix.
-pub fn set_view_transform(id: ViewId, view: &[f32; 16], proj: &[f32; 16]) {
-    unsafe {
-        let _view = std::mem::transmute(view);
-        let _proj = std::mem::transmute(proj);
-        bgfx_sys::bgfx_set_view_transform(id, _view, _proj);
-    }
-}
-
-/// * `mtx`:
-/// Pointer to first matrix in array.
-/// * `num`:
-/// Number of matrices in array.
-pub fn set_transform(mtx: &[f32; 16], num: u16) -> u32 {
-    unsafe {
-        let _mtx = std::mem::transmute(mtx);
-        bgfx_sys::bgfx_set_transform(_mtx, num)
-    }
-}
-
-impl Encoder {
-    /// * `handle`: Uniform.
-    /// * `value`: Pointer to uniform data.
-    /// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
-    pub fn set_uniform(&self, handle: &Uniform, value: &[f32], num: u16) {
-        unsafe {
-            let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_uniform(_self, handle.handle, value.as_ptr() as _, num);
-        }
-    }
-}
-
-impl Uniform {
-    /// * `name`:
-    /// Uniform name in shader.
-    /// * `type_r`:
-    /// Type of uniform (See: `bgfx::UniformType`).
-    /// * `num`:
-    /// Number of elements in array.
-    pub fn create(name: &str, type_r: UniformType, num: u16) -> Uniform {
-        unsafe {
-            let name_ = CFixedString::from_str(name);
-            let _ret = bgfx_sys::bgfx_create_uniform(name_.as_ptr() as _, type_r as _, num);
-            Uniform { handle: _ret }
-        }
-    }
-
-    /// * `handle`: Uniform.
-    /// * `value`: uniform data.
-    /// * `num`: Number of elements. Passing `u16::MAX` will use the num passed on uniform creation.
-    pub fn set(&self, value: &[f32], num: u16) {
-        unsafe {
-            bgfx_sys::bgfx_set_uniform(self.handle, value.as_ptr() as _, num);
-        }
-    }
-}
-
-/// * `handle`: Uniform.
-/// * `value`: Pointer to uniform data.
-/// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
-pub fn set_uniform(handle: &Uniform, value: &[f32], num: u16) {
-    unsafe {
-        bgfx_sys::bgfx_set_uniform(handle.handle, value.as_ptr() as _, num);
-    }
-}
@@ -0,0 +1,11 @@
+/// * `x`:
+/// Position x from the left corner of the window.
+/// * `y`:
+/// Position y from the top corner of the window.
+/// * `attr`:
+/// Color palette. Where top 4-bits represent index of background, and bottom
+/// 4-bits represent foreground color from standard VGA text palette (ANSI escape codes).
+/// * `text`: Text to be displayed
+pub fn dbg_text(x: u16, y: u16, attr: u8, text: &str) {
+    crate::debug_text::dbg_text_fmt(x, y, attr, format_args!("{}", text));
+}
@@ -0,0 +1,56 @@
+use core::ffi::c_void;
+use std::mem::MaybeUninit;
+
+use crate::backend;
+
+/// bgfx-managed buffer of memory.
+///
+/// It can be created by either copying existing data through [`copy(...)`], or by referencing
+/// existing memory directly through [`reference(...)`].
+///
+/// [`copy(...)`]: #method.copy
+/// [`reference(...)`]: #method.reference
+#[derive(Copy, Clone)]
+pub struct Memory {
+    pub(super) handle: *const bgfx_sys::bgfx_memory_t,
+}
+
+impl Memory {
+    pub fn new() -> Memory {
+        let t = MaybeUninit::<Memory>::zeroed();
+        let t = unsafe { t.assume_init() };
+        t
+    }
+
+    /// Copies the source data into a new bgfx-managed buffer.
+    ///
+    /// **IMPORTANT:** If this buffer is never passed into a bgfx call, the memory will never be
+    /// freed, and will leak.
+    #[inline]
+    pub fn copy<T>(data: &[T]) -> Memory {
+        unsafe {
+            let handle = backend::bgfx_copy(
+                data.as_ptr() as *const c_void,
+                std::mem::size_of_val(data) as u32,
+            );
+            Memory { handle }
+        }
+    }
+
+    /// Creates a reference to the source data for passing into bgfx. When using this constructor
+    /// over the `copy` call, no copy will be created. bgfx will read the source memory directly.
+    ///
+    /// *Note* That the data passed to this function must be keep alive during the whole duration
+    /// of the program and is only really recommended for static data unless you know you know
+    /// what you are doing. Thus this function is marked as unsafe because of this reason.
+    #[inline]
+    pub fn reference<T>(data: &[T]) -> Memory {
+        unsafe {
+            let handle = backend::bgfx_make_ref(
+                data.as_ptr() as *const c_void,
+                std::mem::size_of_val(data) as u32,
+            );
+            Memory { handle }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use super::ViewId;
+use crate::backend;
+
+/// * `id`:
+/// View id.
+/// * `view`:
+/// View matrix.
+/// * `proj`:
+/// Projection matrix.
+pub fn set_view_transform(id: ViewId, view: &[f32; 16], proj: &[f32; 16]) {
+    unsafe {
+        let _view = std::mem::transmute(view);
+        let _proj = std::mem::transmute(proj);
+        backend::bgfx_set_view_transform(id, _view, _proj);
+    }
+}
+
+/// * `mtx`:
+/// Pointer to first matrix in array.
+/// * `num`:
+/// Number of matrices in array.
+pub fn set_transform(mtx: &[f32; 16], num: u16) -> u32 {
+    unsafe {
+        let _mtx = std::mem::transmute(mtx);
+        backend::bgfx_set_transform(_mtx, num)
+    }
+}
@@ -0,0 +1,60 @@
+use cfixed_string::CFixedString;
+
+use super::{Encoder, Shader, Uniform, UniformType};
+use crate::backend;
+
+/// Returns the number of uniforms and uniform handles used inside a shader.
+///
+/// Notice that only non-predefined uniforms are returned.
+
+impl Shader {
+    //pub fn get_uniforms(&self, uniforms: &mut [Uniform]) -> u16 {
+    //	unsafe { bgfx_sys::bgfx_get_shader_uniforms(self.handle, uniforms.as_ptr(), uniforms.len() as u16) }
+    //}
+}
+
+impl Encoder {
+    /// * `handle`: Uniform.
+    /// * `value`: Pointer to uniform data.
+    /// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
+    pub fn set_uniform(&self, handle: &Uniform, value: &[f32], num: u16) {
+        unsafe {
+            let _self = std::mem::transmute(self);
+            backend::bgfx_encoder_set_uniform(_self, handle.handle, value.as_ptr() as _, num);
+        }
+    }
+}
+
+impl Uniform {
+    /// * `name`:
+    /// Uniform name in shader.
+    /// * `type_r`:
+    /// Type of uniform (See: `bgfx::UniformType`).
+    /// * `num`:
+    /// Number of elements in array.
+    pub fn create(name: &str, type_r: UniformType, num: u16) -> Uniform {
+        unsafe {
+            let name_ = CFixedString::from_str(name);
+            let _ret = backend::bgfx_create_uniform(name_.as_ptr() as _, type_r as _, num);
+            Uniform { handle: _ret }
+        }
+    }
+
+    /// * `handle`: Uniform.
+    /// * `value`: uniform data.
+    /// * `num`: Number of elements. Passing `u16::MAX` will use the num passed on uniform creation.
+    pub fn set(&self, value: &[f32], num: u16) {
+        unsafe {
+            backend::bgfx_set_uniform(self.handle, value.as_ptr() as _, num);
+        }
+    }
+}
+
+/// * `handle`: Uniform.
+/// * `value`: Pointer to uniform data.
+/// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
+pub fn set_uniform(handle: &Uniform, value: &[f32], num: u16) {
+    unsafe {
+        backend::bgfx_set_uniform(handle.handle, value.as_ptr() as _, num);
+    }
+}
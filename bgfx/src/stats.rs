@@ -0,0 +1,213 @@
+use std::ffi::CStr;
+use std::time::Duration;
+
+use crate::bgfx::{EncoderStats, Stats, ViewId, ViewStats};
+
+/// Converts timer ticks to a duration, negative or unknown values give zero.
+fn ticks(ticks: i64, freq: i64) -> Duration {
+    if ticks <= 0 || freq <= 0 {
+        return Duration::ZERO;
+    }
+
+    let secs = ticks / freq;
+    let nanos = (ticks % freq) as i128 * 1_000_000_000 / freq as i128;
+    Duration::new(secs as u64, nanos as u32)
+}
+
+impl Stats {
+    /// Per view timings. Views are only reported while [DebugFlags::PROFILER](crate::DebugFlags)
+    /// is enabled.
+    pub fn views(&self) -> &[ViewStats] {
+        if self.view_stats.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.view_stats, self.num_views as usize) }
+    }
+
+    /// Per encoder timings of the last frame.
+    pub fn encoders(&self) -> &[EncoderStats] {
+        if self.encoder_stats.is_null() {
+            return &[];
+        }
+        unsafe { std::slice::from_raw_parts(self.encoder_stats, self.num_encoders as usize) }
+    }
+
+    /// CPU time between the last two `frame` calls.
+    pub fn cpu_frame_time(&self) -> Duration {
+        ticks(self.cpu_time_frame, self.cpu_timer_freq)
+    }
+
+    /// CPU time the render thread spent submitting the last frame.
+    pub fn cpu_submit_time(&self) -> Duration {
+        ticks(self.cpu_time_end - self.cpu_time_begin, self.cpu_timer_freq)
+    }
+
+    /// GPU time of the last frame.
+    pub fn gpu_frame_time(&self) -> Duration {
+        ticks(self.gpu_time_end - self.gpu_time_begin, self.gpu_timer_freq)
+    }
+
+    /// Time spent waiting for the render backend thread to finish issuing draw commands.
+    pub fn wait_render_time(&self) -> Duration {
+        ticks(self.wait_render, self.cpu_timer_freq)
+    }
+
+    /// Time spent waiting for the submit thread to advance to the next frame.
+    pub fn wait_submit_time(&self) -> Duration {
+        ticks(self.wait_submit, self.cpu_timer_freq)
+    }
+
+    /// Copies the stats, so they stay valid after the next `frame` call.
+    pub fn snapshot(&self) -> StatsSnapshot {
+        StatsSnapshot {
+            cpu_frame_time: self.cpu_frame_time(),
+            cpu_begin: ticks(self.cpu_time_begin, self.cpu_timer_freq),
+            cpu_submit_time: self.cpu_submit_time(),
+            gpu_begin: ticks(self.gpu_time_begin, self.gpu_timer_freq),
+            gpu_frame_time: self.gpu_frame_time(),
+            wait_render_time: self.wait_render_time(),
+            wait_submit_time: self.wait_submit_time(),
+            num_draw: self.num_draw,
+            num_compute: self.num_compute,
+            num_blit: self.num_blit,
+            max_gpu_latency: self.max_gpu_latency,
+            num_prims: self.num_prims,
+            num_dynamic_index_buffers: self.num_dynamic_index_buffers,
+            num_dynamic_vertex_buffers: self.num_dynamic_vertex_buffers,
+            num_frame_buffers: self.num_frame_buffers,
+            num_index_buffers: self.num_index_buffers,
+            num_occlusion_queries: self.num_occlusion_queries,
+            num_programs: self.num_programs,
+            num_shaders: self.num_shaders,
+            num_textures: self.num_textures,
+            num_uniforms: self.num_uniforms,
+            num_vertex_buffers: self.num_vertex_buffers,
+            num_vertex_layouts: self.num_vertex_layouts,
+            texture_memory_used: self.texture_memory_used,
+            rt_memory_used: self.rt_memory_used,
+            transient_vb_used: self.transient_vb_used,
+            transient_ib_used: self.transient_ib_used,
+            gpu_memory_max: self.gpu_memory_max,
+            gpu_memory_used: self.gpu_memory_used,
+            width: self.width,
+            height: self.height,
+            views: self
+                .views()
+                .iter()
+                .map(|view| ViewStatsSnapshot {
+                    name: view.name().to_owned(),
+                    view: view.view,
+                    cpu_begin: ticks(view.cpu_time_begin, self.cpu_timer_freq),
+                    cpu_time: view.cpu_time(self),
+                    gpu_begin: ticks(view.gpu_time_begin, self.gpu_timer_freq),
+                    gpu_time: view.gpu_time(self),
+                })
+                .collect(),
+            encoders: self
+                .encoders()
+                .iter()
+                .map(|encoder| EncoderStatsSnapshot {
+                    cpu_begin: ticks(encoder.cpu_time_begin, self.cpu_timer_freq),
+                    cpu_time: encoder.cpu_time(self),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl ViewStats {
+    /// Name set with `set_view_name`, empty if it's not valid UTF-8.
+    pub fn name(&self) -> &str {
+        let bytes = unsafe { &*(&self.name as *const [i8; 256] as *const [u8; 256]) };
+        CStr::from_bytes_until_nul(bytes)
+            .ok()
+            .and_then(|name| name.to_str().ok())
+            .unwrap_or_default()
+    }
+
+    /// CPU (submit) time of the view. `stats` provides the timer frequency.
+    pub fn cpu_time(&self, stats: &Stats) -> Duration {
+        ticks(
+            self.cpu_time_end - self.cpu_time_begin,
+            stats.cpu_timer_freq,
+        )
+    }
+
+    /// GPU time of the view. `stats` provides the timer frequency.
+    pub fn gpu_time(&self, stats: &Stats) -> Duration {
+        ticks(
+            self.gpu_time_end - self.gpu_time_begin,
+            stats.gpu_timer_freq,
+        )
+    }
+}
+
+impl EncoderStats {
+    /// CPU submit time of the encoder thread. `stats` provides the timer frequency.
+    pub fn cpu_time(&self, stats: &Stats) -> Duration {
+        ticks(
+            self.cpu_time_end - self.cpu_time_begin,
+            stats.cpu_timer_freq,
+        )
+    }
+}
+
+/// Owned copy of [Stats] with timings converted to [Duration]s, see [Stats::snapshot].
+///
+/// `*_begin` values are timestamps since an unspecified start of the CPU or GPU timer, only
+/// useful relative to each other.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct StatsSnapshot {
+    pub cpu_frame_time: Duration,
+    pub cpu_begin: Duration,
+    pub cpu_submit_time: Duration,
+    pub gpu_begin: Duration,
+    pub gpu_frame_time: Duration,
+    pub wait_render_time: Duration,
+    pub wait_submit_time: Duration,
+    pub num_draw: u32,
+    pub num_compute: u32,
+    pub num_blit: u32,
+    pub max_gpu_latency: u32,
+    /// Primitives rendered per [Topology](crate::Topology).
+    pub num_prims: [u32; 5],
+    pub num_dynamic_index_buffers: u16,
+    pub num_dynamic_vertex_buffers: u16,
+    pub num_frame_buffers: u16,
+    pub num_index_buffers: u16,
+    pub num_occlusion_queries: u16,
+    pub num_programs: u16,
+    pub num_shaders: u16,
+    pub num_textures: u16,
+    pub num_uniforms: u16,
+    pub num_vertex_buffers: u16,
+    pub num_vertex_layouts: u16,
+    pub texture_memory_used: i64,
+    pub rt_memory_used: i64,
+    pub transient_vb_used: i32,
+    pub transient_ib_used: i32,
+    pub gpu_memory_max: i64,
+    pub gpu_memory_used: i64,
+    pub width: u16,
+    pub height: u16,
+    pub views: Vec<ViewStatsSnapshot>,
+    pub encoders: Vec<EncoderStatsSnapshot>,
+}
+
+/// Owned copy of [ViewStats].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct ViewStatsSnapshot {
+    pub name: String,
+    pub view: ViewId,
+    pub cpu_begin: Duration,
+    pub cpu_time: Duration,
+    pub gpu_begin: Duration,
+    pub gpu_time: Duration,
+}
+
+/// Owned copy of [EncoderStats].
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct EncoderStatsSnapshot {
+    pub cpu_begin: Duration,
+    pub cpu_time: Duration,
+}
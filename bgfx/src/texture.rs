@@ -0,0 +1,413 @@
+use std::ffi::CStr;
+use std::fmt;
+use std::os::raw::c_char;
+use std::path::{Path, PathBuf};
+
+use crate::bgfx::{
+    calc_texture_size, create_texture_2d, create_texture_3d, create_texture_cube, get_caps,
+    is_texture_valid, CapsFormatFlags, CreateTexture3DArgs, CreateTextureCubeArgs, Memory, Texture,
+    TextureFlags, TextureFormat, TextureInfo,
+};
+
+/// Error returned when loading an image or creating a texture from it fails.
+#[derive(Debug)]
+pub enum TextureError {
+    Io {
+        path: PathBuf,
+        error: std::io::Error,
+    },
+    /// bimg could not parse the data, with the reason reported by bimg.
+    Parse(String),
+    /// bimg returned a format that has no [TextureFormat] counterpart.
+    UnknownFormat(u32),
+    /// The current renderer does not support the format for this kind of texture (see `Caps::formats`).
+    UnsupportedFormat(TextureFormat),
+    /// The renderer can't create a texture with these dimensions, layers and flags.
+    InvalidTexture,
+    /// [TextureEncoder](crate::TextureEncoder) can't encode into this format.
+    UnsupportedEncoding(TextureFormat),
+    /// The pixel data has a different length than width * height * channels.
+    InvalidData { expected: usize, found: usize },
+    /// bimg failed to encode the image, with the reason reported by bimg.
+    Encode(String),
+    /// bimg's image functions take the width and height as `u16`.
+    TooLarge { width: u32, height: u32 },
+    /// The image can't be used as or converted into a cubemap.
+    InvalidCubemap(String),
+    /// The arguments don't describe a texture, f.e. surfaces of different sizes.
+    InvalidInput(String),
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TextureError::Io { path, error } => {
+                write!(f, "failed to read {}: {}", path.display(), error)
+            }
+            TextureError::Parse(message) => write!(f, "failed to parse image: {}", message),
+            TextureError::UnknownFormat(format) => write!(f, "unknown texture format {}", format),
+            TextureError::UnsupportedFormat(format) => {
+                write!(
+                    f,
+                    "texture format {:?} is not supported by the renderer",
+                    format
+                )
+            }
+            TextureError::InvalidTexture => {
+                write!(f, "texture parameters are not supported by the renderer")
+            }
+            TextureError::UnsupportedEncoding(format) => {
+                write!(f, "encoding to {:?} is not supported", format)
+            }
+            TextureError::InvalidData { expected, found } => {
+                write!(
+                    f,
+                    "expected {} elements of pixel data, found {}",
+                    expected, found
+                )
+            }
+            TextureError::Encode(message) => write!(f, "failed to encode image: {}", message),
+            TextureError::TooLarge { width, height } => write!(
+                f,
+                "image of {}x{} is larger than {}x{}",
+                width,
+                height,
+                u16::MAX,
+                u16::MAX
+            ),
+            TextureError::InvalidCubemap(message) => write!(f, "invalid cubemap: {}", message),
+            TextureError::InvalidInput(message) => write!(f, "invalid input: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for TextureError {}
+
+/// Decoded image, parsed by bimg from DDS, KTX or PVR containers or from common image formats
+/// (PNG, JPEG, TGA, BMP, HDR, EXR, ...).
+///
+/// Container formats keep their mips, cubemap faces, layers and (compressed) format. Other
+/// formats are decoded into a single mip.
+pub struct Image {
+    handle: *mut bgfx_sys::bimg_image_container_t,
+    info: bgfx_sys::bimg_image_info_t,
+}
+
+impl Image {
+    /// Parses `data` keeping the format of the source.
+    pub fn parse(data: &[u8]) -> Result<Image, TextureError> {
+        Image::parse_impl(data, TextureFormat::Count as u32)
+    }
+
+    /// Parses `data` and converts it to `format`.
+    pub fn parse_as(data: &[u8], format: TextureFormat) -> Result<Image, TextureError> {
+        Image::parse_impl(data, format as u32)
+    }
+
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Image, TextureError> {
+        Image::parse(&read_file(path.as_ref())?)
+    }
+
+    /// Creates an image from raw pixel data laid out the way bgfx expects (layers, then cube
+    /// faces, then mips). `data` must hold exactly the size of such an image.
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_data(
+        format: TextureFormat,
+        width: u16,
+        height: u16,
+        depth: u16,
+        num_layers: u16,
+        cube_map: bool,
+        has_mips: bool,
+        data: &[u8],
+    ) -> Result<Image, TextureError> {
+        let expected = unsafe {
+            bgfx_sys::bimg_image_get_size(
+                width,
+                height,
+                depth,
+                cube_map,
+                has_mips,
+                num_layers,
+                format as u32,
+            )
+        } as usize;
+
+        if data.len() != expected {
+            return Err(TextureError::InvalidData {
+                expected,
+                found: data.len(),
+            });
+        }
+
+        let handle = unsafe {
+            bgfx_sys::bimg_image_alloc(
+                format as u32,
+                width,
+                height,
+                depth,
+                num_layers,
+                cube_map,
+                has_mips,
+                data.as_ptr() as _,
+            )
+        };
+        Image::from_handle(handle, TextureError::Encode)
+    }
+
+    fn parse_impl(data: &[u8], format: u32) -> Result<Image, TextureError> {
+        Image::try_create(TextureError::Parse, |message, max| unsafe {
+            bgfx_sys::bimg_image_parse(data.as_ptr() as _, data.len() as u32, format, message, max)
+        })
+    }
+
+    /// Calls a bimg function returning a new image, turning NULL into `error` with the message
+    /// bimg wrote into the buffer passed to `create`.
+    pub(crate) fn try_create<E, F>(error: E, create: F) -> Result<Image, TextureError>
+    where
+        E: FnOnce(String) -> TextureError,
+        F: FnOnce(*mut c_char, u32) -> *mut bgfx_sys::bimg_image_container_t,
+    {
+        let mut message = [0 as c_char; 256];
+        let handle = create(message.as_mut_ptr(), message.len() as u32);
+
+        if handle.is_null() {
+            let message = unsafe { CStr::from_ptr(message.as_ptr()) };
+            return Err(error(message.to_string_lossy().into_owned()));
+        }
+
+        Image::from_handle(handle, error)
+    }
+
+    fn from_handle<E>(
+        handle: *mut bgfx_sys::bimg_image_container_t,
+        error: E,
+    ) -> Result<Image, TextureError>
+    where
+        E: FnOnce(String) -> TextureError,
+    {
+        if handle.is_null() {
+            return Err(error("allocation failed".to_owned()));
+        }
+
+        let mut info = bgfx_sys::bimg_image_info_t::default();
+        unsafe { bgfx_sys::bimg_image_get_info(handle, &mut info) };
+
+        let image = Image { handle, info };
+        image.format()?;
+        Ok(image)
+    }
+
+    pub(crate) fn as_ptr(&self) -> *const bgfx_sys::bimg_image_container_t {
+        self.handle
+    }
+
+    /// Converts the image and all its mips to `format`.
+    pub fn convert(&self, format: TextureFormat) -> Result<Image, TextureError> {
+        let handle = unsafe { bgfx_sys::bimg_image_convert(self.handle, format as u32) };
+        Image::from_handle(handle, |_| TextureError::UnsupportedEncoding(format))
+    }
+
+    pub fn format(&self) -> Result<TextureFormat, TextureError> {
+        if self.info.format < TextureFormat::Count as u32 {
+            Ok(unsafe { std::mem::transmute::<u32, TextureFormat>(self.info.format) })
+        } else {
+            Err(TextureError::UnknownFormat(self.info.format))
+        }
+    }
+
+    pub fn width(&self) -> u32 {
+        self.info.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.info.height
+    }
+
+    /// Depth of volume textures, 1 otherwise.
+    pub fn depth(&self) -> u32 {
+        self.info.depth
+    }
+
+    pub fn num_layers(&self) -> u16 {
+        self.info.num_layers
+    }
+
+    pub fn num_mips(&self) -> u8 {
+        self.info.num_mips
+    }
+
+    pub fn has_alpha(&self) -> bool {
+        self.info.has_alpha
+    }
+
+    pub fn is_cube_map(&self) -> bool {
+        self.info.cube_map
+    }
+
+    /// True if the container marks the data as sRGB.
+    pub fn is_srgb(&self) -> bool {
+        self.info.srgb
+    }
+
+    /// Image data of all layers, faces and mips, in the layout expected by bgfx.
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            let data = bgfx_sys::bimg_image_get_data(self.handle);
+            std::slice::from_raw_parts(data as *const u8, self.info.size as usize)
+        }
+    }
+
+    /// Returns the [TextureInfo] of a texture created from this image.
+    pub fn texture_info(&self) -> Result<TextureInfo, TextureError> {
+        let mut info = TextureInfo::new();
+        calc_texture_size(
+            &mut info,
+            self.info.width as u16,
+            self.info.height as u16,
+            self.info.depth as u16,
+            self.info.cube_map,
+            self.info.num_mips > 1,
+            self.info.num_layers,
+            self.format()?,
+        );
+        Ok(info)
+    }
+
+    /// Checks that the current renderer can sample this image with `flags` (see [TextureFlags]).
+    /// Requires bgfx to be initialized.
+    pub fn is_supported(&self, flags: u64) -> Result<(), TextureError> {
+        let format = self.format()?;
+        let srgb = flags & TextureFlags::SRGB.bits() != 0;
+
+        let required = match (self.info.cube_map, self.info.depth > 1, srgb) {
+            (true, _, false) => {
+                CapsFormatFlags::TEXTURE_CUBE | CapsFormatFlags::TEXTURE_CUBE_EMULATED
+            }
+            (true, _, true) => CapsFormatFlags::TEXTURE_CUBE_SRGB,
+            (false, true, false) => {
+                CapsFormatFlags::TEXTURE_3_D | CapsFormatFlags::TEXTURE_3_D_EMULATED
+            }
+            (false, true, true) => CapsFormatFlags::TEXTURE_3_D_SRGB,
+            (false, false, false) => {
+                CapsFormatFlags::TEXTURE_2_D | CapsFormatFlags::TEXTURE_2_D_EMULATED
+            }
+            (false, false, true) => CapsFormatFlags::TEXTURE_2_D_SRGB,
+        };
+
+        let supported =
+            CapsFormatFlags::from_bits_truncate(get_caps().formats[format as usize] as u32);
+        if !supported.intersects(required) {
+            return Err(TextureError::UnsupportedFormat(format));
+        }
+
+        if !is_texture_valid(
+            self.info.depth as u16,
+            self.info.cube_map,
+            self.info.num_layers,
+            format,
+            flags,
+        ) {
+            return Err(TextureError::InvalidTexture);
+        }
+
+        Ok(())
+    }
+
+    /// Creates an immutable 2D, 3D or cube texture with the data of this image.
+    /// Requires bgfx to be initialized.
+    ///
+    /// `flags` are texture creation (see [TextureFlags]) and sampler (see `SamplerFlags`) flags.
+    pub fn create_texture(&self, flags: u64) -> Result<(Texture, TextureInfo), TextureError> {
+        self.is_supported(flags)?;
+
+        let format = self.format()?;
+        let has_mips = self.info.num_mips > 1;
+        let mem = Memory::copy(self.data());
+
+        let texture = if self.info.cube_map {
+            create_texture_cube(
+                self.info.width as u16,
+                has_mips,
+                self.info.num_layers,
+                format,
+                CreateTextureCubeArgs {
+                    flags,
+                    mem: Some(mem),
+                },
+            )
+        } else if self.info.depth > 1 {
+            create_texture_3d(
+                self.info.width as u16,
+                self.info.height as u16,
+                self.info.depth as u16,
+                has_mips,
+                format,
+                CreateTexture3DArgs {
+                    flags,
+                    mem: Some(mem),
+                },
+            )
+        } else {
+            create_texture_2d(
+                self.info.width as u16,
+                self.info.height as u16,
+                has_mips,
+                self.info.num_layers,
+                format,
+                flags,
+                &mem,
+            )
+        };
+
+        Ok((texture, self.texture_info()?))
+    }
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe { bgfx_sys::bimg_image_free(self.handle) }
+    }
+}
+
+impl fmt::Debug for Image {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Image")
+            .field("format", &self.format())
+            .field("width", &self.info.width)
+            .field("height", &self.info.height)
+            .field("depth", &self.info.depth)
+            .field("num_layers", &self.info.num_layers)
+            .field("num_mips", &self.info.num_mips)
+            .field("cube_map", &self.info.cube_map)
+            .finish()
+    }
+}
+
+impl Texture {
+    /// Parses DDS, KTX, PVR or common image formats with bimg and creates a matching 2D, 3D or
+    /// cube texture. Requires bgfx to be initialized.
+    ///
+    /// ```ignore
+    /// let (texture, info) = Texture::load(include_bytes!("logo.png"), 0)?;
+    /// ```
+    ///
+    /// `flags` are texture creation (see [TextureFlags]) and sampler (see `SamplerFlags`) flags.
+    pub fn load(data: &[u8], flags: u64) -> Result<(Texture, TextureInfo), TextureError> {
+        Image::parse(data)?.create_texture(flags)
+    }
+
+    pub fn load_file<P: AsRef<Path>>(
+        path: P,
+        flags: u64,
+    ) -> Result<(Texture, TextureInfo), TextureError> {
+        Image::from_file(path)?.create_texture(flags)
+    }
+}
+
+fn read_file(path: &Path) -> Result<Vec<u8>, TextureError> {
+    std::fs::read(path).map_err(|error| TextureError::Io {
+        path: path.to_owned(),
+        error,
+    })
+}
@@ -0,0 +1,185 @@
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use crate::bgfx::TextureFormat;
+use crate::texture::TextureError;
+
+/// Speed/quality trade-off of [TextureEncoder]. The normal map variants weigh channels for
+/// tangent space normals instead of perceived color.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub enum EncodeQuality {
+    #[default]
+    Default,
+    Highest,
+    Fastest,
+    NormalMapDefault,
+    NormalMapHighest,
+    NormalMapFastest,
+}
+
+/// Compresses RGBA8 or RGBA32F pixels with bimg's encoders, f.e. to compress textures offline
+/// in an asset pipeline.
+///
+/// ```ignore
+/// let data = TextureEncoder::new(TextureFormat::BC7)?
+///     .quality(EncodeQuality::Highest)
+///     .encode_rgba8(&pixels, width, height)?;
+///
+/// let texture = create_texture_2d(
+///     width as u16,
+///     height as u16,
+///     false,
+///     1,
+///     TextureFormat::BC7,
+///     0,
+///     &Memory::copy(&data),
+/// );
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TextureEncoder {
+    format: TextureFormat,
+    quality: EncodeQuality,
+}
+
+impl TextureEncoder {
+    /// Formats supported as encoder output.
+    pub const FORMATS: &'static [TextureFormat] = &[
+        TextureFormat::BC1,
+        TextureFormat::BC2,
+        TextureFormat::BC3,
+        TextureFormat::BC4,
+        TextureFormat::BC5,
+        TextureFormat::BC6H,
+        TextureFormat::BC7,
+        TextureFormat::ETC1,
+        TextureFormat::ETC2,
+        TextureFormat::ETC2A,
+        TextureFormat::ETC2A1,
+        TextureFormat::PTC14,
+        TextureFormat::PTC14A,
+    ];
+
+    /// Returns [TextureError::UnsupportedEncoding] if `format` is not one of [TextureEncoder::FORMATS].
+    pub fn new(format: TextureFormat) -> Result<TextureEncoder, TextureError> {
+        if !TextureEncoder::FORMATS.contains(&format) {
+            return Err(TextureError::UnsupportedEncoding(format));
+        }
+
+        Ok(TextureEncoder {
+            format,
+            quality: EncodeQuality::Default,
+        })
+    }
+
+    pub fn quality(&mut self, quality: EncodeQuality) -> &mut Self {
+        self.quality = quality;
+        self
+    }
+
+    pub fn format(&self) -> TextureFormat {
+        self.format
+    }
+
+    /// Size in bytes of the encoded data for an image of `width` x `height`.
+    pub fn encoded_size(&self, width: u16, height: u16) -> usize {
+        unsafe {
+            bgfx_sys::bimg_image_get_size(
+                width,
+                height,
+                1,
+                false,
+                false,
+                1,
+                self.format as u32,
+            ) as usize
+        }
+    }
+
+    /// Encodes `pixels`, 4 bytes per pixel in RGBA order, row by row. [TextureFormat::PTC14] and
+    /// [TextureFormat::PTC14A] need a square image with a power of two size.
+    pub fn encode_rgba8(
+        &self,
+        pixels: &[u8],
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<u8>, TextureError> {
+        let (width, height) = check_size(pixels.len(), width, height)?;
+        self.encode(pixels.as_ptr() as _, width, height, false)
+    }
+
+    /// Encodes `pixels`, 4 floats per pixel in RGBA order, row by row. Use this for HDR data
+    /// encoded to [TextureFormat::BC6H].
+    pub fn encode_rgba32f(
+        &self,
+        pixels: &[f32],
+        width: u32,
+        height: u32,
+    ) -> Result<Vec<u8>, TextureError> {
+        let (width, height) = check_size(pixels.len(), width, height)?;
+        self.encode(pixels.as_ptr() as _, width, height, true)
+    }
+
+    fn encode(
+        &self,
+        src: *const std::os::raw::c_void,
+        width: u16,
+        height: u16,
+        float: bool,
+    ) -> Result<Vec<u8>, TextureError> {
+        // bimg's PVRTC encoder only asserts this in debug builds and reads past the pixels otherwise
+        let pvrtc = matches!(self.format, TextureFormat::PTC14 | TextureFormat::PTC14A);
+        if pvrtc && (width != height || !width.is_power_of_two()) {
+            return Err(TextureError::InvalidInput(format!(
+                "{:?} needs a square power of two image, not {}x{}",
+                self.format, width, height
+            )));
+        }
+
+        let mut data = vec![0u8; self.encoded_size(width, height)];
+        let mut message = [0 as c_char; 256];
+
+        let ok = unsafe {
+            let encode = if float {
+                bgfx_sys::bimg_image_encode_from_rgba32f
+            } else {
+                bgfx_sys::bimg_image_encode_from_rgba8
+            };
+            encode(
+                data.as_mut_ptr() as _,
+                src,
+                width as u32,
+                height as u32,
+                1,
+                self.format as u32,
+                self.quality as u32,
+                message.as_mut_ptr(),
+                message.len() as u32,
+            )
+        };
+
+        if !ok {
+            let message = unsafe { CStr::from_ptr(message.as_ptr()) };
+            return Err(TextureError::Encode(message.to_string_lossy().into_owned()));
+        }
+
+        Ok(data)
+    }
+}
+
+/// Checks that `len` elements hold `width` x `height` RGBA pixels of a size bimg accepts.
+fn check_size(len: usize, width: u32, height: u32) -> Result<(u16, u16), TextureError> {
+    let size = match (u16::try_from(width), u16::try_from(height)) {
+        (Ok(width), Ok(height)) => (width, height),
+        _ => return Err(TextureError::TooLarge { width, height }),
+    };
+
+    let expected = width as usize * height as usize * 4;
+    if len != expected {
+        return Err(TextureError::InvalidData {
+            expected,
+            found: len,
+        });
+    }
+    Ok(size)
+}
@@ -0,0 +1,239 @@
+use crate::bgfx::{TextureFormat, TextureInfo};
+
+/// Per format block layout, the same values bimg uses (`s_imageBlockInfo`).
+struct BlockInfo {
+    bits_per_pixel: u8,
+    block_width: u8,
+    block_height: u8,
+    block_bytes: u8,
+    min_block_x: u8,
+    min_block_y: u8,
+    channels: u8,
+    float: bool,
+}
+
+const fn block(
+    bits_per_pixel: u8,
+    block_width: u8,
+    block_height: u8,
+    block_bytes: u8,
+    min_block: u8,
+    channels: u8,
+) -> BlockInfo {
+    BlockInfo {
+        bits_per_pixel,
+        block_width,
+        block_height,
+        block_bytes,
+        min_block_x: min_block,
+        min_block_y: min_block,
+        channels,
+        float: false,
+    }
+}
+
+const fn pixel(bits_per_pixel: u8, channels: u8) -> BlockInfo {
+    BlockInfo {
+        bits_per_pixel,
+        block_width: 1,
+        block_height: 1,
+        block_bytes: bits_per_pixel / 8,
+        min_block_x: 1,
+        min_block_y: 1,
+        channels,
+        float: false,
+    }
+}
+
+const fn float(info: BlockInfo) -> BlockInfo {
+    BlockInfo {
+        float: true,
+        ..info
+    }
+}
+
+impl TextureFormat {
+    const fn block_info(self) -> BlockInfo {
+        use TextureFormat::*;
+
+        match self {
+            BC1 => block(4, 4, 4, 8, 1, 4),
+            BC2 => block(8, 4, 4, 16, 1, 4),
+            BC3 => block(8, 4, 4, 16, 1, 4),
+            BC4 => block(4, 4, 4, 8, 1, 1),
+            BC5 => block(8, 4, 4, 16, 1, 2),
+            BC6H => float(block(8, 4, 4, 16, 1, 3)),
+            BC7 => block(8, 4, 4, 16, 1, 4),
+            ETC1 => block(4, 4, 4, 8, 1, 3),
+            ETC2 => block(4, 4, 4, 8, 1, 3),
+            ETC2A => block(8, 4, 4, 16, 1, 4),
+            ETC2A1 => block(4, 4, 4, 8, 1, 4),
+            PTC12 => block(2, 8, 4, 8, 2, 3),
+            PTC14 => block(4, 4, 4, 8, 2, 3),
+            PTC12A => block(2, 8, 4, 8, 2, 4),
+            PTC14A => block(4, 4, 4, 8, 2, 4),
+            PTC22 => block(2, 8, 4, 8, 2, 4),
+            PTC24 => block(4, 4, 4, 8, 2, 4),
+            ATC => block(4, 4, 4, 8, 1, 3),
+            ATCE => block(8, 4, 4, 16, 1, 4),
+            ATCI => block(8, 4, 4, 16, 1, 4),
+            ASTC4x4 => block(8, 4, 4, 16, 1, 4),
+            ASTC5x5 => block(6, 5, 5, 16, 1, 4),
+            ASTC6x6 => block(4, 6, 6, 16, 1, 4),
+            ASTC8x5 => block(4, 8, 5, 16, 1, 4),
+            ASTC8x6 => block(3, 8, 6, 16, 1, 4),
+            ASTC10x5 => block(3, 10, 5, 16, 1, 4),
+            Unknown => pixel(0, 0),
+            R1 => block(1, 8, 1, 1, 1, 1),
+            A8 | R8 | R8I | R8U | R8S => pixel(8, 1),
+            R16 | R16I | R16U | R16S => pixel(16, 1),
+            R16F => float(pixel(16, 1)),
+            R32I | R32U => pixel(32, 1),
+            R32F => float(pixel(32, 1)),
+            RG8 | RG8I | RG8U | RG8S => pixel(16, 2),
+            RG16 | RG16I | RG16U | RG16S => pixel(32, 2),
+            RG16F => float(pixel(32, 2)),
+            RG32I | RG32U => pixel(64, 2),
+            RG32F => float(pixel(64, 2)),
+            RGB8 | RGB8I | RGB8U | RGB8S => pixel(24, 3),
+            RGB9E5F => float(pixel(32, 3)),
+            BGRA8 | RGBA8 | RGBA8I | RGBA8U | RGBA8S => pixel(32, 4),
+            RGBA16 | RGBA16I | RGBA16U | RGBA16S => pixel(64, 4),
+            RGBA16F => float(pixel(64, 4)),
+            RGBA32I | RGBA32U => pixel(128, 4),
+            RGBA32F => float(pixel(128, 4)),
+            R5G6B5 => pixel(16, 3),
+            RGBA4 | RGB5A1 => pixel(16, 4),
+            RGB10A2 => pixel(32, 4),
+            RG11B10F => float(pixel(32, 3)),
+            UnknownDepth => pixel(0, 0),
+            D16 => pixel(16, 1),
+            D24 => pixel(24, 1),
+            D24S8 => pixel(32, 2),
+            D32 => pixel(32, 1),
+            D16F => float(pixel(16, 1)),
+            D24F => float(pixel(24, 1)),
+            D32F => float(pixel(32, 1)),
+            D0S8 => pixel(8, 1),
+            Count => pixel(0, 0),
+        }
+    }
+
+    /// Iterates over all formats in declaration order, without [TextureFormat::Count].
+    pub fn all() -> impl Iterator<Item = TextureFormat> {
+        (0..TextureFormat::Count as u32)
+            .map(|format| unsafe { std::mem::transmute::<u32, TextureFormat>(format) })
+    }
+
+    /// Average bits per pixel, the value bgfx reports in `TextureInfo::bits_per_pixel`.
+    pub const fn bits_per_pixel(self) -> u8 {
+        self.block_info().bits_per_pixel
+    }
+
+    /// Width and height of a block in pixels, 1x1 for uncompressed formats.
+    pub const fn block_size(self) -> (u8, u8) {
+        let info = self.block_info();
+        (info.block_width, info.block_height)
+    }
+
+    /// Size of a block in bytes.
+    pub const fn block_bytes(self) -> u8 {
+        self.block_info().block_bytes
+    }
+
+    /// True for block compressed formats (BC, ETC, PVRTC, ATC and ASTC).
+    pub const fn is_compressed(self) -> bool {
+        (self as u32) < TextureFormat::Unknown as u32
+    }
+
+    /// True for depth and stencil formats.
+    pub const fn is_depth(self) -> bool {
+        (self as u32) > TextureFormat::UnknownDepth as u32
+            && (self as u32) < TextureFormat::Count as u32
+    }
+
+    pub const fn has_stencil(self) -> bool {
+        matches!(self, TextureFormat::D24S8 | TextureFormat::D0S8)
+    }
+
+    /// True for floating point formats, including shared exponent and packed float formats.
+    pub const fn is_float(self) -> bool {
+        self.block_info().float
+    }
+
+    /// Number of channels, depth and stencil count as one channel each.
+    pub const fn channel_count(self) -> u8 {
+        self.block_info().channels
+    }
+}
+
+impl TextureInfo {
+    /// Pure Rust equivalent of `calc_texture_size`, usable without an initialized bgfx context,
+    /// f.e. in headless asset tools. Takes the same arguments and gives the same result, so the
+    /// width and height are padded to whole blocks for compressed formats (5x5 BC1 is 8x8).
+    pub fn calc(
+        width: u16,
+        height: u16,
+        depth: u16,
+        cube_map: bool,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+    ) -> TextureInfo {
+        let info = format.block_info();
+        let block_width = info.block_width as u32;
+        let block_height = info.block_height as u32;
+        let min_width = block_width * info.min_block_x as u32;
+        let min_height = block_height * info.min_block_y as u32;
+
+        // Like bimg, every mip is padded to whole blocks and at least the minimum number of
+        // blocks, and the padded size is what's reported and halved for the next mip.
+        let pad = |size: u32, block: u32, min: u32| {
+            if block == 0 {
+                size
+            } else {
+                (size.div_ceil(block) * block).max(min)
+            }
+        };
+        let width = pad(width as u32, block_width, min_width) as u16;
+        let height = pad(height as u32, block_height, min_height) as u16;
+        let depth = depth.max(1);
+
+        let num_mips = if has_mips {
+            let max = width.max(height).max(depth).max(1) as u32;
+            1 + (31 - max.leading_zeros()) as u8
+        } else {
+            1
+        };
+        let sides = if cube_map { 6 } else { 1 };
+
+        let mut w = width as u32;
+        let mut h = height as u32;
+        let mut d = depth as u32;
+        let mut size = 0u64;
+
+        for _ in 0..num_mips {
+            w = pad(w, block_width, min_width);
+            h = pad(h, block_height, min_height);
+            d = d.max(1);
+            size += w as u64 * h as u64 * d as u64 * info.bits_per_pixel as u64 / 8 * sides;
+
+            w >>= 1;
+            h >>= 1;
+            d >>= 1;
+        }
+        size *= num_layers as u64;
+
+        let mut texture_info = TextureInfo::new();
+        texture_info.format = format;
+        texture_info.storage_size = size as u32;
+        texture_info.width = width;
+        texture_info.height = height;
+        texture_info.depth = depth;
+        texture_info.num_layers = num_layers;
+        texture_info.num_mips = num_mips;
+        texture_info.bits_per_pixel = info.bits_per_pixel;
+        texture_info.cube_map = cube_map;
+        texture_info
+    }
+}
@@ -0,0 +1,378 @@
+use std::fmt;
+use std::path::Path;
+
+use crate::bgfx::{AddArgs, Attrib, AttribType, RendererType, VertexLayoutBuilder};
+
+/// A single declaration from a `varying.def.sc` file, f.e.
+/// `vec4 v_color0 : COLOR0 = vec4(1.0, 0.0, 0.0, 1.0);`
+#[derive(Clone, PartialEq, Debug)]
+pub struct VaryingDecl {
+    /// Interpolation qualifier (`flat`, `smooth`, `noperspective`, `centroid`), if any.
+    pub interpolation: Option<String>,
+    /// GLSL type name, f.e. `vec3`.
+    pub type_name: String,
+    /// Number of components of the type (1 for scalars).
+    pub components: u8,
+    /// Variable name, f.e. `a_position`.
+    pub name: String,
+    /// Semantic, f.e. `POSITION`.
+    pub semantic: String,
+    /// Default value expression, if any.
+    pub default: Option<String>,
+}
+
+impl VaryingDecl {
+    /// Returns true for vertex shader inputs (`a_*`).
+    pub fn is_attribute(&self) -> bool {
+        self.name.starts_with("a_")
+    }
+
+    /// Returns true for instance data inputs (`i_*`).
+    pub fn is_instance_data(&self) -> bool {
+        self.name.starts_with("i_")
+    }
+
+    /// Returns the vertex attribute of an `a_*` input. Like shaderc it's taken from the name
+    /// (`a_position`, `a_color0`, ...), the semantic isn't used.
+    pub fn attrib(&self) -> Option<Attrib> {
+        let attrib = match self.name.as_str() {
+            "a_position" => Attrib::Position,
+            "a_normal" => Attrib::Normal,
+            "a_tangent" => Attrib::Tangent,
+            "a_bitangent" => Attrib::Bitangent,
+            "a_color0" => Attrib::Color0,
+            "a_color1" => Attrib::Color1,
+            "a_color2" => Attrib::Color2,
+            "a_color3" => Attrib::Color3,
+            "a_indices" => Attrib::Indices,
+            "a_weight" => Attrib::Weight,
+            "a_texcoord0" => Attrib::TexCoord0,
+            "a_texcoord1" => Attrib::TexCoord1,
+            "a_texcoord2" => Attrib::TexCoord2,
+            "a_texcoord3" => Attrib::TexCoord3,
+            "a_texcoord4" => Attrib::TexCoord4,
+            "a_texcoord5" => Attrib::TexCoord5,
+            "a_texcoord6" => Attrib::TexCoord6,
+            "a_texcoord7" => Attrib::TexCoord7,
+            _ => return None,
+        };
+        Some(attrib)
+    }
+}
+
+/// Error returned when parsing a `varying.def.sc` file fails.
+#[derive(Debug)]
+pub enum VaryingError {
+    Io(std::io::Error),
+    /// Malformed declaration at `line` (1-based).
+    Parse {
+        line: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for VaryingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VaryingError::Io(e) => write!(f, "failed to read varying.def.sc: {}", e),
+            VaryingError::Parse { line, message } => {
+                write!(f, "varying.def.sc:{}: {}", line, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VaryingError {}
+
+impl From<std::io::Error> for VaryingError {
+    fn from(e: std::io::Error) -> VaryingError {
+        VaryingError::Io(e)
+    }
+}
+
+/// Difference between the inputs a shader expects and a vertex layout.
+#[derive(Clone, PartialEq, Debug)]
+pub enum LayoutMismatch {
+    /// The shader reads `name` but the layout has no such attribute. The draw will read zeros.
+    MissingAttrib { name: String, attrib: Attrib },
+    /// The layout provides fewer components than the shader declares. bgfx fills the missing
+    /// components with defaults, which is sometimes intended (f.e. `vec4` position from 3 floats).
+    ComponentCount {
+        name: String,
+        attrib: Attrib,
+        expected: u8,
+        found: u8,
+    },
+    /// The shader input has no matching declaration in `varying.def.sc`.
+    Undeclared { name: String },
+}
+
+impl fmt::Display for LayoutMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LayoutMismatch::MissingAttrib { name, attrib } => {
+                write!(
+                    f,
+                    "shader input `{}` expects {:?} but the layout has none",
+                    name, attrib
+                )
+            }
+            LayoutMismatch::ComponentCount {
+                name,
+                attrib,
+                expected,
+                found,
+            } => write!(
+                f,
+                "shader input `{}` expects {} components but the layout has {} for {:?}",
+                name, expected, found, attrib
+            ),
+            LayoutMismatch::Undeclared { name } => {
+                write!(
+                    f,
+                    "shader input `{}` is not declared in varying.def.sc",
+                    name
+                )
+            }
+        }
+    }
+}
+
+/// Parsed `varying.def.sc` file.
+///
+/// ```ignore
+/// let varying = VaryingDef::from_file("resources/rect/varying.def.sc")?;
+/// let inputs = shader_inputs(&std::fs::read_to_string("resources/rect/vs_rect.sc")?);
+///
+/// for mismatch in varying.check_layout(&layout, &inputs) {
+///     eprintln!("{}", mismatch);
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct VaryingDef {
+    pub decls: Vec<VaryingDecl>,
+}
+
+impl VaryingDef {
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<VaryingDef, VaryingError> {
+        VaryingDef::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(source: &str) -> Result<VaryingDef, VaryingError> {
+        let source = strip_comments(source);
+        let mut decls = Vec::new();
+        let mut offset = 0;
+
+        for statement in source.split(';') {
+            let start = offset + (statement.len() - statement.trim_start().len());
+            offset += statement.len() + 1;
+
+            if statement.trim().is_empty() {
+                continue;
+            }
+
+            let line = source[..start].matches('\n').count() + 1;
+            decls.push(
+                parse_decl(statement.trim())
+                    .map_err(|message| VaryingError::Parse { line, message })?,
+            );
+        }
+
+        Ok(VaryingDef { decls })
+    }
+
+    /// Returns the declaration named `name`.
+    pub fn get(&self, name: &str) -> Option<&VaryingDecl> {
+        self.decls.iter().find(|decl| decl.name == name)
+    }
+
+    /// Returns all vertex attribute (`a_*`) declarations with a known attribute name.
+    pub fn attributes(&self) -> impl Iterator<Item = (Attrib, &VaryingDecl)> {
+        self.decls
+            .iter()
+            .filter_map(|decl| decl.attrib().map(|attrib| (attrib, decl)))
+    }
+
+    /// Builds a float vertex layout for `inputs` (see [shader_inputs]), in the given order.
+    /// When `inputs` is empty all `a_*` declarations are used.
+    pub fn vertex_layout(&self, inputs: &[String]) -> Result<VertexLayoutBuilder, LayoutMismatch> {
+        let layout = VertexLayoutBuilder::new();
+        layout.begin(RendererType::Noop);
+
+        for attribute in self.selected_attributes(inputs) {
+            let (attrib, decl) = attribute?;
+            layout.add(
+                attrib,
+                decl.components,
+                AttribType::Float,
+                AddArgs::default(),
+            );
+        }
+
+        layout.end();
+        Ok(layout)
+    }
+
+    /// Checks that `layout` provides every input the shader reads. `inputs` is the `$input` list
+    /// of the vertex shader (see [shader_inputs]); when empty all `a_*` declarations are checked.
+    /// Returns every mismatch, in the order of `inputs`.
+    pub fn check_layout(
+        &self,
+        layout: &VertexLayoutBuilder,
+        inputs: &[String],
+    ) -> Vec<LayoutMismatch> {
+        let mut mismatches = Vec::new();
+
+        for attribute in self.selected_attributes(inputs) {
+            let (attrib, decl) = match attribute {
+                Ok(attribute) => attribute,
+                Err(mismatch) => {
+                    mismatches.push(mismatch);
+                    continue;
+                }
+            };
+            if !layout.has(attrib) {
+                mismatches.push(LayoutMismatch::MissingAttrib {
+                    name: decl.name.clone(),
+                    attrib,
+                });
+                continue;
+            }
+
+            let mut num = 0;
+            let mut type_r = AttribType::Float;
+            let mut normalized = false;
+            let mut as_int = false;
+            layout.decode(attrib, &mut num, &mut type_r, &mut normalized, &mut as_int);
+
+            if num < decl.components {
+                mismatches.push(LayoutMismatch::ComponentCount {
+                    name: decl.name.clone(),
+                    attrib,
+                    expected: decl.components,
+                    found: num,
+                });
+            }
+        }
+
+        mismatches
+    }
+
+    /// The attributes of `inputs`, or [LayoutMismatch::Undeclared] for inputs without one.
+    fn selected_attributes(
+        &self,
+        inputs: &[String],
+    ) -> Vec<Result<(Attrib, &VaryingDecl), LayoutMismatch>> {
+        if inputs.is_empty() {
+            return self.attributes().map(Ok).collect();
+        }
+
+        inputs
+            .iter()
+            .filter(|name| !name.starts_with("i_"))
+            .map(|name| {
+                self.get(name)
+                    .and_then(|decl| decl.attrib().map(|attrib| (attrib, decl)))
+                    .ok_or_else(|| LayoutMismatch::Undeclared { name: name.clone() })
+            })
+            .collect()
+    }
+}
+
+/// Returns the names listed on the `$input` line of a shader source, f.e.
+/// `$input a_position, a_color0` gives `["a_position", "a_color0"]`.
+pub fn shader_inputs(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("$input"))
+        .flat_map(|list| list.split(','))
+        .map(|name| name.trim().to_owned())
+        .filter(|name| !name.is_empty())
+        .collect()
+}
+
+/// Replaces `//` and `/* */` comments with spaces, keeping newlines so line numbers still match.
+fn strip_comments(source: &str) -> String {
+    let mut output = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('/', Some('/')) => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        output.push('\n');
+                        break;
+                    }
+                }
+            }
+            ('/', Some('*')) => {
+                chars.next();
+                let mut prev = ' ';
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        output.push('\n');
+                    }
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+                output.push(' ');
+            }
+            _ => output.push(c),
+        }
+    }
+
+    output
+}
+
+fn parse_decl(statement: &str) -> Result<VaryingDecl, String> {
+    let (decl, default) = match statement.split_once('=') {
+        Some((decl, default)) => (decl, Some(default.trim().to_owned())),
+        None => (statement, None),
+    };
+
+    let (decl, semantic) = decl
+        .split_once(':')
+        .ok_or_else(|| format!("expected `type name : SEMANTIC` in `{}`", statement))?;
+
+    let semantic = semantic.trim();
+    if semantic.is_empty() || semantic.contains(char::is_whitespace) {
+        return Err(format!("invalid semantic `{}`", semantic));
+    }
+
+    let words: Vec<&str> = decl.split_whitespace().collect();
+    let (interpolation, type_name, name) = match words.as_slice() {
+        [type_name, name] => (None, *type_name, *name),
+        [interpolation, type_name, name] => (Some(interpolation.to_string()), *type_name, *name),
+        _ => {
+            return Err(format!(
+                "expected `type name : SEMANTIC` in `{}`",
+                statement
+            ))
+        }
+    };
+
+    let components = type_components(type_name)
+        .ok_or_else(|| format!("unknown type `{}` for `{}`", type_name, name))?;
+
+    Ok(VaryingDecl {
+        interpolation,
+        type_name: type_name.to_owned(),
+        components,
+        name: name.to_owned(),
+        semantic: semantic.to_owned(),
+        default,
+    })
+}
+
+fn type_components(type_name: &str) -> Option<u8> {
+    match type_name {
+        "float" | "int" | "uint" | "bool" => Some(1),
+        "vec2" | "ivec2" | "uvec2" | "bvec2" => Some(2),
+        "vec3" | "ivec3" | "uvec3" | "bvec3" => Some(3),
+        "vec4" | "ivec4" | "uvec4" | "bvec4" => Some(4),
+        _ => None,
+    }
+}
@@ -0,0 +1,147 @@
+//! Shared setup of the headless tests. bgfx is a global, so every test holds [Noop] for its
+//! whole duration, which serializes the tests of a binary and shuts bgfx down at the end.
+
+#![allow(dead_code)]
+
+use std::sync::{Mutex, MutexGuard};
+
+use bgfx_rs::bgfx::{self, *};
+
+static BGFX: Mutex<()> = Mutex::new(());
+
+pub const WIDTH: u32 = 320;
+pub const HEIGHT: u32 = 240;
+
+pub const VS_CUBES: &[u8] =
+    include_bytes!("../../../resources/examples/runtime/shaders/glsl/vs_cubes.bin");
+pub const FS_CUBES: &[u8] =
+    include_bytes!("../../../resources/examples/runtime/shaders/glsl/fs_cubes.bin");
+pub const CS_UPDATE: &[u8] =
+    include_bytes!("../../../resources/examples/runtime/shaders/glsl/cs_update.bin");
+
+/// bgfx initialized with [RendererType::Noop] and no window, shut down on drop.
+///
+/// Declare it before any handle so the handles are destroyed first.
+pub struct Noop {
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl Noop {
+    pub fn init() -> Noop {
+        // A failed test poisons the lock, the next test still gets a fresh bgfx.
+        let lock = BGFX.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+
+        // Render on the calling thread, `frame` then processes everything synchronously.
+        bgfx::render_frame(-1);
+
+        let mut init = Init::new();
+        init.type_r = RendererType::Noop;
+        init.resolution.width = WIDTH;
+        init.resolution.height = HEIGHT;
+        init.resolution.reset = ResetFlags::NONE.bits();
+        init.platform_data = PlatformData::new();
+
+        assert!(
+            bgfx::init(&init),
+            "failed to init bgfx with the noop renderer"
+        );
+        assert_eq!(bgfx::get_renderer_type(), RendererType::Noop);
+
+        Noop { _lock: lock }
+    }
+}
+
+impl Drop for Noop {
+    fn drop(&mut self) {
+        bgfx::shutdown();
+    }
+}
+
+/// Live handle counts as reported by [Stats].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct Counts {
+    pub dynamic_index_buffers: u16,
+    pub dynamic_vertex_buffers: u16,
+    pub frame_buffers: u16,
+    pub index_buffers: u16,
+    pub occlusion_queries: u16,
+    pub programs: u16,
+    pub shaders: u16,
+    pub textures: u16,
+    pub uniforms: u16,
+    pub vertex_buffers: u16,
+    pub vertex_layouts: u16,
+}
+
+/// Handle counts after pending creates and destroys went through the renderer. Destroyed
+/// handles are released at the end of the frame that processed the destroy, so this runs two
+/// frames.
+pub fn counts() -> Counts {
+    bgfx::frame(false);
+    bgfx::frame(false);
+
+    let stats = bgfx::get_stats();
+    Counts {
+        dynamic_index_buffers: stats.num_dynamic_index_buffers,
+        dynamic_vertex_buffers: stats.num_dynamic_vertex_buffers,
+        frame_buffers: stats.num_frame_buffers,
+        index_buffers: stats.num_index_buffers,
+        occlusion_queries: stats.num_occlusion_queries,
+        programs: stats.num_programs,
+        shaders: stats.num_shaders,
+        textures: stats.num_textures,
+        uniforms: stats.num_uniforms,
+        vertex_buffers: stats.num_vertex_buffers,
+        vertex_layouts: stats.num_vertex_layouts,
+    }
+}
+
+/// Position and color, the layout of the cubes example.
+pub fn pos_color_layout() -> VertexLayoutBuilder {
+    let layout = VertexLayoutBuilder::new();
+    layout
+        .begin(RendererType::Noop)
+        .add(Attrib::Position, 3, AttribType::Float, AddArgs::default())
+        .add(
+            Attrib::Color0,
+            4,
+            AttribType::Uint8,
+            AddArgs {
+                normalized: true,
+                as_int: false,
+            },
+        )
+        .end();
+    layout
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct PosColor {
+    pub pos: [f32; 3],
+    pub abgr: u32,
+}
+
+pub const TRIANGLE: [PosColor; 3] = [
+    PosColor {
+        pos: [0.0, 1.0, 0.0],
+        abgr: 0xff0000ff,
+    },
+    PosColor {
+        pos: [-1.0, -1.0, 0.0],
+        abgr: 0xff00ff00,
+    },
+    PosColor {
+        pos: [1.0, -1.0, 0.0],
+        abgr: 0xffff0000,
+    },
+];
+
+pub const TRIANGLE_INDICES: [u16; 3] = [0, 1, 2];
+
+pub fn cubes_program() -> Program {
+    let vs = bgfx::create_shader(&Memory::copy(VS_CUBES));
+    let fs = bgfx::create_shader(&Memory::copy(FS_CUBES));
+    // The shaders are destroyed by their own handles, so the program must not destroy them.
+    bgfx::create_program(&vs, &fs, false)
+}
@@ -0,0 +1,255 @@
+//! Views, encoders, submission and the frame stats on the noop renderer.
+
+#![cfg(not(feature = "mock-only"))]
+
+mod common;
+
+use bgfx_rs::bgfx::{self, *};
+use bgfx_rs::{dbg_text, DebugTextImage, FrameProfiler, TextAttr};
+use common::{counts, Noop};
+
+#[test]
+fn frame_number_increases() {
+    let _noop = Noop::init();
+
+    let first = bgfx::frame(false);
+    let second = bgfx::frame(false);
+    assert!(second > first);
+}
+
+#[test]
+fn reset_changes_backbuffer_size() {
+    let _noop = Noop::init();
+
+    bgfx::reset(640, 480, ResetArgs::default());
+    bgfx::frame(false);
+
+    let stats = bgfx::get_stats();
+    assert_eq!((stats.width, stats.height), (640, 480));
+}
+
+#[test]
+fn views() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let fb = bgfx::create_frame_buffer(64, 64, TextureFormat::RGBA8, TextureFlags::RT.bits());
+    for view in 0..4 {
+        bgfx::set_view_clear(
+            view,
+            ClearFlags::COLOR.bits() | ClearFlags::DEPTH.bits(),
+            SetViewClearArgs {
+                rgba: 0x303030ff,
+                ..Default::default()
+            },
+        );
+        bgfx::set_view_rect(view, 0, 0, common::WIDTH as u16, common::HEIGHT as u16);
+        bgfx::set_view_mode(view, ViewMode::Sequential);
+        bgfx::set_view_transform(view, &IDENTITY, &IDENTITY);
+        bgfx::touch(view);
+    }
+    bgfx::set_view_frame_buffer(1, &fb);
+
+    // The frame buffer owns its color texture.
+    let rendered = counts();
+    assert_eq!(rendered.frame_buffers, before.frame_buffers + 1);
+    assert_eq!(rendered.textures, before.textures + 1);
+
+    for view in 0..4 {
+        bgfx::reset_view(view);
+    }
+    drop(fb);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn submit() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let layout = common::pos_color_layout();
+    let vb = bgfx::create_vertex_buffer(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let ib = bgfx::create_index_buffer(
+        &Memory::copy(&common::TRIANGLE_INDICES),
+        BufferFlags::NONE.bits(),
+    );
+    let buffers = counts();
+    assert_eq!(buffers.vertex_buffers, before.vertex_buffers + 1);
+    assert_eq!(buffers.index_buffers, before.index_buffers + 1);
+
+    // The shader handles are dropped inside `cubes_program`, the program keeps them alive.
+    let program = common::cubes_program();
+    let created = counts();
+    assert_eq!(created.programs, before.programs + 1);
+    assert_eq!(created.shaders, before.shaders + 2);
+
+    bgfx::set_view_rect(0, 0, 0, common::WIDTH as u16, common::HEIGHT as u16);
+    for _ in 0..3 {
+        bgfx::set_transform(&IDENTITY, 1);
+        bgfx::set_vertex_buffer(0, &vb, 0, u32::MAX);
+        bgfx::set_index_buffer(&ib, 0, u32::MAX);
+        bgfx::set_state(
+            StateWriteFlags::RGB.bits() | StateDepthTestFlags::LESS.bits(),
+            0,
+        );
+        bgfx::submit(0, &program, SubmitArgs::default());
+    }
+    // Submitting doesn't create or release anything.
+    assert_eq!(counts(), created);
+
+    drop(program);
+    let released = counts();
+    assert_eq!(released.programs, before.programs);
+    assert_eq!(released.shaders, before.shaders);
+
+    drop(vb);
+    drop(ib);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn transient_submit() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let layout = common::pos_color_layout();
+    let program = common::cubes_program();
+
+    let mut tvb = TransientVertexBuffer::new();
+    let mut tib = TransientIndexBuffer::new();
+    assert!(bgfx::alloc_transient_buffers(
+        &mut tvb, &layout, 3, &mut tib, 3, false
+    ));
+    assert_eq!((tvb.size, tvb.stride), (3 * 16, 16));
+    assert_eq!(tib.size, 3 * 2);
+
+    // The transient buffers count as used until the end of the frame.
+    let stats = bgfx::get_stats();
+    assert_eq!(stats.transient_vb_used, 3 * 16);
+    assert_eq!(stats.transient_ib_used, 3 * 2);
+
+    bgfx::set_transient_vertex_buffer(0, &tvb, 0, 3);
+    bgfx::set_transient_index_buffer(&tib, 0, 3);
+    bgfx::submit(0, &program, SubmitArgs::default());
+
+    let after = counts();
+    let stats = bgfx::get_stats();
+    assert_eq!((stats.transient_vb_used, stats.transient_ib_used), (0, 0));
+    // Transient buffers aren't handles of their own.
+    assert_eq!(after.vertex_buffers, before.vertex_buffers);
+    assert_eq!(after.index_buffers, before.index_buffers);
+
+    drop(program);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn encoders() {
+    let _noop = Noop::init();
+
+    let layout = common::pos_color_layout();
+    let vb = bgfx::create_vertex_buffer(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let program = common::cubes_program();
+
+    // An encoder for another thread is one more than the main thread's.
+    let encoder = bgfx::encoder_begin(true);
+    encoder.touch(0);
+    encoder.set_vertex_buffer(0, &vb, 0, u32::MAX);
+    encoder.set_state(StateWriteFlags::RGB.bits(), 0);
+    encoder.submit(0, &program, SubmitArgs::default());
+    bgfx::encoder_end(encoder);
+
+    // `counts` runs two frames, the stats are those of the frame the encoder was used in.
+    counts();
+    let stats = bgfx::get_stats();
+    assert_eq!(stats.encoders().len(), 2);
+    assert_eq!(stats.num_encoders, 2);
+
+    counts();
+    assert_eq!(bgfx::get_stats().encoders().len(), 1);
+}
+
+#[test]
+fn stats() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    bgfx::set_debug(DebugFlags::PROFILER.bits());
+    let texture = bgfx::create_texture_2d(
+        16,
+        16,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        0,
+        &Memory::copy(&[0u8; 16 * 16 * 4]),
+    );
+    let uniform = Uniform::create("u_color", UniformType::Vec4, 1);
+    bgfx::touch(0);
+
+    let created = counts();
+    assert_eq!(created.textures, before.textures + 1);
+    assert_eq!(created.uniforms, before.uniforms + 1);
+
+    let stats = bgfx::get_stats();
+    assert_eq!(
+        (stats.width, stats.height),
+        (common::WIDTH as u16, common::HEIGHT as u16)
+    );
+    assert!(stats.cpu_timer_freq > 0);
+
+    // The snapshot is an owned copy, it keeps the values of this frame.
+    let snapshot = stats.snapshot();
+    drop(texture);
+    drop(uniform);
+    assert_eq!(counts(), before);
+    assert_eq!(snapshot.num_textures, created.textures);
+    assert_eq!(snapshot.num_uniforms, created.uniforms);
+    assert_eq!(bgfx::get_stats().num_textures, before.textures);
+}
+
+#[test]
+fn profiler() {
+    let _noop = Noop::init();
+
+    bgfx::set_debug(DebugFlags::PROFILER.bits());
+    let mut profiler = FrameProfiler::new(4);
+    for _ in 0..6 {
+        bgfx::touch(0);
+        bgfx::frame(false);
+        profiler.sample();
+    }
+    assert_eq!(profiler.len(), 4);
+    assert_eq!(profiler.summary().frames, 4);
+
+    let mut trace = Vec::new();
+    profiler.write_chrome_trace(&mut trace).unwrap();
+    let trace = String::from_utf8(trace).unwrap();
+    assert!(trace.starts_with('{') && trace.trim_end().ends_with('}'));
+    assert!(trace.contains("\"Frame 2\""));
+}
+
+#[test]
+fn debug_text() {
+    let _noop = Noop::init();
+
+    bgfx::set_debug(DebugFlags::TEXT.bits());
+    bgfx::dbg_text_clear(DbgTextClearArgs::default());
+    bgfx::dbg_text(0, 0, 0x0f, "100% plain text");
+    dbg_text!(0, 1, TextAttr::default(), "formatted {} {:.1}", 1, 2.0);
+
+    let mut image = DebugTextImage::new(8, 2);
+    image.print(0, 0, TextAttr::default(), "image");
+    // Partly outside of the text area, clipped by `draw`.
+    image.draw(u16::MAX - 4, 0);
+    image.draw(0, 2);
+    bgfx::frame(false);
+}
@@ -0,0 +1,189 @@
+//! The API routed through a [MockBackend], no bgfx context involved.
+
+mod common;
+
+use std::rc::Rc;
+
+use bgfx_rs::bgfx::{self, *};
+use bgfx_rs::{dbg_text, set_backend, MockArg, MockBackend, TextAttr};
+
+fn alpha_blend() -> u64 {
+    let func = StateBlendFlags::SRC_ALPHA.bits() | (StateBlendFlags::INV_SRC_ALPHA.bits() << 4);
+    func | (func << 8)
+}
+
+#[test]
+fn submits() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let program = common::cubes_program();
+    let state = StateWriteFlags::RGB.bits() | alpha_blend();
+    bgfx::set_state(state, 0);
+    bgfx::submit(3, &program, SubmitArgs::default());
+
+    let encoder = bgfx::encoder_begin(false);
+    encoder.submit(1, &program, SubmitArgs { depth: 7, flags: 0 });
+    bgfx::encoder_end(encoder);
+
+    let submits = mock.submits();
+    assert_eq!(submits.len(), 2);
+    assert_eq!(submits[0].view, 3);
+    assert_eq!(submits[0].state, state);
+    // The state is reset by the submit, the encoder never set one.
+    assert_eq!(submits[1].view, 1);
+    assert_eq!(submits[1].depth, 7);
+    assert_eq!(submits[1].state, StateFlags::DEFAULT.bits());
+    assert_eq!(submits[0].program, submits[1].program);
+}
+
+#[test]
+fn resources() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let layout = common::pos_color_layout();
+    let vb = bgfx::create_vertex_buffer(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let ib = bgfx::create_index_buffer(
+        &Memory::copy(&common::TRIANGLE_INDICES),
+        BufferFlags::NONE.bits(),
+    );
+    drop(vb);
+    drop(ib);
+
+    let vertices = &mock.calls_to("create_vertex_buffer")[0].args[0];
+    let indices = &mock.calls_to("create_index_buffer")[0].args[0];
+    assert_eq!(
+        vertices.as_bytes().map(<[u8]>::len),
+        Some(std::mem::size_of_val(&common::TRIANGLE))
+    );
+    assert_eq!(indices.as_bytes(), Some(&[0, 0, 1, 0, 2, 0][..]));
+
+    // Handles are unique across types.
+    let vb = mock.calls_to("destroy_vertex_buffer")[0].args[0].as_handle();
+    let ib = mock.calls_to("destroy_index_buffer")[0].args[0].as_handle();
+    assert!(vb.is_some() && ib.is_some());
+    assert_ne!(vb, ib);
+}
+
+#[test]
+fn transient_buffers() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let layout = common::pos_color_layout();
+    let mut tvb = TransientVertexBuffer::new();
+    let mut tib = TransientIndexBuffer::new();
+    assert!(bgfx::alloc_transient_buffers(
+        &mut tvb, &layout, 3, &mut tib, 6, false
+    ));
+
+    assert_eq!(tvb.size, 3 * std::mem::size_of::<common::PosColor>() as u32);
+    assert_eq!(tib.size, 6 * 2);
+    assert!(!tvb.data.is_null() && !tib.data.is_null());
+}
+
+#[test]
+fn pure_calls() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let layout = VertexLayoutBuilder::new();
+    layout
+        .begin(RendererType::OpenGL)
+        .add(Attrib::Position, 3, AttribType::Float, AddArgs::default())
+        .skip(4)
+        .add(
+            Attrib::Color0,
+            3,
+            AttribType::Uint8,
+            AddArgs {
+                normalized: true,
+                as_int: false,
+            },
+        )
+        .end();
+    assert_eq!(layout.stride, 12 + 4 + 4);
+    assert_eq!(layout.offset[Attrib::Color0 as usize], 16);
+    assert!(layout.has(Attrib::Color0) && !layout.has(Attrib::Normal));
+
+    let (mut num, mut type_r, mut normalized, mut as_int) = (0, AttribType::Float, false, true);
+    layout.decode(
+        Attrib::Color0,
+        &mut num,
+        &mut type_r,
+        &mut normalized,
+        &mut as_int,
+    );
+    assert_eq!(
+        (num, type_r, normalized, as_int),
+        (3, AttribType::Uint8, true, false)
+    );
+
+    // Direct3D pads a single byte to 4
+    let padded = VertexLayoutBuilder::new();
+    padded
+        .begin(RendererType::Direct3D11)
+        .add(Attrib::Color0, 1, AttribType::Uint8, AddArgs::default())
+        .end();
+    assert_eq!(padded.stride, 4);
+    assert_ne!(padded.hash, layout.hash);
+
+    let mut info = TextureInfo::new();
+    bgfx::calc_texture_size(&mut info, 5, 5, 1, false, true, 1, TextureFormat::BC1);
+    let calc = TextureInfo::calc(5, 5, 1, false, true, 1, TextureFormat::BC1);
+    assert_eq!(
+        (info.width, info.num_mips, info.storage_size),
+        (8, 4, calc.storage_size)
+    );
+
+    let init = Init::new();
+    assert_eq!(init.type_r, RendererType::Count);
+    assert_eq!((init.resolution.width, init.resolution.height), (1280, 720));
+    assert!(init.limits.max_encoders > 0);
+    assert!(mock.calls().is_empty());
+}
+
+#[test]
+fn frames_and_debug_text() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    assert_eq!(bgfx::get_renderer_type(), RendererType::Noop);
+    dbg_text!(0, 1, TextAttr::default(), "frame {}", 1);
+    assert_eq!(bgfx::frame(false), 0);
+    assert_eq!(bgfx::frame(false), 1);
+
+    let text = &mock.calls_to("dbg_text")[0];
+    assert_eq!(text.args[3], MockArg::Str("frame 1".to_owned()));
+
+    mock.clear();
+    assert!(mock.calls().is_empty());
+}
+
+#[test]
+fn transforms() {
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let mut transform = Transform::new();
+    assert_eq!(bgfx::alloc_transform(&mut transform, 2), 0);
+    assert_eq!(transform.num, 2);
+    assert!(!transform.data.is_null());
+    let matrices = unsafe { std::slice::from_raw_parts(transform.data, 2 * 16) };
+    assert!(matrices.iter().all(|&value| value == 0.0));
+
+    let encoder = bgfx::encoder_begin(false);
+    let mut transform = Transform::new();
+    assert_eq!(encoder.alloc_transform(&mut transform, 1), 0);
+    assert_eq!(transform.num, 1);
+    assert!(!transform.data.is_null());
+    bgfx::encoder_end(encoder);
+
+    assert_eq!(mock.calls_to("alloc_transform")[0].args, [MockArg::Uint(2)]);
+    assert_eq!(mock.count("encoder_alloc_transform"), 1);
+}
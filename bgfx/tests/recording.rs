@@ -0,0 +1,255 @@
+//! Recording a frame and replaying it, both against a [MockBackend].
+
+mod common;
+
+use std::rc::Rc;
+
+use bgfx_rs::bgfx::{self, *};
+use bgfx_rs::{set_backend, Command, MockBackend, Recorder, Recording, Replayer};
+
+fn record() -> Recording {
+    let recorder = Rc::new(Recorder::with_backend(MockBackend::new()));
+    let _guard = set_backend(recorder.clone());
+
+    let layout = common::pos_color_layout();
+    let vb = bgfx::create_vertex_buffer(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let program = common::cubes_program();
+    let color = Uniform::create("u_color", UniformType::Vec4, 1);
+    bgfx::set_view_rect(3, 0, 0, 320, 240);
+    bgfx::frame(false);
+
+    recorder.capture_next_frame();
+    bgfx::frame(false);
+    assert!(recorder.take_recording().is_none());
+
+    bgfx::set_uniform(&color, &[1.0, 0.5, 0.0, 1.0], 1);
+    bgfx::set_vertex_buffer(0, &vb, 0, 3);
+    bgfx::set_state(StateWriteFlags::RGB.bits(), 0);
+    bgfx::submit(3, &program, SubmitArgs::default());
+    bgfx::frame(false);
+
+    recorder.take_recording().unwrap()
+}
+
+#[test]
+fn setup_and_frame() {
+    let recording = record();
+
+    assert!(recording
+        .setup
+        .iter()
+        .any(|command| matches!(command, Command::CreateVertexBuffer { data, .. } if data.len() == std::mem::size_of_val(&common::TRIANGLE))));
+    assert!(recording
+        .setup
+        .iter()
+        .any(|command| matches!(command, Command::SetViewRect { view: 3, .. })));
+    assert!(matches!(
+        recording.frame.last(),
+        Some(Command::Submit {
+            view: 3,
+            depth: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        &recording.frame[0],
+        Command::SetUniform { data, num: 1, .. } if data.len() == 16
+    ));
+}
+
+#[test]
+fn binary_round_trip() {
+    let recording = record();
+
+    let mut data = Vec::new();
+    recording.write(&mut data).unwrap();
+    assert_eq!(Recording::read(&mut data.as_slice()).unwrap(), recording);
+
+    data[0] = b'X';
+    assert!(Recording::read(&mut data.as_slice()).is_err());
+}
+
+#[test]
+fn replay() {
+    let recording = record();
+
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let mut replayer = Replayer::new(recording);
+    assert_eq!(mock.count("create_vertex_buffer"), 1);
+    assert_eq!(mock.count("create_program"), 1);
+    assert!(mock.submits().is_empty());
+
+    replayer.replay_frame();
+    replayer.replay_frame();
+    let submits = mock.submits();
+    assert_eq!(submits.len(), 2);
+    assert_eq!(submits[0].view, 3);
+    assert_eq!(submits[0].state, StateWriteFlags::RGB.bits());
+
+    drop(replayer);
+    assert_eq!(mock.count("destroy_program"), 1);
+}
+
+#[test]
+fn replay_skips_short_data() {
+    let recording = Recording {
+        setup: vec![Command::CreateUniform {
+            handle: 4,
+            name: "u_params".to_owned(),
+            uniform_type: UniformType::Vec4 as u32,
+            num: 2,
+        }],
+        frame: vec![
+            Command::SetUniform {
+                handle: 4,
+                data: vec![0; 16],
+                num: 2,
+            },
+            Command::SetUniform {
+                handle: 4,
+                data: vec![0; 16],
+                num: u16::MAX,
+            },
+            Command::SetUniform {
+                handle: 4,
+                data: vec![0; 32],
+                num: u16::MAX,
+            },
+            Command::SetViewTransform {
+                view: 0,
+                view_mtx: Some(vec![]),
+                proj_mtx: None,
+            },
+            Command::SetViewTransform {
+                view: 0,
+                view_mtx: Some(vec![1.0; 16]),
+                proj_mtx: Some(vec![1.0; 15]),
+            },
+            Command::SetViewTransform {
+                view: 0,
+                view_mtx: Some(vec![1.0; 16]),
+                proj_mtx: None,
+            },
+        ],
+    };
+
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let mut replayer = Replayer::new(recording);
+    replayer.replay_frame();
+    assert_eq!(mock.calls_to("set_uniform").len(), 1);
+    assert_eq!(mock.calls_to("set_view_transform").len(), 1);
+}
+
+#[test]
+fn replay_skips_short_texture_data() {
+    let rgba8 = TextureFormat::RGBA8 as u32;
+    let texture_2d = |handle, format, size, len| Command::CreateTexture2d {
+        handle,
+        width: size,
+        height: size,
+        has_mips: false,
+        num_layers: 1,
+        format,
+        flags: 0,
+        data: Some(vec![0; len]),
+    };
+    let update_2d = |handle, size, pitch, len| Command::UpdateTexture2d {
+        handle,
+        layer: 0,
+        mip: 0,
+        x: 0,
+        y: 0,
+        width: size,
+        height: size,
+        data: vec![0; len],
+        pitch,
+    };
+    let texture_3d = |handle, len| Command::CreateTexture3d {
+        handle,
+        width: 2,
+        height: 2,
+        depth: 2,
+        has_mips: false,
+        format: rgba8,
+        flags: 0,
+        data: Some(vec![0; len]),
+    };
+    let update_3d = |len| Command::UpdateTexture3d {
+        handle: 3,
+        mip: 0,
+        x: 0,
+        y: 0,
+        z: 0,
+        width: 2,
+        height: 2,
+        depth: 2,
+        data: vec![0; len],
+    };
+    let texture_cube = |handle, len| Command::CreateTextureCube {
+        handle,
+        size: 2,
+        has_mips: false,
+        num_layers: 1,
+        format: rgba8,
+        flags: 0,
+        data: Some(vec![0; len]),
+    };
+
+    let recording = Recording {
+        setup: vec![
+            texture_2d(1, rgba8, 4, 4 * 4 * 4 - 1),
+            texture_2d(1, rgba8, 4, 4 * 4 * 4),
+            // One 8 byte block per 4x4 texels.
+            texture_2d(2, TextureFormat::BC1 as u32, 8, 4 * 8 - 1),
+            texture_2d(2, TextureFormat::BC1 as u32, 8, 4 * 8),
+            texture_3d(3, 2 * 2 * 2 * 4 - 1),
+            texture_3d(3, 2 * 2 * 2 * 4),
+            texture_cube(4, 6 * 2 * 2 * 4 - 1),
+            texture_cube(4, 6 * 2 * 2 * 4),
+        ],
+        frame: vec![
+            update_2d(1, 2, u16::MAX, 2 * 2 * 4 - 1),
+            update_2d(1, 2, u16::MAX, 2 * 2 * 4),
+            // The last row only needs its texels, not the whole pitch.
+            update_2d(1, 2, 12, 12 + 8 - 1),
+            update_2d(1, 2, 12, 12 + 8),
+            update_2d(2, 4, u16::MAX, 8 - 1),
+            update_2d(2, 4, u16::MAX, 8),
+            update_3d(2 * 2 * 2 * 4 - 1),
+            update_3d(2 * 2 * 2 * 4),
+            Command::UpdateTextureCube {
+                handle: 4,
+                layer: 0,
+                side: 0,
+                mip: 0,
+                x: 0,
+                y: 0,
+                width: 2,
+                height: 2,
+                data: vec![0; 2 * 2 * 4 - 1],
+                pitch: u16::MAX,
+            },
+        ],
+    };
+
+    let mock = Rc::new(MockBackend::new());
+    let _guard = set_backend(mock.clone());
+
+    let mut replayer = Replayer::new(recording);
+    assert_eq!(mock.count("create_texture_2d"), 2);
+    assert_eq!(mock.count("create_texture_3d"), 1);
+    assert_eq!(mock.count("create_texture_cube"), 1);
+
+    replayer.replay_frame();
+    assert_eq!(mock.count("update_texture_2d"), 3);
+    assert_eq!(mock.count("update_texture_3d"), 1);
+    assert_eq!(mock.count("update_texture_cube"), 0);
+}
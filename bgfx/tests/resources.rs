@@ -0,0 +1,341 @@
+//! Creation and destruction of every handle type on the noop renderer, checked against the
+//! handle counts bgfx reports.
+
+#![cfg(not(feature = "mock-only"))]
+
+mod common;
+
+use bgfx_rs::bgfx::{self, *};
+use common::{counts, Noop};
+
+#[test]
+fn vertex_and_index_buffers() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let layout = common::pos_color_layout();
+    let vb = bgfx::create_vertex_buffer(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let ib = bgfx::create_index_buffer(
+        &Memory::copy(&common::TRIANGLE_INDICES),
+        BufferFlags::NONE.bits(),
+    );
+    vb.set_name("triangle");
+    ib.set_name("triangle");
+
+    let created = counts();
+    assert_eq!(created.vertex_buffers, before.vertex_buffers + 1);
+    assert_eq!(created.index_buffers, before.index_buffers + 1);
+    assert!(created.vertex_layouts > before.vertex_layouts);
+
+    drop(vb);
+    drop(ib);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn dynamic_buffers() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let layout = common::pos_color_layout();
+    let dvb = bgfx::create_dynamic_vertex_buffer(16, &layout, BufferFlags::ALLOW_RESIZE.bits());
+    let dvb_mem = bgfx::create_dynamic_vertex_buffer_mem(
+        &Memory::copy(&common::TRIANGLE),
+        &layout,
+        BufferFlags::NONE.bits(),
+    );
+    let dib = bgfx::create_dynamic_index_buffer(16, BufferFlags::NONE.bits());
+
+    bgfx::update_dynamic_vertex_buffer(&dvb, 0, &Memory::copy(&common::TRIANGLE));
+    bgfx::update_dynamic_index_buffer(&dib, 0, &Memory::copy(&common::TRIANGLE_INDICES));
+
+    let created = counts();
+    assert_eq!(
+        created.dynamic_vertex_buffers,
+        before.dynamic_vertex_buffers + 2
+    );
+    assert_eq!(
+        created.dynamic_index_buffers,
+        before.dynamic_index_buffers + 1
+    );
+
+    drop(dvb);
+    drop(dvb_mem);
+    drop(dib);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn indirect_buffer() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let indirect = bgfx::create_indirect_buffer(4);
+    counts();
+
+    drop(indirect);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn transient_buffers() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let layout = common::pos_color_layout();
+    assert!(bgfx::get_avail_transient_vertex_buffer(3, &layout) >= 3);
+
+    let mut tvb = TransientVertexBuffer::new();
+    let mut tib = TransientIndexBuffer::new();
+    assert!(bgfx::alloc_transient_buffers(
+        &mut tvb, &layout, 3, &mut tib, 3, false
+    ));
+
+    // Transient buffers live in the frame and don't create handles.
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn textures() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let pixels = [0xffu8; 4 * 4 * 4];
+    let immutable = bgfx::create_texture_2d(
+        4,
+        4,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        0,
+        &Memory::copy(&pixels),
+    );
+    // Without memory the texture is mutable.
+    let mutable = bgfx::create_texture_2d(4, 4, false, 1, TextureFormat::RGBA8, 0, &Memory::new());
+    bgfx::update_texture_2d(&mutable, 0, 0, 0, 0, 4, 4, &Memory::copy(&pixels), u16::MAX);
+    let cube = bgfx::create_texture_cube(
+        8,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        CreateTextureCubeArgs::default(),
+    );
+    immutable.set_name("immutable");
+
+    assert_eq!(counts().textures, before.textures + 3);
+
+    drop(immutable);
+    drop(mutable);
+    drop(cube);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn texture_size_matches_bgfx() {
+    let _noop = Noop::init();
+
+    // bgfx divides by the block size, which is 0 for the unknown formats.
+    let formats = TextureFormat::all()
+        .filter(|format| !matches!(format, TextureFormat::Unknown | TextureFormat::UnknownDepth));
+
+    // width, height, depth, cube map, mips, layers
+    let sizes = [
+        (1, 1, 1, false, true, 1),
+        (2, 2, 1, false, true, 1),
+        (5, 5, 1, false, true, 1),
+        (5, 5, 1, false, false, 1),
+        (37, 19, 1, false, true, 2),
+        (16, 16, 1, true, true, 1),
+        (5, 5, 1, true, true, 3),
+        (64, 32, 1, false, false, 6),
+        (8, 4, 6, false, true, 1),
+        (0, 0, 0, false, true, 1),
+    ];
+
+    for format in formats {
+        for (width, height, depth, cube_map, has_mips, num_layers) in sizes {
+            let mut info = TextureInfo::new();
+            bgfx::calc_texture_size(
+                &mut info, width, height, depth, cube_map, has_mips, num_layers, format,
+            );
+            let rust =
+                TextureInfo::calc(width, height, depth, cube_map, has_mips, num_layers, format);
+
+            let what = format!(
+                "{:?} {}x{}x{} cube {} mips {} layers {}",
+                format, width, height, depth, cube_map, has_mips, num_layers
+            );
+            assert_eq!(rust.format, info.format, "{}", what);
+            assert_eq!(rust.storage_size, info.storage_size, "{}", what);
+            assert_eq!(rust.width, info.width, "{}", what);
+            assert_eq!(rust.height, info.height, "{}", what);
+            assert_eq!(rust.depth, info.depth, "{}", what);
+            assert_eq!(rust.num_layers, info.num_layers, "{}", what);
+            assert_eq!(rust.num_mips, info.num_mips, "{}", what);
+            assert_eq!(rust.bits_per_pixel, info.bits_per_pixel, "{}", what);
+            assert_eq!(rust.cube_map, info.cube_map, "{}", what);
+        }
+    }
+
+    // Compressed formats are padded to whole blocks.
+    let bc1 = TextureInfo::calc(1, 1, 1, false, true, 1, TextureFormat::BC1);
+    assert_eq!((bc1.width, bc1.height, bc1.num_mips), (4, 4, 3));
+    assert_eq!(bc1.storage_size, 24);
+    let bc1 = TextureInfo::calc(5, 5, 1, false, false, 1, TextureFormat::BC1);
+    assert_eq!((bc1.width, bc1.height, bc1.storage_size), (8, 8, 32));
+    let d24 = TextureInfo::calc(4, 4, 1, false, false, 1, TextureFormat::D24);
+    assert_eq!((d24.bits_per_pixel, d24.storage_size), (24, 48));
+}
+
+#[test]
+fn frame_buffers() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    // Owns its color texture.
+    let fb = bgfx::create_frame_buffer(64, 64, TextureFormat::RGBA8, TextureFlags::RT.bits());
+    fb.set_name("offscreen");
+
+    let created = counts();
+    assert_eq!(created.frame_buffers, before.frame_buffers + 1);
+    assert_eq!(created.textures, before.textures + 1);
+
+    bgfx::set_view_frame_buffer(1, &fb);
+    bgfx::touch(1);
+    bgfx::frame(false);
+    bgfx::reset_view(1);
+
+    drop(fb);
+    assert_eq!(counts(), before);
+
+    // Attached to a texture it doesn't own.
+    let texture = bgfx::create_texture_2d(
+        64,
+        64,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        TextureFlags::RT.bits(),
+        &Memory::new(),
+    );
+    let fb = bgfx::create_frame_buffer_from_handles(1, &texture, false);
+
+    let created = counts();
+    assert_eq!(created.frame_buffers, before.frame_buffers + 1);
+    assert_eq!(created.textures, before.textures + 1);
+
+    drop(fb);
+    assert_eq!(counts().textures, before.textures + 1);
+
+    drop(texture);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn shaders_and_programs() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let vs = bgfx::create_shader(&Memory::copy(common::VS_CUBES));
+    let fs = bgfx::create_shader(&Memory::copy(common::FS_CUBES));
+    vs.set_name("vs_cubes");
+    let program = bgfx::create_program(&vs, &fs, false);
+
+    let created = counts();
+    assert_eq!(created.shaders, before.shaders + 2);
+    assert_eq!(created.programs, before.programs + 1);
+
+    drop(program);
+    drop(vs);
+    drop(fs);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn compute_program() {
+    let _noop = Noop::init();
+    if !bgfx::get_caps().supports(CapsFlags::COMPUTE) {
+        return;
+    }
+    let before = counts();
+
+    let cs = bgfx::create_shader(&Memory::copy(common::CS_UPDATE));
+    let program = bgfx::create_compute_program(&cs, false);
+
+    let created = counts();
+    assert_eq!(created.shaders, before.shaders + 1);
+    assert_eq!(created.programs, before.programs + 1);
+
+    drop(program);
+    drop(cs);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn uniforms() {
+    let _noop = Noop::init();
+    let before = counts();
+
+    let color = Uniform::create("u_color", UniformType::Vec4, 1);
+    let matrices = Uniform::create("u_matrices", UniformType::Mat4, 4);
+    // Creating a uniform with the same name returns the same handle.
+    let color_again = Uniform::create("u_color", UniformType::Vec4, 1);
+
+    let mut info = UniformInfo::new();
+    matrices.get_uniform_info(&mut info);
+    assert_eq!(info.type_r, UniformType::Mat4);
+    assert_eq!(info.num, 4);
+
+    color.set(&[1.0, 0.0, 0.0, 1.0], 1);
+    bgfx::set_uniform(&matrices, &[0.0; 64], u16::MAX);
+
+    assert_eq!(counts().uniforms, before.uniforms + 2);
+
+    drop(matrices);
+    drop(color_again);
+    assert_eq!(counts().uniforms, before.uniforms + 1);
+
+    drop(color);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn occlusion_queries() {
+    let _noop = Noop::init();
+    if !bgfx::get_caps().supports(CapsFlags::OCCLUSION_QUERY) {
+        return;
+    }
+    let before = counts();
+
+    let query = bgfx::create_occlusion_query();
+    assert_eq!(counts().occlusion_queries, before.occlusion_queries + 1);
+
+    drop(query);
+    assert_eq!(counts(), before);
+}
+
+#[test]
+fn reinit_starts_clean() {
+    let before = {
+        let _noop = Noop::init();
+        let before = counts();
+
+        // Leaked on purpose, shutdown releases it.
+        std::mem::forget(bgfx::create_dynamic_index_buffer(
+            16,
+            BufferFlags::NONE.bits(),
+        ));
+        assert_eq!(
+            counts().dynamic_index_buffers,
+            before.dynamic_index_buffers + 1
+        );
+        before
+    };
+
+    let _noop = Noop::init();
+    assert_eq!(counts(), before);
+}
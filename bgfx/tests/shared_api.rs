@@ -0,0 +1,14 @@
+//! Error paths of the `shared-api` feature, run with `--features shared-api`.
+
+#![cfg(feature = "shared-api")]
+
+use bgfx_rs::shared_api;
+use bgfx_rs::SharedApiError;
+
+#[test]
+fn null_interface() {
+    let error = unsafe { shared_api::set_interface(std::ptr::null_mut()) }.unwrap_err();
+    assert!(matches!(error, SharedApiError::NullInterface));
+    assert_eq!(error.to_string(), "the bgfx interface is null");
+    assert!(shared_api::interface().is_none());
+}
@@ -0,0 +1,235 @@
+//! The CPU side texture helpers built on bimg, they don't need bgfx to be initialized.
+
+use bgfx_rs::bgfx::TextureFormat;
+use bgfx_rs::{
+    convert_pixels, texture_memory, Cubemap, CubemapLayout, Image, MipChain, TextureEncoder,
+    TextureError,
+};
+
+/// RGBA32F image with every texel set to `color`.
+fn constant_image(size: u16, cube_map: bool, color: [f32; 4]) -> Image {
+    let faces = if cube_map { 6 } else { 1 };
+    let texel: Vec<u8> = color.iter().flat_map(|c| c.to_ne_bytes()).collect();
+    let data = texel.repeat(size as usize * size as usize * faces);
+    Image::from_data(
+        TextureFormat::RGBA32F,
+        size,
+        size,
+        1,
+        1,
+        cube_map,
+        false,
+        &data,
+    )
+    .unwrap()
+}
+
+fn texels(image: &Image) -> Vec<f32> {
+    image
+        .data()
+        .chunks_exact(4)
+        .map(|bytes| f32::from_ne_bytes(bytes.try_into().unwrap()))
+        .collect()
+}
+
+#[test]
+fn encoder_rejects_large_images() {
+    let encoder = TextureEncoder::new(TextureFormat::BC1).unwrap();
+
+    for (width, height) in [(65536, 4), (4, 65536), (u32::MAX, 1)] {
+        match encoder.encode_rgba8(&[], width, height) {
+            Err(TextureError::TooLarge {
+                width: w,
+                height: h,
+            }) => {
+                assert_eq!((w, h), (width, height))
+            }
+            other => panic!(
+                "expected TooLarge for {}x{}, got {:?}",
+                width, height, other
+            ),
+        }
+    }
+
+    match encoder.encode_rgba32f(&[0.0; 4], 65536, 1) {
+        Err(TextureError::TooLarge { .. }) => {}
+        other => panic!("expected TooLarge, got {:?}", other),
+    }
+}
+
+#[test]
+fn encoder_checks_pixel_len() {
+    let encoder = TextureEncoder::new(TextureFormat::BC7).unwrap();
+    match encoder.encode_rgba8(&[0; 4 * 4 * 4 - 1], 4, 4) {
+        Err(TextureError::InvalidData { expected, found }) => {
+            assert_eq!((expected, found), (64, 63))
+        }
+        other => panic!("expected InvalidData, got {:?}", other),
+    }
+
+    assert!(matches!(
+        TextureEncoder::new(TextureFormat::RGBA8),
+        Err(TextureError::UnsupportedEncoding(TextureFormat::RGBA8))
+    ));
+}
+
+#[test]
+fn encoder_rejects_pvrtc_sizes() {
+    for format in [TextureFormat::PTC14, TextureFormat::PTC14A] {
+        let encoder = TextureEncoder::new(format).unwrap();
+        for (width, height) in [(8, 4), (12, 12), (6, 6)] {
+            let pixels = vec![0; width as usize * height as usize * 4];
+            assert!(
+                matches!(
+                    encoder.encode_rgba8(&pixels, width, height),
+                    Err(TextureError::InvalidInput(_))
+                ),
+                "{:?} {}x{}",
+                format,
+                width,
+                height
+            );
+        }
+    }
+}
+
+#[test]
+fn encoder_round_trip() {
+    // Opaque, so formats without alpha decode it too. Only the channels a format stores are
+    // compared, f.e. red for BC4.
+    let color = [192u8, 96, 48, 255];
+    let size = 8;
+    let pixels = color.repeat(size * size);
+
+    for &format in TextureEncoder::FORMATS {
+        let encoder = TextureEncoder::new(format).unwrap();
+        let channels = match format {
+            TextureFormat::BC4 => 1,
+            TextureFormat::BC5 => 2,
+            _ => 4,
+        };
+
+        let decoded = if format == TextureFormat::BC6H {
+            let pixels: Vec<f32> = pixels.iter().map(|&c| c as f32 / 255.0).collect();
+            let data = encoder
+                .encode_rgba32f(&pixels, size as u32, size as u32)
+                .unwrap();
+            let image =
+                Image::from_data(format, size as u16, size as u16, 1, 1, false, false, &data)
+                    .unwrap();
+            texels(&image.convert(TextureFormat::RGBA32F).unwrap())
+        } else {
+            let data = encoder
+                .encode_rgba8(&pixels, size as u32, size as u32)
+                .unwrap();
+            assert_eq!(
+                data.len(),
+                encoder.encoded_size(size as u16, size as u16),
+                "{:?}",
+                format
+            );
+            let image =
+                Image::from_data(format, size as u16, size as u16, 1, 1, false, false, &data)
+                    .unwrap();
+            let image = image.convert(TextureFormat::RGBA8).unwrap();
+            image.data().iter().map(|&c| c as f32 / 255.0).collect()
+        };
+
+        assert_eq!(decoded.len(), size * size * 4, "{:?}", format);
+        for texel in decoded.chunks_exact(4) {
+            for (value, expected) in texel.iter().zip(color).take(channels) {
+                let expected = expected as f32 / 255.0;
+                assert!(
+                    (value - expected).abs() < 0.05,
+                    "{:?}: {:?} instead of {:?}",
+                    format,
+                    texel,
+                    color
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn cubemap_layout_detection() {
+    use CubemapLayout::*;
+
+    for (width, height, layout) in [
+        (512, 256, Some(LatLong)),
+        (768, 128, Some(Strip)),
+        (512, 384, Some(HorizontalCross)),
+        (384, 512, Some(VerticalCross)),
+        (256, 256, None),
+        (500, 256, None),
+        (0, 0, None),
+        (u32::MAX, u32::MAX / 2, None),
+    ] {
+        assert_eq!(
+            CubemapLayout::detect(width, height),
+            layout,
+            "{}x{}",
+            width,
+            height
+        );
+    }
+
+    assert!(Strip.fits(6, 1));
+    assert!(!Strip.fits(512, 384));
+}
+
+#[test]
+fn cubemap_rejects_other_images() {
+    let image = constant_image(4, false, [1.0; 4]);
+    assert!(matches!(
+        Cubemap::from_image(&image, CubemapLayout::HorizontalCross),
+        Err(TextureError::InvalidCubemap(_))
+    ));
+    assert!(matches!(
+        Cubemap::from_cube(image),
+        Err(TextureError::InvalidCubemap(_))
+    ));
+}
+
+#[test]
+fn irradiance_of_constant_cube() {
+    let color = [0.25, 0.5, 1.0, 1.0];
+    let cubemap = Cubemap::from_cube(constant_image(8, true, color)).unwrap();
+    assert_eq!(cubemap.size(), 8);
+
+    // Uniform radiance L gives an irradiance of pi * L, stored divided by pi.
+    let irradiance = cubemap.irradiance(4).unwrap();
+    assert!(irradiance.is_cube_map());
+    assert_eq!(irradiance.width(), 4);
+
+    let texels = texels(&irradiance);
+    assert_eq!(texels.len(), 4 * 4 * 6 * 4);
+    for texel in texels.chunks_exact(4) {
+        for (value, expected) in texel.iter().zip(color) {
+            assert!((value - expected).abs() < 1e-3, "{:?}", texel);
+        }
+    }
+}
+
+#[test]
+fn convert_rejects_large_images() {
+    match convert_pixels(&[], TextureFormat::RGBA8, TextureFormat::BGRA8, 70000, 2) {
+        Err(TextureError::TooLarge { width, height }) => assert_eq!((width, height), (70000, 2)),
+        other => panic!("expected TooLarge, got {:?}", other),
+    }
+}
+
+#[test]
+fn texture_memory_checks_surfaces() {
+    assert!(matches!(
+        texture_memory(&[]),
+        Err(TextureError::InvalidInput(_))
+    ));
+
+    let small = MipChain::single(TextureFormat::RGBA8, 1, 1, &[0; 4]);
+    let large = MipChain::single(TextureFormat::RGBA8, 2, 1, &[0; 8]);
+    assert!(matches!(
+        texture_memory(&[small, large]),
+        Err(TextureError::InvalidInput(_))
+    ));
+}
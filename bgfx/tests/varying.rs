@@ -0,0 +1,155 @@
+//! Parsing of the `varying.def.sc` files in `resources` and the vertex layout checks.
+
+use bgfx_rs::bgfx::*;
+use bgfx_rs::{shader_inputs, LayoutMismatch, VaryingDef, VaryingError};
+
+const RESOURCES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../resources");
+
+fn varying(name: &str) -> VaryingDef {
+    VaryingDef::from_file(format!("{}/{}/varying.def.sc", RESOURCES, name)).unwrap()
+}
+
+fn inputs(name: &str) -> Vec<String> {
+    let path = format!("{}/{}/vs_{}.sc", RESOURCES, name, name);
+    shader_inputs(&std::fs::read_to_string(path).unwrap())
+}
+
+fn layout(attribs: &[(Attrib, u8)]) -> VertexLayoutBuilder {
+    let layout = VertexLayoutBuilder::new();
+    layout.begin(RendererType::Noop);
+    for &(attrib, num) in attribs {
+        layout.add(attrib, num, AttribType::Float, AddArgs::default());
+    }
+    layout.end();
+    layout
+}
+
+#[test]
+fn parses_resources() {
+    for (name, components) in [("font", 2), ("image", 2), ("rect", 3), ("triangle", 2)] {
+        let varying = varying(name);
+        let position = varying.get("a_position").unwrap();
+        assert_eq!(position.attrib(), Some(Attrib::Position));
+        assert_eq!(position.components, components);
+    }
+
+    let font = varying("font");
+    assert_eq!(font.attributes().count(), 3);
+    assert_eq!(font.get("a_color0").unwrap().attrib(), Some(Attrib::Color0));
+    let color = font.get("v_color0").unwrap();
+    assert_eq!(color.attrib(), None);
+    assert_eq!(color.semantic, "COLOR0");
+    assert_eq!(color.default.as_deref(), Some("vec4(1.0, 0.0, 0.0, 1.0)"));
+    assert_eq!(inputs("font"), ["a_position", "a_color0", "a_texcoord0"]);
+}
+
+#[test]
+fn missing_color0() {
+    let font = varying("font");
+    let layout = layout(&[(Attrib::Position, 2), (Attrib::TexCoord0, 4)]);
+
+    let mismatches = font.check_layout(&layout, &inputs("font"));
+    assert_eq!(
+        mismatches,
+        [LayoutMismatch::MissingAttrib {
+            name: "a_color0".to_owned(),
+            attrib: Attrib::Color0,
+        }]
+    );
+    assert_eq!(
+        mismatches[0].to_string(),
+        "shader input `a_color0` expects Color0 but the layout has none"
+    );
+}
+
+#[test]
+fn matching_layout() {
+    let font = varying("font");
+    let layout = layout(&[
+        (Attrib::Position, 2),
+        (Attrib::Color0, 4),
+        (Attrib::TexCoord0, 4),
+    ]);
+    assert_eq!(font.check_layout(&layout, &inputs("font")), []);
+
+    let built = font.vertex_layout(&inputs("font")).unwrap();
+    assert_eq!(font.check_layout(&built, &[]), []);
+}
+
+#[test]
+fn component_count_and_undeclared() {
+    let font = varying("font");
+    let layout = layout(&[
+        (Attrib::Position, 2),
+        (Attrib::Color0, 3),
+        (Attrib::TexCoord0, 4),
+    ]);
+    assert_eq!(
+        font.check_layout(&layout, &[]),
+        [LayoutMismatch::ComponentCount {
+            name: "a_color0".to_owned(),
+            attrib: Attrib::Color0,
+            expected: 4,
+            found: 3,
+        }]
+    );
+
+    let inputs = ["a_position".to_owned(), "a_normal".to_owned()];
+    assert_eq!(
+        font.check_layout(&layout, &inputs),
+        [LayoutMismatch::Undeclared {
+            name: "a_normal".to_owned()
+        }]
+    );
+}
+
+#[test]
+fn every_mismatch() {
+    // Every mismatch is reported, in the order of the inputs.
+    let font = varying("font");
+    let inputs = [
+        "a_normal".to_owned(),
+        "a_color0".to_owned(),
+        "a_tangent".to_owned(),
+        "a_texcoord0".to_owned(),
+    ];
+    let layout = layout(&[(Attrib::Position, 2), (Attrib::Color0, 3)]);
+    assert_eq!(
+        font.check_layout(&layout, &inputs),
+        [
+            LayoutMismatch::Undeclared {
+                name: "a_normal".to_owned()
+            },
+            LayoutMismatch::ComponentCount {
+                name: "a_color0".to_owned(),
+                attrib: Attrib::Color0,
+                expected: 4,
+                found: 3,
+            },
+            LayoutMismatch::Undeclared {
+                name: "a_tangent".to_owned()
+            },
+            LayoutMismatch::MissingAttrib {
+                name: "a_texcoord0".to_owned(),
+                attrib: Attrib::TexCoord0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn parse_errors() {
+    let source = "vec2 a_position : POSITION; // position\n/* color */ vec4 a_color0 COLOR0;";
+    match VaryingDef::parse(source) {
+        Err(VaryingError::Parse { line, .. }) => assert_eq!(line, 2),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+
+    match VaryingDef::parse("flat mat4 a_position : POSITION;") {
+        Err(error @ VaryingError::Parse { line: 1, .. }) => assert_eq!(
+            error.to_string(),
+            "varying.def.sc:1: unknown type `mat4` for `a_position`"
+        ),
+        other => panic!("expected a parse error, got {:?}", other),
+    }
+}
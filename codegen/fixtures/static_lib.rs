@@ -0,0 +1,330 @@
+// Generated by `cargo run -p codegen` from bgfx.idl (API version 115), don't edit.
+// Hand-written parts go into `static_lib/`.
+
+use core::ffi::c_void;
+use std::mem::MaybeUninit;
+
+use crate::backend;
+
+mod uniforms;
+pub use uniforms::*;
+
+/// Renderer backend type enum.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RendererType {
+    /// No rendering.
+    Noop,
+    /// Direct3D 11.0
+    Direct3D11,
+    /// Vulkan
+    Vulkan,
+    /// Number of entries in the enum
+    Count,
+}
+
+/// Vertex attribute enum.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Attrib {
+    /// a_position
+    Position,
+    /// a_color0
+    Color0,
+    /// Number of entries in the enum
+    Count,
+}
+
+/// Vertex attribute type enum.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AttribType {
+    /// Uint8
+    Uint8,
+    /// Float
+    Float,
+    /// Number of entries in the enum
+    Count,
+}
+
+bitflags! {
+/// Color RGB/alpha/depth write. When it's not specified write will be disabled.
+    pub struct StateWriteFlags : u64 {
+        /// Enable R write.
+        const R = bgfx_sys::BGFX_STATE_WRITE_R as _;
+        /// Enable G write.
+        const G = bgfx_sys::BGFX_STATE_WRITE_G as _;
+        /// Enable B write.
+        const B = bgfx_sys::BGFX_STATE_WRITE_B as _;
+        /// Enable alpha write.
+        const A = bgfx_sys::BGFX_STATE_WRITE_A as _;
+        /// Enable depth write.
+        const Z = bgfx_sys::BGFX_STATE_WRITE_Z as _;
+        /// Enable RGB write.
+        const RGB = bgfx_sys::BGFX_STATE_WRITE_RGB as _;
+        /// Write all channels mask.
+        const MASK = bgfx_sys::BGFX_STATE_WRITE_MASK as _;
+    }
+}
+
+bitflags! {
+    pub struct ClearFlags : u16 {
+        /// No clear flags.
+        const NONE = bgfx_sys::BGFX_CLEAR_NONE as _;
+        /// Clear color.
+        const COLOR = bgfx_sys::BGFX_CLEAR_COLOR as _;
+        /// Clear depth.
+        const DEPTH = bgfx_sys::BGFX_CLEAR_DEPTH as _;
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct IndexBuffer {
+    handle: bgfx_sys::bgfx_index_buffer_handle_t,
+}
+
+#[derive(Clone, Debug)]
+pub struct Program {
+    handle: bgfx_sys::bgfx_program_handle_t,
+}
+
+#[derive(Clone, Debug)]
+pub struct Shader {
+    handle: bgfx_sys::bgfx_shader_handle_t,
+}
+
+#[derive(Clone, Debug)]
+pub struct VertexLayout {
+    handle: bgfx_sys::bgfx_vertex_layout_handle_t,
+}
+
+pub type ViewId = u16;
+
+pub struct AddArgs {
+    /// When using fixed point AttribType (f.e. Uint8)
+    /// value will be normalized for vertex shader usage. When normalized
+    /// is set to true, AttribType::Uint8 value in range 0-255 will be
+    /// in range 0.0-1.0 in vertex shader.
+    pub normalized: bool,
+    /// Packaging rule for vertexPack, vertexUnpack, and
+    /// vertexConvert for AttribType::Uint8 and AttribType::Int16.
+    /// Unpacking code must be implemented inside vertex shader.
+    pub as_int: bool,
+}
+
+impl Default for AddArgs {
+    fn default() -> AddArgs {
+        AddArgs {
+            normalized: false,
+            as_int: false,
+        }
+    }
+}
+
+pub struct SetViewClearArgs {
+    /// Color clear value.
+    pub rgba: u32,
+    /// Depth clear value.
+    pub depth: f32,
+    /// Stencil clear value.
+    pub stencil: u8,
+}
+
+impl Default for SetViewClearArgs {
+    fn default() -> SetViewClearArgs {
+        SetViewClearArgs {
+            rgba: 0x000000ff,
+            depth: 1.0,
+            stencil: 0,
+        }
+    }
+}
+
+/// Renderer capabilities.
+#[repr(C)]
+pub struct Caps {
+    /// Renderer backend type. See: `bgfx::RendererType`
+    pub renderer_type: RendererType,
+    /// Supported functionality.
+    ///
+    /// @attention See `BGFX_CAPS_*` flags.
+    pub supported: u64,
+    /// Rendering limits.
+    pub limits: CapsLimits,
+    /// Supported formats per renderer.
+    pub formats: [u16; 3usize],
+}
+/// Renderer runtime limits.
+#[repr(C)]
+pub struct CapsLimits {
+    /// Maximum number of draw calls.
+    pub max_draw_calls: u32,
+    /// Maximum number of views.
+    pub max_views: u32,
+}
+/// Vertex layout.
+#[repr(C)]
+pub struct VertexLayoutBuilder {
+    /// Hash.
+    pub hash: u32,
+    /// Stride.
+    pub stride: u16,
+    /// Attribute offsets.
+    pub offset: [u16; 2usize],
+}
+
+impl IndexBuffer {
+    /// Create static index buffer.
+    /// * `mem`:
+    /// Index buffer data.
+    /// * `flags`:
+    /// Buffer creation flags.
+    pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+    unsafe {
+            let _ret = backend::bgfx_create_index_buffer(mem.handle, flags);
+            IndexBuffer { handle: _ret }
+        }
+    }
+}
+
+impl Drop for IndexBuffer {
+    fn drop(&mut self) {
+        unsafe {
+            backend::bgfx_destroy_index_buffer(self.handle);
+        }
+    }
+}
+
+impl Shader {
+    /// Set shader debug name.
+    /// * `handle`:
+    /// Shader handle.
+    /// * `name`:
+    /// Shader name.
+    pub fn set_name(&self, name: &str) {
+    unsafe {
+            backend::bgfx_set_shader_name(self.handle, name.as_ptr() as _, name.len() as i32);
+        }
+    }
+}
+
+impl Caps {
+    pub fn new() -> Caps {
+        let t = MaybeUninit::<Caps>::zeroed();
+        let t = unsafe { t.assume_init() };
+        t
+    }
+}
+
+impl CapsLimits {
+    pub fn new() -> CapsLimits {
+        let t = MaybeUninit::<CapsLimits>::zeroed();
+        let t = unsafe { t.assume_init() };
+        t
+    }
+}
+
+impl VertexLayoutBuilder {
+    pub fn new() -> VertexLayoutBuilder {
+        let t = MaybeUninit::<VertexLayoutBuilder>::zeroed();
+        let t = unsafe { t.assume_init() };
+        t
+    }
+
+    /// Start VertexLayout.
+    /// * `renderer_type`:
+    /// Renderer backend type.
+    pub fn begin(&self, renderer_type: RendererType) -> &Self {
+    unsafe {
+            let _self = std::mem::transmute(self);
+            backend::bgfx_vertex_layout_begin(_self, renderer_type as _);
+            self
+        }
+    }
+    /// Add attribute to VertexLayout.
+    ///
+    /// @remarks Must be called between begin/end.
+    ///
+    /// * `attrib`:
+    /// Attribute semantics. See: `bgfx::Attrib`
+    /// * `num`:
+    /// Number of elements 1, 2, 3 or 4.
+    /// * `type_r`:
+    /// Element type.
+    /// * `normalized`:
+    /// When using fixed point AttribType (f.e. Uint8)
+    /// value will be normalized for vertex shader usage. When normalized
+    /// is set to true, AttribType::Uint8 value in range 0-255 will be
+    /// in range 0.0-1.0 in vertex shader.
+    /// * `as_int`:
+    /// Packaging rule for vertexPack, vertexUnpack, and
+    /// vertexConvert for AttribType::Uint8 and AttribType::Int16.
+    /// Unpacking code must be implemented inside vertex shader.
+    pub fn add(&self, attrib: Attrib, num: u8, type_r: AttribType, params: AddArgs) -> &Self {
+    unsafe {
+            let _self = std::mem::transmute(self);
+            backend::bgfx_vertex_layout_add(_self, attrib as _, num, type_r as _, params.normalized, params.as_int);
+            self
+        }
+    }
+}
+
+/// Returns renderer capabilities.
+///
+/// @remarks
+///   Library must be initialized.
+///
+pub fn get_caps() -> &'static Caps {
+    unsafe {
+        let _ret = backend::bgfx_get_caps();
+        std::mem::transmute(_ret)
+    }
+}
+/// Create static index buffer.
+/// * `mem`:
+/// Index buffer data.
+/// * `flags`:
+/// Buffer creation flags.
+pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+    unsafe {
+        let _ret = backend::bgfx_create_index_buffer(mem.handle, flags);
+        IndexBuffer { handle: _ret }
+    }
+}
+/// Set shader debug name.
+/// * `handle`:
+/// Shader handle.
+/// * `name`:
+/// Shader name.
+pub fn set_name(handle: &Shader, name: &str) {
+    unsafe {
+        backend::bgfx_set_shader_name(handle.handle, name.as_ptr() as _, name.len() as i32);
+    }
+}
+/// Set view clear flags.
+/// * `id`:
+/// View id.
+/// * `flags`:
+/// Clear flags. Use [ClearFlags::NONE] to remove any clear
+/// operation. See: [ClearFlags].
+/// * `rgba`:
+/// Color clear value.
+/// * `depth`:
+/// Depth clear value.
+/// * `stencil`:
+/// Stencil clear value.
+pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
+    unsafe {
+        backend::bgfx_set_view_clear(id, flags, params.rgba, params.depth, params.stencil);
+    }
+}
+/// Set render states for draw primitive.
+/// * `state`:
+/// State flags, f.e. [StateWriteFlags::RGB].
+/// * `rgba`:
+/// Sets blend factor used by `BGFX_STATE_BLEND_FACTOR`.
+pub fn set_state(state: u64, rgba: u32) {
+    unsafe {
+        backend::bgfx_set_state(state, rgba);
+    }
+}
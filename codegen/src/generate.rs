@@ -0,0 +1,1042 @@
+//! Emits `bgfx/src/static_lib.rs` from the parsed IDL.
+//!
+//! Everything goes through the `backend::bgfx_*` shims, so only functions listed in
+//! `for_each_function!` of `bgfx/src/backend.rs` are wrapped, new bgfx functions have to be
+//! added there first. Functions that need a hand-written wrapper are listed in [MANUAL_FUNCS],
+//! their wrappers live in `bgfx/src/static_lib/*.rs`, which are declared as child modules of the
+//! generated file so they can reach the private `handle` fields.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+use crate::idl::{Field, Func, Idl, Struct};
+
+/// Functions wrapped by hand in `bgfx/src/static_lib/`, f.e. because they take slices or
+/// strings, as `Class.name` for member functions.
+pub const MANUAL_FUNCS: &[&str] = &[
+    "copy",
+    "makeRef",
+    "createUniform",
+    "setUniform",
+    "Encoder.setUniform",
+    "setViewTransform",
+    "setTransform",
+    "dbgTextPrintf",
+    "dbgTextVprintf",
+];
+
+/// Structs defined by hand in `bgfx/src/static_lib/`.
+pub const MANUAL_STRUCTS: &[&str] = &["Memory"];
+
+/// Rust names of IDL structs, `VertexLayout` is the name of the handle.
+const RENAMES: &[(&str, &str)] = &[
+    ("Caps::Limits", "CapsLimits"),
+    ("VertexLayout", "VertexLayoutBuilder"),
+];
+
+/// Everything outside of the IDL the generator needs.
+pub struct Context {
+    /// bgfx-sys functions the backend routes, without the `bgfx_` prefix.
+    pub backend: HashSet<String>,
+    /// `BGFX_*` constants of bgfx-sys.
+    pub constants: Vec<String>,
+    /// Hand-written modules in `bgfx/src/static_lib/`.
+    pub modules: Vec<String>,
+}
+
+/// `numGPUs` -> `num_gp_us`, `index32` -> `index_32`, used for arguments, fields and flags.
+pub fn snake(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if i > 0 {
+            let prev = chars[i - 1];
+            let next = chars.get(i + 1).copied();
+            let boundary = if c.is_ascii_uppercase() {
+                prev.is_ascii_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_ascii_uppercase() && next.is_some_and(|n| n.is_ascii_lowercase()))
+            } else if c.is_ascii_digit() {
+                prev.is_ascii_alphabetic()
+            } else {
+                prev.is_ascii_digit()
+            };
+            if boundary {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_lowercase());
+    }
+    out
+}
+
+/// bgfx's own `camelcase_to_underscorecase`, `createTexture2D` -> `create_texture_2d`, used for
+/// the names of the C functions and constants.
+fn cname(name: &str) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut prev_lower = false;
+    for (i, c) in name.chars().enumerate() {
+        let lower = c.is_ascii_lowercase();
+        if i == 0 || (!lower && prev_lower) {
+            words.push(String::new());
+        }
+        words.last_mut().unwrap().push(c.to_ascii_lowercase());
+        prev_lower = lower;
+    }
+    words.join("_")
+}
+
+fn pascal(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn escape(name: String) -> String {
+    match name.as_str() {
+        "type" | "ref" | "fn" | "impl" | "mod" | "use" | "self" => name + "_r",
+        _ => name,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Ty {
+    Void,
+    Prim(&'static str),
+    Enum(String),
+    Handle(String),
+    HandleArray(String),
+    Memory,
+    /// `const char*` followed by an `int32_t` length.
+    Str,
+    CStr,
+    Struct(String, bool),
+    EnumOut(String),
+    PrimOut(&'static str),
+    ConstVoid,
+    MutVoid,
+    /// `VertexLayout&` returned by the builder functions.
+    This,
+}
+
+impl Ty {
+    fn rust(&self) -> String {
+        match self {
+            Ty::Void => "()".into(),
+            Ty::Prim(prim) => prim.to_string(),
+            Ty::Enum(name) => name.clone(),
+            Ty::Handle(name) | Ty::HandleArray(name) | Ty::Struct(name, false) => {
+                format!("&{}", name)
+            }
+            Ty::Memory => "&Memory".into(),
+            Ty::Str => "&str".into(),
+            Ty::CStr => "&i8".into(),
+            Ty::Struct(name, true) | Ty::EnumOut(name) => format!("&mut {}", name),
+            Ty::PrimOut(prim) => format!("&mut {}", prim),
+            Ty::ConstVoid => "&c_void".into(),
+            Ty::MutVoid => "*mut c_void".into(),
+            Ty::This => "&Self".into(),
+        }
+    }
+}
+
+fn primitive(name: &str) -> Option<&'static str> {
+    Some(match name {
+        "bool" => "bool",
+        "char" | "int8_t" => "i8",
+        "uint8_t" => "u8",
+        "int16_t" => "i16",
+        "uint16_t" => "u16",
+        "int32_t" | "int" => "i32",
+        "uint32_t" => "u32",
+        "int64_t" => "i64",
+        "uint64_t" => "u64",
+        "float" => "f32",
+        "double" => "f64",
+        "size_t" | "uintptr_t" => "usize",
+        "ViewId" => "ViewId",
+        _ => return None,
+    })
+}
+
+struct FlagInfo {
+    name: String,
+    ty: &'static str,
+    prefix: String,
+    // Rust name and bgfx-sys constant of every member.
+    members: Vec<(String, String)>,
+}
+
+pub struct Generator<'a> {
+    idl: &'a Idl,
+    context: &'a Context,
+    enums: HashMap<String, usize>,
+    handles: HashSet<String>,
+    structs: HashMap<String, String>,
+    flags: Vec<FlagInfo>,
+    out: String,
+}
+
+impl<'a> Generator<'a> {
+    pub fn new(idl: &'a Idl, context: &'a Context) -> Generator<'a> {
+        let enums = idl
+            .enums
+            .iter()
+            .map(|e| (e.name.clone(), e.members.len()))
+            .collect();
+        let handles = idl
+            .handles
+            .iter()
+            .map(|handle| handle.trim_end_matches("Handle").to_string())
+            .collect();
+        let structs = idl
+            .structs
+            .iter()
+            .map(|s| (qualified(s), struct_name(s)))
+            .collect();
+
+        let constants: HashMap<String, &String> = context
+            .constants
+            .iter()
+            .map(|constant| (constant.replace('_', ""), constant))
+            .collect();
+        let flags = idl
+            .flags
+            .iter()
+            .filter(|flag| !flag.members.is_empty())
+            .map(|flag| {
+                let prefix = format!("BGFX_{}_", cname(&flag.name).to_uppercase());
+                let members = flag
+                    .members
+                    .iter()
+                    .map(|member| {
+                        let constant = format!("{}{}", prefix, cname(&member.name).to_uppercase());
+                        // bgfx isn't consistent about underscores around digits, take the
+                        // spelling bgfx-sys has.
+                        let constant = constants
+                            .get(&constant.replace('_', ""))
+                            .map_or(constant, |constant| constant.to_string());
+                        (snake(&member.name).to_uppercase(), constant)
+                    })
+                    .collect();
+                FlagInfo {
+                    name: format!("{}Flags", flag.name),
+                    ty: match flag.bits() {
+                        8 => "u8",
+                        16 => "u16",
+                        64 => "u64",
+                        _ => "u32",
+                    },
+                    prefix,
+                    members,
+                }
+            })
+            .collect();
+
+        Generator {
+            idl,
+            context,
+            enums,
+            handles,
+            structs,
+            flags,
+            out: String::new(),
+        }
+    }
+
+    pub fn generate(mut self) -> String {
+        writeln!(
+            self.out,
+            "// Generated by `cargo run -p codegen` from bgfx.idl (API version {}), don't edit.\n\
+             // Hand-written parts go into `static_lib/`.\n",
+            self.idl.version
+        )
+        .unwrap();
+        self.out.push_str(
+            "use core::ffi::c_void;\nuse std::mem::MaybeUninit;\n\nuse crate::backend;\n",
+        );
+        for module in &self.context.modules {
+            writeln!(self.out, "\nmod {0};\npub use {0}::*;", module).unwrap();
+        }
+
+        self.enums();
+        self.bitflags();
+        self.handles();
+        self.out.push_str("\npub type ViewId = u16;\n");
+
+        let funcs: Vec<(&Func, Vec<Arg>, Ty)> = self
+            .idl
+            .funcs
+            .iter()
+            .filter_map(|func| self.wrapped(func).map(|(args, ret)| (func, args, ret)))
+            .collect();
+
+        self.args_structs(&funcs);
+        self.structs();
+        self.handle_impls(&funcs);
+        self.struct_impls(&funcs);
+
+        // Overloads like `setName` only make sense as methods of their handle.
+        let mut names: HashMap<&str, usize> = HashMap::new();
+        for (func, ..) in funcs.iter().filter(|(func, ..)| func.class.is_none()) {
+            *names.entry(&func.name).or_default() += 1;
+        }
+        self.out.push('\n');
+        for (func, args, ret) in &funcs {
+            if func.class.is_none() && names[func.name.as_str()] == 1 && !is_destroy(func, args) {
+                self.function(func, args, ret, Receiver::None, "");
+            }
+        }
+
+        self.out
+    }
+
+    fn enums(&mut self) {
+        for e in &self.idl.enums {
+            self.out.push('\n');
+            let docs = self.docs(&e.doc);
+            doc(&mut self.out, "", &docs);
+            writeln!(
+                self.out,
+                "#[repr(u32)]\n#[derive(Clone, Copy, PartialEq, Debug)]\npub enum {} {{",
+                e.name
+            )
+            .unwrap();
+            for member in &e.members {
+                let docs = self.docs(&member.doc);
+                doc(&mut self.out, "    ", &docs);
+                writeln!(self.out, "    {},", member.name).unwrap();
+            }
+            // `()` at the end of the declaration adds `Count`.
+            self.out
+                .push_str("    /// Number of entries in the enum\n    Count,\n}\n");
+        }
+    }
+
+    fn bitflags(&mut self) {
+        for flag in self
+            .idl
+            .flags
+            .iter()
+            .filter(|flag| !flag.members.is_empty())
+        {
+            let info = self.flag(&format!("{}Flags", flag.name)).unwrap();
+            let mut out = String::from("\nbitflags! {\n");
+            doc(&mut out, "", &self.docs(&flag.doc));
+            writeln!(out, "    pub struct {} : {} {{", info.name, info.ty).unwrap();
+            for (member, (name, constant)) in flag.members.iter().zip(&info.members) {
+                doc(&mut out, "        ", &self.docs(&member.doc));
+                writeln!(out, "        const {} = bgfx_sys::{} as _;", name, constant).unwrap();
+            }
+            out.push_str("    }\n}\n");
+            self.out.push_str(&out);
+        }
+    }
+
+    fn handles(&mut self) {
+        for handle in &self.idl.handles {
+            let name = handle.trim_end_matches("Handle");
+            writeln!(
+                self.out,
+                "\n#[derive(Clone, Debug)]\npub struct {} {{\n    handle: bgfx_sys::bgfx_{}_handle_t,\n}}",
+                name,
+                cname(name)
+            )
+            .unwrap();
+        }
+    }
+
+    fn structs(&mut self) {
+        // The C structs follow each other without blank lines.
+        self.out.push('\n');
+        for s in &self.idl.structs {
+            let name = struct_name(s);
+            if MANUAL_STRUCTS.contains(&name.as_str()) {
+                continue;
+            }
+            let docs = self.docs(&s.doc);
+            doc(&mut self.out, "", &docs);
+            writeln!(self.out, "#[repr(C)]\npub struct {} {{", name).unwrap();
+            for field in &s.fields {
+                let docs = self.docs(&field.doc);
+                doc(&mut self.out, "    ", &docs);
+                writeln!(
+                    self.out,
+                    "    pub {}: {},",
+                    escape(snake(&field.name)),
+                    self.field_type(s, &field.ty)
+                )
+                .unwrap();
+            }
+            self.out.push_str("}\n");
+        }
+    }
+
+    fn field_type(&self, owner: &Struct, ty: &str) -> String {
+        let ty = ty.trim();
+        if let Some((base, len)) = ty.strip_suffix(']').and_then(|ty| ty.split_once('[')) {
+            let len = match len.strip_suffix("::Count") {
+                Some(e) => self.enums.get(e).copied().unwrap_or(0).to_string(),
+                None => len.to_string(),
+            };
+            return format!("[{}; {}usize]", self.field_type(owner, base), len);
+        }
+        if let Some(base) = ty.strip_suffix('*') {
+            let base = base.trim().trim_start_matches("const ").trim();
+            let pointee = primitive(base)
+                .map(str::to_string)
+                .or_else(|| self.struct_type(owner, base))
+                .unwrap_or_else(|| "c_void".to_string());
+            return format!("*const {}", pointee);
+        }
+        if let Some(e) = ty.strip_suffix("::Enum") {
+            return e.to_string();
+        }
+        if let Some(prim) = primitive(ty) {
+            return prim.to_string();
+        }
+        let handle = ty.trim_end_matches("Handle");
+        if self.handles.contains(handle) {
+            return handle.to_string();
+        }
+        self.struct_type(owner, ty)
+            .unwrap_or_else(|| panic!("unknown type `{}` in struct {}", ty, owner.name))
+    }
+
+    // Nested structs are referred to by their short name inside of their namespace.
+    fn struct_type(&self, owner: &Struct, name: &str) -> Option<String> {
+        let nested = format!("{}::{}", owner.name, name);
+        self.structs
+            .get(&nested)
+            .or_else(|| self.structs.get(name))
+            .cloned()
+    }
+
+    fn arg_type(&self, ty: &str) -> Option<Ty> {
+        let ty = ty.trim();
+        let is_const = ty.starts_with("const ");
+        let base = ty.trim_start_matches("const ");
+        let (base, indirect) = match base.strip_suffix(['*', '&']) {
+            Some(base) => (base.trim(), true),
+            None => (base, false),
+        };
+
+        if base == "void" {
+            return Some(match (indirect, is_const) {
+                (false, _) => Ty::Void,
+                (true, true) => Ty::ConstVoid,
+                (true, false) => Ty::MutVoid,
+            });
+        }
+        if base == "char" && indirect && is_const {
+            return Some(Ty::CStr);
+        }
+        if let Some(prim) = primitive(base) {
+            return match (indirect, is_const) {
+                (false, _) => Some(Ty::Prim(prim)),
+                (true, false) => Some(Ty::PrimOut(prim)),
+                (true, true) => None,
+            };
+        }
+        if let Some(e) = base.strip_suffix("::Enum") {
+            return match (indirect, is_const) {
+                (false, _) => Some(Ty::Enum(e.to_string())),
+                (true, false) => Some(Ty::EnumOut(e.to_string())),
+                (true, true) => None,
+            };
+        }
+        let handle = base.trim_end_matches("Handle");
+        if self.handles.contains(handle) && base != handle {
+            return match (indirect, is_const) {
+                (false, _) => Some(Ty::Handle(handle.to_string())),
+                (true, true) => Some(Ty::HandleArray(handle.to_string())),
+                (true, false) => None,
+            };
+        }
+        if base == "Memory" && indirect {
+            return Some(Ty::Memory);
+        }
+        match self.structs.get(base) {
+            Some(name) if indirect => Some(Ty::Struct(name.clone(), !is_const)),
+            _ => None,
+        }
+    }
+
+    // Argument and return types of a function that can be wrapped.
+    fn wrapped<'f>(&self, func: &'f Func) -> Option<(Vec<Arg<'f>>, Ty)> {
+        let key = match &func.class {
+            Some(class) => format!("{}.{}", class, func.name),
+            None => func.name.clone(),
+        };
+        if MANUAL_FUNCS.contains(&key.as_str())
+            || func.attrs.has("cpponly")
+            || func.attrs.get("vararg").is_some()
+            || !self.context.backend.contains(&c_function(func))
+        {
+            return None;
+        }
+
+        let ret = if func.class.is_some() && func.ret.ends_with('&') {
+            Ty::This
+        } else {
+            self.arg_type(&func.ret)?
+        };
+        let mut args = Vec::new();
+        let mut iter = func.args.iter().peekable();
+        while let Some(arg) = iter.next() {
+            let ty = self.arg_type(&arg.ty)?;
+            if ty == Ty::CStr && iter.peek().is_some_and(|next| next.ty == "int32_t") {
+                iter.next();
+                args.push((arg, Ty::Str));
+            } else {
+                args.push((arg, ty));
+            }
+        }
+        Some((args, ret))
+    }
+
+    fn flag(&self, name: &str) -> Option<&FlagInfo> {
+        self.flags.iter().find(|flag| flag.name == name)
+    }
+
+    // The flag and member a `BGFX_*` constant belongs to.
+    fn constant(&self, constant: &str) -> Option<(&FlagInfo, Option<&str>)> {
+        let flag = self
+            .flags
+            .iter()
+            .filter(|flag| {
+                constant.starts_with(&flag.prefix)
+                    || constant == &flag.prefix[..flag.prefix.len() - 1]
+            })
+            .max_by_key(|flag| flag.prefix.len())?;
+        let member = flag
+            .members
+            .iter()
+            .find(|(_, c)| c == constant || c.replace('_', "") == constant.replace('_', ""))
+            .map(|(name, _)| name.as_str());
+        Some((flag, member))
+    }
+
+    // Links `BGFX_*` constants in the IDL's doc comments to the generated flags.
+    fn docs(&self, lines: &[String]) -> Vec<String> {
+        lines.iter().map(|line| self.doc_line(line)).collect()
+    }
+
+    fn doc_line(&self, line: &str) -> String {
+        let mut out = String::new();
+        let mut rest = line;
+        while let Some(start) = rest.find("BGFX_") {
+            let (before, from) = rest.split_at(start);
+            let len = from
+                .find(|c: char| !(c.is_ascii_uppercase() || c.is_ascii_digit() || c == '_'))
+                .unwrap_or(from.len());
+            let (constant, mut after) = from.split_at(len);
+            let mut suffix = "";
+            if after.starts_with('*') {
+                after = &after[1..];
+                suffix = "*";
+            } else if after.starts_with('[') {
+                let end = after.find(']').map_or(after.len(), |end| end + 1);
+                suffix = &after[..end];
+                after = &after[end..];
+            }
+
+            let link = match self.constant(constant) {
+                Some((flag, Some(member))) if suffix.is_empty() => {
+                    Some(format!("[{}::{}]", flag.name, member))
+                }
+                Some((flag, _)) if constant.len() + 1 >= flag.prefix.len() && suffix == "*" => {
+                    Some(format!("[{}]", flag.name))
+                }
+                Some((flag, _)) if constant.len() >= flag.prefix.len() => Some(format!(
+                    "[{}::{}{}]",
+                    flag.name,
+                    &constant[flag.prefix.len()..],
+                    suffix
+                )),
+                _ => None,
+            };
+            match link {
+                Some(link) => {
+                    let before = before.strip_suffix('`').unwrap_or(before);
+                    out.push_str(before);
+                    out.push_str(&link);
+                    rest = after.strip_prefix('`').unwrap_or(after);
+                }
+                None => {
+                    out.push_str(before);
+                    out.push_str(constant);
+                    out.push_str(suffix);
+                    rest = after;
+                }
+            }
+        }
+        out.push_str(rest);
+        out
+    }
+
+    // Two or more trailing arguments with defaults go into a `*Args` struct implementing
+    // `Default`, a single one stays a plain argument.
+    fn defaults<'f>(&self, args: &[Arg<'f>]) -> Vec<(&'f Field, Ty, String)> {
+        let mut defaults = Vec::new();
+        for (arg, ty) in args.iter().rev() {
+            match arg
+                .default()
+                .and_then(|value| self.default_value(ty, value))
+            {
+                Some((ty, value)) => defaults.push((*arg, ty, value)),
+                None => break,
+            }
+        }
+        if defaults.len() < 2 {
+            return Vec::new();
+        }
+        defaults.reverse();
+        defaults
+    }
+
+    fn default_value(&self, ty: &Ty, value: &str) -> Option<(Ty, String)> {
+        let value = value.trim();
+        match ty {
+            Ty::Memory if value == "NULL" => Some((Ty::Memory, "None".into())),
+            Ty::Enum(_) => Some((ty.clone(), value.to_string())),
+            Ty::Prim(prim) => {
+                let value = if let Some(max) = value.strip_suffix("_MAX") {
+                    format!("{}::MAX", primitive(&format!("{}_t", max.to_lowercase()))?)
+                } else if value.starts_with("BGFX_") {
+                    let parts: Vec<&str> = value.split('|').map(str::trim).collect();
+                    let mut bits = Vec::new();
+                    for part in &parts {
+                        let (flag, member) = self.constant(part)?;
+                        let cast = parts.len() > 1 || flag.ty != *prim;
+                        bits.push(format!(
+                            "{}::{}.bits(){}",
+                            flag.name,
+                            member?,
+                            if cast {
+                                format!(" as {}", prim)
+                            } else {
+                                String::new()
+                            }
+                        ));
+                    }
+                    bits.join(" | ")
+                } else if *prim == "f32" || *prim == "f64" {
+                    let value = value.trim_end_matches('f');
+                    if value.contains('.') {
+                        value.to_string()
+                    } else {
+                        format!("{}.0", value)
+                    }
+                } else {
+                    value.to_string()
+                };
+                Some((ty.clone(), value))
+            }
+            _ => None,
+        }
+    }
+
+    fn args_structs(&mut self, funcs: &[(&Func, Vec<Arg>, Ty)]) {
+        let mut done = HashSet::new();
+        for (func, args, _) in funcs {
+            let defaults = self.defaults(args);
+            let name = format!("{}Args", pascal(&func.name));
+            if defaults.is_empty() || !done.insert(name.clone()) {
+                continue;
+            }
+
+            let mut out = format!("\npub struct {} {{\n", name);
+            let mut default = String::new();
+            for (arg, ty, value) in &defaults {
+                let field = escape(snake(&arg.name));
+                let ty = match ty {
+                    Ty::Memory => "Option<Memory>".to_string(),
+                    ty => ty.rust(),
+                };
+                doc(&mut out, "    ", &self.docs(&arg.doc));
+                writeln!(out, "    pub {}: {},", field, ty).unwrap();
+                writeln!(default, "            {}: {},", field, value).unwrap();
+            }
+            write!(
+                out,
+                "}}\n\nimpl Default for {0} {{\n    fn default() -> {0} {{\n        {0} {{\n{1}        }}\n    }}\n}}\n",
+                name, default
+            )
+            .unwrap();
+            self.out.push_str(&out);
+        }
+    }
+
+    fn handle_impls(&mut self, funcs: &[(&Func, Vec<Arg>, Ty)]) {
+        for handle in self.idl.handles.clone() {
+            let name = handle.trim_end_matches("Handle").to_string();
+            let this = Ty::Handle(name.clone());
+            let mut body = String::new();
+            let mut destroy = None;
+            for (func, args, ret) in funcs.iter().filter(|(func, ..)| func.class.is_none()) {
+                if is_destroy(func, args) {
+                    if args[0].1 == this {
+                        destroy = Some(c_function(func));
+                    }
+                } else if *ret == this {
+                    self.function_into(&mut body, func, args, ret, Receiver::None, "    ");
+                } else if args.first().map(|(_, ty)| ty) == Some(&this) {
+                    self.function_into(&mut body, func, args, ret, Receiver::Handle, "    ");
+                }
+            }
+
+            if !body.is_empty() {
+                writeln!(self.out, "\nimpl {} {{\n{}}}", name, body).unwrap();
+            }
+            if let Some(destroy) = destroy {
+                writeln!(
+                    self.out,
+                    "\nimpl Drop for {} {{\n    fn drop(&mut self) {{\n        unsafe {{\n            backend::bgfx_{}(self.handle);\n        }}\n    }}\n}}",
+                    name, destroy
+                )
+                .unwrap();
+            }
+        }
+    }
+
+    fn struct_impls(&mut self, funcs: &[(&Func, Vec<Arg>, Ty)]) {
+        for s in &self.idl.structs {
+            let name = struct_name(s);
+            if MANUAL_STRUCTS.contains(&name.as_str()) {
+                continue;
+            }
+            let ctor = format!("{}_ctor", cname(&s.name));
+            let ctor = if s.attrs.has("ctor") && self.context.backend.contains(&ctor) {
+                format!(
+                    "        unsafe {{\n            let _this = std::mem::transmute(&t);\n            backend::bgfx_{}(_this);\n        }}\n",
+                    ctor
+                )
+            } else {
+                String::new()
+            };
+            let mut body = format!(
+                "    pub fn new() -> {0} {{\n        let t = MaybeUninit::<{0}>::zeroed();\n        let t = unsafe {{ t.assume_init() }};\n{1}        t\n    }}\n",
+                name, ctor
+            );
+
+            let mut first = true;
+            for (func, args, ret) in funcs {
+                if func.class.as_deref() == Some(s.name.as_str()) {
+                    if first {
+                        body.push('\n');
+                        first = false;
+                    }
+                    self.function_into(&mut body, func, args, ret, Receiver::This, "    ");
+                }
+            }
+            writeln!(self.out, "\nimpl {} {{\n{}}}", name, body).unwrap();
+        }
+    }
+
+    fn function(&mut self, func: &Func, args: &[Arg], ret: &Ty, receiver: Receiver, indent: &str) {
+        let mut out = String::new();
+        self.function_into(&mut out, func, args, ret, receiver, indent);
+        self.out.push_str(&out);
+    }
+
+    fn function_into(
+        &self,
+        out: &mut String,
+        func: &Func,
+        args: &[Arg],
+        ret: &Ty,
+        receiver: Receiver,
+        indent: &str,
+    ) {
+        let defaults = self.defaults(args);
+        let explicit = args.len() - defaults.len();
+
+        let mut docs = self.docs(&func.doc);
+        for (arg, _) in args {
+            docs.push(format!("* `{}`:", escape(snake(&arg.name))));
+            docs.extend(self.docs(&arg.doc));
+        }
+        doc(out, indent, &docs);
+
+        let mut params = Vec::new();
+        let mut prelude = Vec::new();
+        let mut call = Vec::new();
+        match receiver {
+            Receiver::None => {}
+            Receiver::Handle => params.push("&self".to_string()),
+            Receiver::This => {
+                params.push("&self".to_string());
+                prelude.push("let _self = std::mem::transmute(self);".to_string());
+                call.push("_self".to_string());
+            }
+        }
+
+        for (i, (arg, ty)) in args.iter().enumerate() {
+            let name = escape(snake(&arg.name));
+            if i >= explicit {
+                let field = format!("params.{}", name);
+                match ty {
+                    Ty::Memory => {
+                        prelude.push(format!(
+                            "let _{} = if let Some(h) = {} {{ h.handle }} else {{ std::ptr::null() }};",
+                            name, field
+                        ));
+                        call.push(format!("_{}", name));
+                    }
+                    Ty::Enum(_) => call.push(format!("{} as _", field)),
+                    _ => call.push(field),
+                }
+                continue;
+            }
+            if i == 0 && receiver == Receiver::Handle {
+                call.push("self.handle".to_string());
+                continue;
+            }
+
+            params.push(format!("{}: {}", name, ty.rust()));
+            match ty {
+                Ty::Enum(_) | Ty::MutVoid => call.push(format!("{} as _", name)),
+                Ty::Handle(_) | Ty::Memory => call.push(format!("{}.handle", name)),
+                Ty::HandleArray(_) => call.push(format!("&{}.handle", name)),
+                Ty::Str => call.push(format!("{0}.as_ptr() as _, {0}.len() as i32", name)),
+                Ty::Struct(..) | Ty::EnumOut(_) => {
+                    prelude.push(format!("let _{0} = std::mem::transmute({0});", name));
+                    call.push(format!("_{}", name));
+                }
+                _ => call.push(name),
+            }
+        }
+        if !defaults.is_empty() {
+            params.push(format!("params: {}Args", pascal(&func.name)));
+        }
+
+        let ret_type = match ret {
+            Ty::Void => String::new(),
+            Ty::Handle(name) => format!(" -> {}", name),
+            Ty::Struct(name, _) => format!(" -> &'static {}", name),
+            ret => format!(" -> {}", ret.rust()),
+        };
+        let call = format!("backend::bgfx_{}({})", c_function(func), call.join(", "));
+        let tail = match ret {
+            Ty::Void => format!("{};", call),
+            Ty::Handle(name) => format!("let _ret = {};\n{} {{ handle: _ret }}", call, name),
+            Ty::Enum(_) | Ty::Struct(..) => {
+                format!("let _ret = {};\nstd::mem::transmute(_ret)", call)
+            }
+            Ty::This => format!("{};\nself", call),
+            _ => call,
+        };
+
+        writeln!(
+            out,
+            "{}pub fn {}({}){} {{\n    unsafe {{",
+            indent,
+            cname(&func.name),
+            params.join(", "),
+            ret_type
+        )
+        .unwrap();
+        for line in prelude
+            .iter()
+            .chain(tail.lines().map(str::to_string).collect::<Vec<_>>().iter())
+        {
+            writeln!(out, "{}        {}", indent, line).unwrap();
+        }
+        writeln!(out, "{}    }}\n{}}}", indent, indent).unwrap();
+    }
+}
+
+// An argument of a wrapped function, the length after a `const char*` is merged into `Ty::Str`.
+type Arg<'f> = (&'f Field, Ty);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Receiver {
+    None,
+    /// The first argument is the handle the function is a method of.
+    Handle,
+    /// A member function of a struct, f.e. `VertexLayout.add`.
+    This,
+}
+
+fn qualified(s: &Struct) -> String {
+    match s.namespace() {
+        Some(namespace) => format!("{}::{}", namespace, s.name),
+        None => s.name.clone(),
+    }
+}
+
+fn struct_name(s: &Struct) -> String {
+    let qualified = qualified(s);
+    RENAMES
+        .iter()
+        .find(|(from, _)| *from == qualified)
+        .map_or(s.name.clone(), |(_, to)| to.to_string())
+}
+
+/// `bgfx_` function a func calls, without the prefix.
+pub fn c_function(func: &Func) -> String {
+    if let Some(name) = func.attrs.get("cname") {
+        return match &func.class {
+            Some(class) => format!("{}_{}", cname(class), name),
+            None => name.to_string(),
+        };
+    }
+    match &func.class {
+        Some(class) => format!("{}_{}", cname(class), cname(&func.name)),
+        None => cname(&func.name),
+    }
+}
+
+fn is_destroy(func: &Func, args: &[Arg]) -> bool {
+    func.name.starts_with("destroy") && args.len() == 1
+}
+
+fn doc(out: &mut String, indent: &str, lines: &[String]) {
+    for line in lines {
+        if line.is_empty() {
+            writeln!(out, "{}///", indent).unwrap();
+        } else {
+            writeln!(out, "{}/// {}", indent, line).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDL: &str = include_str!("../fixtures/bgfx.idl");
+    const EXPECTED: &str = include_str!("../fixtures/static_lib.rs");
+
+    fn context() -> Context {
+        let backend = [
+            "vertex_layout_begin",
+            "vertex_layout_add",
+            "get_caps",
+            "create_index_buffer",
+            "destroy_index_buffer",
+            "set_shader_name",
+            "set_view_clear",
+            "set_state",
+            "set_uniform",
+        ];
+        let constants = [
+            "BGFX_STATE_WRITE_R",
+            "BGFX_STATE_WRITE_G",
+            "BGFX_STATE_WRITE_B",
+            "BGFX_STATE_WRITE_A",
+            "BGFX_STATE_WRITE_Z",
+            "BGFX_STATE_WRITE_RGB",
+            "BGFX_STATE_WRITE_MASK",
+            "BGFX_CLEAR_NONE",
+            "BGFX_CLEAR_COLOR",
+            "BGFX_CLEAR_DEPTH",
+        ];
+        Context {
+            backend: backend.iter().map(|name| name.to_string()).collect(),
+            constants: constants.iter().map(|name| name.to_string()).collect(),
+            modules: vec!["uniforms".to_string()],
+        }
+    }
+
+    #[test]
+    fn parses_fixture() {
+        let idl = Idl::parse(IDL).unwrap();
+        assert_eq!(idl.version, 115);
+        assert_eq!(idl.typedefs.len(), 10);
+        assert_eq!(
+            idl.handles,
+            [
+                "IndexBuffer",
+                "ProgramHandle",
+                "ShaderHandle",
+                "VertexLayoutHandle"
+            ]
+        );
+
+        let renderers: Vec<&str> = idl.enums[0]
+            .members
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(renderers, ["Noop", "Direct3D11", "Vulkan"]);
+
+        let write = &idl.flags[0];
+        assert_eq!((write.name.as_str(), write.bits()), ("StateWrite", 64));
+        assert_eq!(write.members.len(), 7);
+        assert_eq!(idl.flags[1].bits(), 16);
+
+        let limits = &idl.structs[1];
+        assert_eq!(limits.namespace(), Some("Caps"));
+        let supported = &idl.structs[0].fields[1];
+        assert_eq!(
+            supported.doc,
+            [
+                "Supported functionality.",
+                "",
+                "@attention See `BGFX_CAPS_*` flags."
+            ]
+        );
+
+        let add = &idl.funcs[1];
+        assert_eq!(add.class.as_deref(), Some("VertexLayout"));
+        assert_eq!(add.ret, "VertexLayout&");
+        assert_eq!(
+            add.doc,
+            [
+                "Add attribute to VertexLayout.",
+                "",
+                "@remarks Must be called between begin/end.",
+                ""
+            ]
+        );
+        assert_eq!(add.args[3].default(), Some("false"));
+        assert_eq!(add.args[3].doc.len(), 4);
+
+        let destroy = idl
+            .funcs
+            .iter()
+            .find(|func| func.name == "destroy")
+            .unwrap();
+        assert_eq!(c_function(destroy), "destroy_index_buffer");
+        let clear = idl
+            .funcs
+            .iter()
+            .find(|func| func.name == "setViewClear")
+            .unwrap();
+        assert_eq!(clear.args[3].default(), Some("1.0f"));
+        // `funcptr`s aren't kept
+        assert_eq!(idl.funcs.len(), 10);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = Idl::parse("version(115)\n\tflag.Clear").unwrap_err();
+        assert_eq!(error.to_string(), "bgfx.idl:2: unexpected `flag.Clear`");
+        let error = Idl::parse("version(abc)").unwrap_err();
+        assert_eq!(error.line, 1);
+        let error = Idl::parse("typedef \"bool").unwrap_err();
+        assert_eq!(error.to_string(), "bgfx.idl:1: unterminated string");
+    }
+
+    /// Compares the output for `fixtures/bgfx.idl` with `fixtures/static_lib.rs`, after a
+    /// deliberate change of the output run with `UPDATE_FIXTURES=1` and review the diff.
+    #[test]
+    fn generates_fixture() {
+        let idl = Idl::parse(IDL).unwrap();
+        let generated = Generator::new(&idl, &context()).generate();
+        if std::env::var_os("UPDATE_FIXTURES").is_some() {
+            let path = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures/static_lib.rs");
+            std::fs::write(path, &generated).unwrap();
+            return;
+        }
+
+        for (index, (generated, expected)) in generated.lines().zip(EXPECTED.lines()).enumerate() {
+            assert_eq!(generated, expected, "fixtures/static_lib.rs:{}", index + 1);
+        }
+        assert_eq!(generated.lines().count(), EXPECTED.lines().count());
+    }
+}
@@ -0,0 +1,523 @@
+//! Parser for bgfx's `scripts/bgfx.idl`.
+//!
+//! The IDL is a Lua DSL, but it's written one declaration per line, so it's read line by line
+//! instead of evaluating it:
+//!
+//! ```text
+//! --- Renderer backend type enum.
+//! enum.RendererType { comment = "Renderer types:" }
+//!     .Noop       --- No rendering.
+//!     ()
+//!
+//! func.VertexLayout.add
+//!     "VertexLayout&"                --- Returns itself.
+//!     .attrib     "Attrib::Enum"     --- Attribute semantics.
+//!     .normalized "bool"             --- When using fixed point AttribType (f.e. Uint8)
+//!                                    --- value will be normalized for vertex shader usage.
+//!      { default = false }
+//! ```
+
+use std::fmt;
+
+/// Attributes of a declaration, f.e. `{ bits = 64, shift = 4, const }`, in order.
+#[derive(Clone, Debug, Default)]
+pub struct Attrs(pub Vec<(Option<String>, String)>);
+
+impl Attrs {
+    /// Value of `key = value`.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(k, _)| k.as_deref() == Some(key))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Bare `value` without a key, f.e. `const` or `out`.
+    pub fn has(&self, value: &str) -> bool {
+        self.0.iter().any(|(k, v)| k.is_none() && v == value)
+    }
+
+    fn extend(&mut self, other: Attrs) {
+        self.0.extend(other.0);
+    }
+}
+
+/// Member of an enum or a flag.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub name: String,
+    pub attrs: Attrs,
+    pub doc: Vec<String>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Enum {
+    pub name: String,
+    pub doc: Vec<String>,
+    pub members: Vec<Member>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Flag {
+    pub name: String,
+    pub attrs: Attrs,
+    pub doc: Vec<String>,
+    pub members: Vec<Member>,
+}
+
+impl Flag {
+    /// Width of the flag in bits, 32 when not specified.
+    pub fn bits(&self) -> u32 {
+        self.attrs
+            .get("bits")
+            .and_then(|bits| bits.parse().ok())
+            .unwrap_or(32)
+    }
+}
+
+/// Field of a struct, or argument of a function.
+#[derive(Clone, Debug)]
+pub struct Field {
+    pub name: String,
+    pub ty: String,
+    pub attrs: Attrs,
+    pub doc: Vec<String>,
+}
+
+impl Field {
+    /// `{ default = .. }` of an argument.
+    pub fn default(&self) -> Option<&str> {
+        self.attrs.get("default")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Struct {
+    pub name: String,
+    pub attrs: Attrs,
+    pub doc: Vec<String>,
+    pub fields: Vec<Field>,
+}
+
+impl Struct {
+    /// Nested structs like `Caps::Limits` are declared with `{ namespace = "Caps" }`.
+    pub fn namespace(&self) -> Option<&str> {
+        self.attrs.get("namespace")
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Func {
+    pub name: String,
+    /// `VertexLayout` of `func.VertexLayout.add`.
+    pub class: Option<String>,
+    pub attrs: Attrs,
+    pub doc: Vec<String>,
+    pub ret: String,
+    pub ret_doc: Vec<String>,
+    pub args: Vec<Field>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Idl {
+    pub version: u32,
+    pub typedefs: Vec<String>,
+    pub handles: Vec<String>,
+    pub enums: Vec<Enum>,
+    pub flags: Vec<Flag>,
+    pub structs: Vec<Struct>,
+    pub funcs: Vec<Func>,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "bgfx.idl:{}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    Table(String),
+    Paren(String),
+}
+
+// Splits a line into tokens and the text of its `---` doc comment.
+fn tokenize(line: &str) -> Result<(Vec<Token>, Option<String>), String> {
+    let chars: Vec<char> = line.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() || c == ',' {
+            i += 1;
+        } else if c == '-' && chars.get(i + 1) == Some(&'-') {
+            if chars.get(i + 2) == Some(&'-') {
+                let doc: String = chars[i + 3..].iter().collect();
+                let doc = doc.strip_prefix(' ').unwrap_or(&doc).trim_end();
+                return Ok((tokens, Some(doc.to_string())));
+            }
+            break;
+        } else if c == '"' {
+            let end = find_quote(&chars, i + 1).ok_or("unterminated string")?;
+            tokens.push(Token::Str(chars[i + 1..end].iter().collect()));
+            i = end + 1;
+        } else if c == '{' || c == '(' {
+            let close = if c == '{' { '}' } else { ')' };
+            let end = find_close(&chars, i, c, close).ok_or(format!("unmatched `{}`", c))?;
+            let inner: String = chars[i + 1..end].iter().collect();
+            tokens.push(if c == '{' {
+                Token::Table(inner.trim().to_string())
+            } else {
+                Token::Paren(inner.trim().to_string())
+            });
+            i = end + 1;
+        } else {
+            let start = i;
+            while i < chars.len()
+                && !chars[i].is_whitespace()
+                && !matches!(chars[i], '"' | '{' | '(' | ',')
+            {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        }
+    }
+
+    Ok((tokens, None))
+}
+
+fn find_quote(chars: &[char], from: usize) -> Option<usize> {
+    let mut i = from;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return Some(i),
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+fn find_close(chars: &[char], from: usize, open: char, close: char) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = from;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '"' {
+            i = find_quote(chars, i + 1)?;
+        } else if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(i);
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+// Parses the inside of `{ .. }`, f.e. `bits = 64, shift = 4, "helper"`.
+fn parse_attrs(table: &str) -> Attrs {
+    let mut attrs = Vec::new();
+    let chars: Vec<char> = table.chars().collect();
+    let mut start = 0;
+    let mut i = 0;
+    let mut depth = 0;
+
+    while i <= chars.len() {
+        match chars.get(i) {
+            Some('"') => i = find_quote(&chars, i + 1).unwrap_or(chars.len()),
+            Some('{') | Some('(') => depth += 1,
+            Some('}') | Some(')') => depth -= 1,
+            Some(',') if depth == 0 => {
+                attrs.extend(parse_attr(&chars[start..i].iter().collect::<String>()));
+                start = i + 1;
+            }
+            None => attrs.extend(parse_attr(&chars[start..].iter().collect::<String>())),
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Attrs(attrs)
+}
+
+fn parse_attr(attr: &str) -> Option<(Option<String>, String)> {
+    let attr = attr.trim();
+    if attr.is_empty() {
+        return None;
+    }
+    match attr.split_once('=') {
+        Some((key, value)) if !key.contains('"') && !key.trim().is_empty() => {
+            Some((Some(key.trim().to_string()), unquote(value.trim())))
+        }
+        _ => Some((None, unquote(attr))),
+    }
+}
+
+fn unquote(value: &str) -> String {
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .unwrap_or(value)
+        .to_string()
+}
+
+// The declaration the indented lines below it belong to.
+enum Current {
+    None,
+    Enum(Enum),
+    Flag(Flag),
+    Struct(Struct),
+    Func(Func),
+    // `funcptr`s are only passed through as `void*`, their signature isn't needed.
+    Skip,
+}
+
+impl Idl {
+    pub fn parse(source: &str) -> Result<Idl, ParseError> {
+        let mut idl = Idl::default();
+        let mut current = Current::None;
+        let mut doc: Vec<String> = Vec::new();
+        // Whether the last doc comment line belongs to the last member, the return type or
+        // the declaration that follows.
+        let mut doc_target = DocTarget::Next;
+
+        for (index, line) in source.lines().enumerate() {
+            let error = |message: String| ParseError {
+                line: index + 1,
+                message,
+            };
+            let (tokens, comment) = tokenize(line).map_err(error)?;
+            let indented = line.starts_with(char::is_whitespace);
+
+            if tokens.is_empty() {
+                match comment {
+                    Some(comment) if indented && doc_target != DocTarget::Next => {
+                        current.push_doc(doc_target, comment)
+                    }
+                    Some(comment) => {
+                        idl.finish(std::mem::replace(&mut current, Current::None));
+                        doc.push(comment);
+                        doc_target = DocTarget::Next;
+                    }
+                    None if line.trim().is_empty() => {
+                        doc_target = DocTarget::Next;
+                    }
+                    None => {}
+                }
+                continue;
+            }
+
+            if indented {
+                doc_target = current.member(tokens, comment).map_err(&error)?;
+                continue;
+            }
+
+            idl.finish(std::mem::replace(&mut current, Current::None));
+            let doc = std::mem::take(&mut doc);
+            doc_target = DocTarget::Next;
+
+            let (head, rest) = match tokens.split_first() {
+                Some((Token::Ident(head), rest)) => (head.as_str(), rest),
+                _ => return Err(error(format!("unexpected `{}`", line.trim()))),
+            };
+            let attrs = rest
+                .iter()
+                .filter_map(|token| match token {
+                    Token::Table(table) => Some(parse_attrs(table)),
+                    _ => None,
+                })
+                .fold(Attrs::default(), |mut attrs, table| {
+                    attrs.extend(table);
+                    attrs
+                });
+            let string = rest.iter().find_map(|token| match token {
+                Token::Str(string) => Some(string.clone()),
+                _ => None,
+            });
+
+            let (kind, name) = head.split_once('.').unwrap_or((head, ""));
+            let name = name.to_string();
+            current = match kind {
+                "version" => {
+                    idl.version = match rest.first() {
+                        Some(Token::Paren(version)) => version
+                            .parse()
+                            .map_err(|_| error(format!("invalid version `{}`", version)))?,
+                        _ => return Err(error("version without a number".to_string())),
+                    };
+                    Current::None
+                }
+                "typedef" => {
+                    let name = string.ok_or_else(|| error("typedef without a name".into()))?;
+                    idl.typedefs.push(name);
+                    Current::None
+                }
+                "handle" => {
+                    let name = string.ok_or_else(|| error("handle without a name".into()))?;
+                    idl.handles.push(name);
+                    Current::None
+                }
+                "funcptr" => Current::Skip,
+                "enum" => Current::Enum(Enum {
+                    name,
+                    doc,
+                    members: Vec::new(),
+                }),
+                "flag" => Current::Flag(Flag {
+                    name,
+                    attrs,
+                    doc,
+                    members: Vec::new(),
+                }),
+                "struct" => Current::Struct(Struct {
+                    name,
+                    attrs,
+                    doc,
+                    fields: Vec::new(),
+                }),
+                "func" => {
+                    let (class, name) = match name.split_once('.') {
+                        Some((class, name)) => (Some(class.to_string()), name.to_string()),
+                        None => (None, name),
+                    };
+                    Current::Func(Func {
+                        name,
+                        class,
+                        attrs,
+                        doc,
+                        ret: String::new(),
+                        ret_doc: Vec::new(),
+                        args: Vec::new(),
+                    })
+                }
+                _ => return Err(error(format!("unknown declaration `{}`", head))),
+            };
+        }
+
+        idl.finish(current);
+        Ok(idl)
+    }
+
+    fn finish(&mut self, current: Current) {
+        match current {
+            Current::Enum(e) => self.enums.push(e),
+            Current::Flag(flag) => self.flags.push(flag),
+            Current::Struct(s) => self.structs.push(s),
+            Current::Func(func) => self.funcs.push(func),
+            Current::None | Current::Skip => {}
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DocTarget {
+    Next,
+    Member,
+    Return,
+}
+
+impl Current {
+    // Adds a `.name "type" (value) { attrs } --- doc`, `"return type"` or `{ attrs }` line.
+    fn member(&mut self, tokens: Vec<Token>, comment: Option<String>) -> Result<DocTarget, String> {
+        let doc: Vec<String> = comment.into_iter().collect();
+        let mut tokens = tokens.into_iter();
+        let first = tokens.next();
+        let mut attrs = Attrs::default();
+        let mut ty = None;
+        for token in tokens {
+            match token {
+                Token::Table(table) => attrs.extend(parse_attrs(&table)),
+                Token::Str(string) => ty = Some(string),
+                // The value of a flag member, `.Z (39)`, bgfx-sys has the constants already.
+                Token::Paren(_) => {}
+                Token::Ident(ident) => return Err(format!("unexpected `{}`", ident)),
+            }
+        }
+
+        let name = match first {
+            // `()` closes an enum.
+            Some(Token::Paren(_)) => return Ok(DocTarget::Next),
+            // `{ default = .. }` on its own line applies to the last member.
+            Some(Token::Table(table)) => {
+                self.last_attrs(parse_attrs(&table));
+                return Ok(DocTarget::Member);
+            }
+            Some(Token::Str(ret)) => {
+                if let Current::Func(func) = self {
+                    func.ret = ret;
+                    func.ret_doc = doc;
+                }
+                return Ok(DocTarget::Return);
+            }
+            Some(Token::Ident(name)) => match name.strip_prefix('.') {
+                Some(name) => name.to_string(),
+                None => return Err(format!("unexpected `{}`", name)),
+            },
+            None => return Ok(DocTarget::Next),
+        };
+
+        match self {
+            Current::Enum(Enum { members, .. }) | Current::Flag(Flag { members, .. }) => {
+                members.push(Member { name, attrs, doc })
+            }
+            Current::Struct(Struct { fields, .. }) | Current::Func(Func { args: fields, .. }) => {
+                fields.push(Field {
+                    name,
+                    ty: ty.ok_or("member without a type")?,
+                    attrs,
+                    doc,
+                })
+            }
+            Current::Skip => {}
+            Current::None => return Err(format!("`.{}` outside of a declaration", name)),
+        }
+        Ok(DocTarget::Member)
+    }
+
+    fn last_attrs(&mut self, attrs: Attrs) {
+        let last = match self {
+            Current::Enum(Enum { members, .. }) | Current::Flag(Flag { members, .. }) => {
+                members.last_mut().map(|member| &mut member.attrs)
+            }
+            Current::Struct(Struct { fields, .. }) | Current::Func(Func { args: fields, .. }) => {
+                fields.last_mut().map(|field| &mut field.attrs)
+            }
+            Current::None | Current::Skip => None,
+        };
+        if let Some(last) = last {
+            last.extend(attrs);
+        }
+    }
+
+    fn push_doc(&mut self, target: DocTarget, line: String) {
+        let doc = match (self, target) {
+            (Current::Func(func), DocTarget::Return) => Some(&mut func.ret_doc),
+            (Current::Enum(Enum { members, .. }), _) | (Current::Flag(Flag { members, .. }), _) => {
+                members.last_mut().map(|member| &mut member.doc)
+            }
+            (Current::Struct(Struct { fields, .. }), _)
+            | (Current::Func(Func { args: fields, .. }), _) => {
+                fields.last_mut().map(|field| &mut field.doc)
+            }
+            _ => None,
+        };
+        if let Some(doc) = doc {
+            doc.push(line);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+//! Generates `bgfx/src/static_lib.rs` from bgfx's `scripts/bgfx.idl`.
+//!
+//! ```sh
+//! cargo run -p codegen [--check] [path/to/bgfx.idl]
+//! ```
+//!
+//! The IDL defaults to `$BGFX_SOURCE_DIR/scripts/bgfx.idl`, or `sys/bgfx/scripts/bgfx.idl` fetched
+//! by `sys/update.sh`. The output is formatted with `rustfmt` when it's installed. `--check` only
+//! compares it with the checked-in file and fails when they differ, without writing anything.
+
+mod generate;
+mod idl;
+
+use std::collections::HashSet;
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::thread;
+
+use generate::{Context, Generator};
+use idl::Idl;
+
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let mut args: Vec<_> = env::args_os().skip(1).collect();
+    let check = match args.iter().position(|arg| arg == "--check") {
+        Some(index) => {
+            args.remove(index);
+            true
+        }
+        None => false,
+    };
+    let idl_path = args
+        .into_iter()
+        .next()
+        .map(PathBuf::from)
+        .or_else(|| {
+            env::var_os("BGFX_SOURCE_DIR").map(|dir| PathBuf::from(dir).join("scripts/bgfx.idl"))
+        })
+        .unwrap_or_else(|| root.join("sys/bgfx/scripts/bgfx.idl"));
+    let output = root.join("bgfx/src/static_lib.rs");
+
+    let source = fs::read_to_string(&idl_path).unwrap_or_else(|error| {
+        panic!(
+            "failed to read {}: {}, run sys/update.sh or pass the path to bgfx.idl",
+            idl_path.display(),
+            error
+        )
+    });
+    let idl = Idl::parse(&source).unwrap_or_else(|error| panic!("{}", error));
+
+    let context = Context {
+        backend: backend_functions(&root.join("bgfx/src/backend.rs")),
+        constants: constants(&root.join("sys/src/bindings.rs")),
+        modules: modules(&root.join("bgfx/src/static_lib")),
+    };
+    let skipped: Vec<&str> = idl
+        .funcs
+        .iter()
+        .filter(|func| {
+            func.class.is_none()
+                && !generate::MANUAL_FUNCS.contains(&func.name.as_str())
+                && !context.backend.contains(&generate::c_function(func))
+        })
+        .map(|func| func.name.as_str())
+        .collect();
+    if !skipped.is_empty() {
+        eprintln!("not in backend.rs, skipped: {}", skipped.join(", "));
+    }
+
+    let generated = format(Generator::new(&idl, &context).generate());
+    if fs::read_to_string(&output).ok().as_ref() == Some(&generated) {
+        println!(
+            "{} is up to date (API version {})",
+            output.display(),
+            idl.version
+        );
+        return;
+    }
+    if check {
+        eprintln!(
+            "{} differs from the output for {}, run `cargo run -p codegen` and review the diff",
+            output.display(),
+            idl_path.display()
+        );
+        process::exit(1);
+    }
+
+    fs::write(&output, generated).unwrap();
+    println!(
+        "wrote {} (API version {}), review the changes with `git diff` before committing them",
+        output.display(),
+        idl.version
+    );
+}
+
+/// `source` formatted by `rustfmt`, or unchanged when it's not installed.
+fn format(source: String) -> String {
+    let child = Command::new("rustfmt")
+        .args(["--edition", "2021", "--emit", "stdout"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn();
+    let mut child = match child {
+        Ok(child) => child,
+        Err(error) => {
+            eprintln!(
+                "failed to run rustfmt ({}), the output is left unformatted",
+                error
+            );
+            return source;
+        }
+    };
+
+    // Written from another thread, rustfmt may fill the stdout pipe before reading all of stdin.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = source.clone();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+    let output = child.wait_with_output().unwrap();
+    let written = writer.join().unwrap();
+
+    match String::from_utf8(output.stdout) {
+        Ok(formatted) if output.status.success() && written.is_ok() => formatted,
+        _ => {
+            eprintln!("rustfmt failed, the output is left unformatted");
+            source
+        }
+    }
+}
+
+// Names of the functions in `for_each_function!`, f.e. `fn frame = bgfx_frame(..)` -> `frame`.
+fn backend_functions(path: &Path) -> HashSet<String> {
+    let source = fs::read_to_string(path).unwrap();
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("fn "))
+        .filter_map(|line| line.split_once(" = bgfx_"))
+        .filter_map(|(_, rest)| rest.split_once('('))
+        .map(|(name, _)| name.to_string())
+        .collect()
+}
+
+fn constants(path: &Path) -> Vec<String> {
+    let source = fs::read_to_string(path).unwrap();
+    source
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("pub const BGFX_"))
+        .filter_map(|line| line.split_once(':'))
+        .map(|(name, _)| format!("BGFX_{}", name.trim()))
+        .collect()
+}
+
+fn modules(dir: &Path) -> Vec<String> {
+    let mut modules: Vec<String> = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let path = entry.path();
+                    match path.extension() {
+                        Some(ext) if ext == "rs" => {
+                            Some(path.file_stem()?.to_string_lossy().into_owned())
+                        }
+                        _ => None,
+                    }
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    modules.sort();
+    modules
+}
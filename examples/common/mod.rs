@@ -1,7 +1,6 @@
-use bgfx_rs::{RendererType, PlatformData, Memory, bgfx, Program};
+use bgfx_rs::{RendererType, PlatformData, Program, ShaderLibrary};
 use glfw::Window;
 use core::ffi::c_void;
-use std::path::PathBuf;
 use raw_window_handle::{RawWindowHandle, HasRawWindowHandle};
 
 pub fn get_platform_data(window: &Window) -> PlatformData {
@@ -52,36 +51,11 @@ pub fn get_render_type() -> RendererType {
     return RendererType::Metal;
 }
 
-pub fn _load_shader_file(name: &str) -> std::io::Result<Vec<u8>> {
-    let mut path = PathBuf::with_capacity(512);
-    path.push("./resources/examples/runtime/shaders");
-
-    match bgfx::get_renderer_type() {
-        RendererType::Direct3D11 => path.push("dx11"),
-        RendererType::OpenGL => path.push("glsl"),
-        RendererType::Metal => path.push("metal"),
-        RendererType::OpenGLES => path.push("essl"),
-        RendererType::Vulkan => path.push("spirv"),
-        e => panic!("Unsupported render type {:#?}", e),
-    }
-
-    path.push(format!("{}.bin", name));
-
-    let mut data = std::fs::read(path)?;
-    data.push(0); // this is to terminate the data
-    Ok(data)
-}
-
 // load shaders and create shader program
 pub fn _load_shader_program(vs: &str, ps: &str) -> std::io::Result<Program> {
-    let vs_data = _load_shader_file(vs)?;
-    let ps_data = _load_shader_file(ps)?;
-
-    let vs_data = Memory::copy(&vs_data);
-    let ps_data = Memory::copy(&ps_data);
-
-    let vs_shader = bgfx::create_shader(&vs_data);
-    let ps_shader = bgfx::create_shader(&ps_data);
+    let mut library = ShaderLibrary::from_dir("./resources/examples/runtime/shaders");
 
-    Ok(bgfx::create_program(&vs_shader, &ps_shader, false))
+    library
+        .program(vs, ps)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
 }
\ No newline at end of file
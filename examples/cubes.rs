@@ -4,7 +4,7 @@ use glfw::{Action, Context, Key, WindowHint, ClientApiHint};
 use std::{time::Instant};
 
 mod common;
-use common::{get_platform_data, get_render_type, _load_shader_program};
+use common::{get_platform_data, get_render_type};
 
 const WIDTH: usize = 1280;
 const HEIGHT: usize = 720;
@@ -45,6 +45,11 @@ static CUBE_INDICES: [u16; 36] = [
     6, 3, 7,
 ];
 
+static VS_CUBES: EmbeddedShader =
+    embedded_shader!("vs_cubes", dir = "../resources/examples/runtime/shaders");
+static FS_CUBES: EmbeddedShader =
+    embedded_shader!("fs_cubes", dir = "../resources/examples/runtime/shaders");
+
 pub fn main() -> std::io::Result<()> {
     let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS).unwrap();
     glfw.window_hint(WindowHint::ClientApi(ClientApiHint::NoApi));
@@ -105,7 +110,8 @@ pub fn main() -> std::io::Result<()> {
         let vbh = bgfx::create_vertex_buffer(&verts_mem, &layout, BufferFlags::NONE.bits());
         let ibh = bgfx::create_index_buffer(&index_mem, BufferFlags::NONE.bits());
 
-        let shader_program = _load_shader_program("vs_cubes", "fs_cubes")?;
+        let shader_program = create_embedded_program(&VS_CUBES, &FS_CUBES)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))?;
 
         let state = (StateWriteFlags::R
             | StateWriteFlags::G
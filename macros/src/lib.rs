@@ -0,0 +1,208 @@
+//! Procedural macros for `bgfx-rs`. Use them through the re-exports in `bgfx-rs`.
+
+use proc_macro::{Delimiter, Group, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use std::path::{Path, PathBuf};
+
+/// Backend directories as produced by bgfx's `shaderc` (see `bgfx_rs::shader_library::shader_dir_name`).
+const BACKENDS: &[&str] = &[
+    "dx9", "dx11", "pssl", "metal", "nvn", "glsl", "essl", "spirv", "wgsl",
+];
+
+/// Embeds every compiled backend variant of a shader into the binary.
+///
+/// ```ignore
+/// let vs = embedded_shader!("vs_cubes", dir = "resources/shaders");
+/// ```
+///
+/// `dir` is relative to the manifest directory of the crate being compiled and is expected to
+/// contain one sub directory per backend (`dx11`, `glsl`, `spirv`, ...) holding `<name>.bin`.
+/// Use `out_dir = "shaders"` instead to read shaders compiled by a build script into `$OUT_DIR`.
+/// Expands to a `bgfx_rs::EmbeddedShader`. Compilation fails if no variant is found.
+#[proc_macro]
+pub fn embedded_shader(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(tokens) => tokens,
+        Err((span, message)) => compile_error(span, &message),
+    }
+}
+
+fn expand(input: TokenStream) -> Result<TokenStream, (Span, String)> {
+    let mut tokens = input.into_iter();
+
+    let (name, name_span) = match tokens.next() {
+        Some(TokenTree::Literal(lit)) => (parse_str(&lit)?, lit.span()),
+        Some(other) => return Err((other.span(), "expected shader name string".to_owned())),
+        None => {
+            return Err((
+                Span::call_site(),
+                "expected shader name, f.e. `embedded_shader!(\"vs_cubes\", dir = \"shaders\")`"
+                    .to_owned(),
+            ))
+        }
+    };
+
+    let mut root = None;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Punct(p) if p.as_char() == ',' => continue,
+            TokenTree::Ident(ident)
+                if ident.to_string() == "dir" || ident.to_string() == "out_dir" =>
+            {
+                match tokens.next() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == '=' => (),
+                    _ => return Err((ident.span(), format!("expected `=` after `{}`", ident))),
+                }
+                let dir = match tokens.next() {
+                    Some(TokenTree::Literal(lit)) => parse_str(&lit)?,
+                    _ => return Err((ident.span(), "expected directory string".to_owned())),
+                };
+                if root.is_some() {
+                    return Err((
+                        ident.span(),
+                        "only one of `dir` or `out_dir` can be given".to_owned(),
+                    ));
+                }
+
+                let base = if ident.to_string() == "dir" {
+                    "CARGO_MANIFEST_DIR"
+                } else {
+                    "OUT_DIR"
+                };
+                root = match std::env::var_os(base) {
+                    Some(base) => Some(PathBuf::from(base).join(&dir)),
+                    None if base == "OUT_DIR" => {
+                        return Err((
+                            ident.span(),
+                            "OUT_DIR is not set, does the crate have a build script?".to_owned(),
+                        ))
+                    }
+                    None => Some(PathBuf::from(&dir)),
+                };
+            }
+            other => {
+                return Err((other.span(), format!("unexpected token `{}`", other)));
+            }
+        }
+    }
+
+    let root = root.ok_or_else(|| {
+        (
+            name_span,
+            "missing `dir = \"...\"` or `out_dir = \"...\"` argument".to_owned(),
+        )
+    })?;
+
+    let variants = find_variants(&root, &name);
+
+    if variants.is_empty() {
+        return Err((
+            name_span,
+            format!(
+                "no compiled variants of shader `{}` found in {} (expected <backend>/{}.bin)",
+                name,
+                root.display(),
+                name
+            ),
+        ));
+    }
+
+    // ::bgfx_rs::EmbeddedShader::new("name", &[("backend", include_bytes!("path")), ...])
+    let mut entries = TokenStream::new();
+    for (backend, path) in variants {
+        let include: TokenStream = vec![
+            TokenTree::Ident(proc_macro::Ident::new("include_bytes", Span::call_site())),
+            TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+            TokenTree::Group(Group::new(
+                Delimiter::Parenthesis,
+                TokenTree::Literal(Literal::string(&path.to_string_lossy())).into(),
+            )),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut entry = TokenStream::new();
+        entry.extend(vec![
+            TokenTree::Literal(Literal::string(backend)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+        entry.extend(include);
+
+        entries.extend(vec![
+            TokenTree::Group(Group::new(Delimiter::Parenthesis, entry)),
+            TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        ]);
+    }
+
+    let mut args = TokenStream::new();
+    args.extend(vec![
+        TokenTree::Literal(Literal::string(&name)),
+        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        TokenTree::Punct(Punct::new('&', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Bracket, entries)),
+    ]);
+
+    let mut output: TokenStream = "::bgfx_rs::EmbeddedShader::new".parse().unwrap();
+    output.extend(std::iter::once(TokenTree::Group(Group::new(
+        Delimiter::Parenthesis,
+        args,
+    ))));
+    Ok(output)
+}
+
+fn find_variants(root: &Path, name: &str) -> Vec<(&'static str, PathBuf)> {
+    BACKENDS
+        .iter()
+        .filter_map(|backend| {
+            let path = root.join(backend).join(format!("{}.bin", name));
+            if path.is_file() {
+                Some((*backend, path.canonicalize().unwrap_or(path)))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Returns the contents of a plain or raw string literal.
+fn parse_str(lit: &Literal) -> Result<String, (Span, String)> {
+    let repr = lit.to_string();
+
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = &raw[hashes..raw.len() - hashes];
+        if body.len() >= 2 && body.starts_with('"') && body.ends_with('"') {
+            return Ok(body[1..body.len() - 1].to_owned());
+        }
+    } else if repr.len() >= 2 && repr.starts_with('"') && repr.ends_with('"') {
+        let mut value = String::with_capacity(repr.len());
+        let mut chars = repr[1..repr.len() - 1].chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some(other) => value.push(other),
+                    None => (),
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        return Ok(value);
+    }
+
+    Err((lit.span(), "expected a string literal".to_owned()))
+}
+
+fn compile_error(span: Span, message: &str) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+    group.set_span(span);
+
+    let mut output: TokenStream = "::core::compile_error!".parse().unwrap();
+    output.extend(std::iter::once(TokenTree::Group(group)));
+    output
+}
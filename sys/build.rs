@@ -1,4 +1,6 @@
-use std::{env::{current_dir}, path::Path, process::Command};
+use std::{env, fs, fs::File, path::{Path, PathBuf}};
+
+mod flags;
 
 fn main() {
     
@@ -9,71 +11,68 @@ fn main() {
     let isdarwin = env.contains("darwin");
     let iswindows = env.contains("windows");
     let isunix = env.contains("linux");
+    // bgfx itself is loaded from libbgfx-shared at runtime by bgfx-rs, or not needed by its mock
+    let no_bgfx = feature("shared-api") || feature("mock-only");
+    let renderers = renderers(iswindows, isdarwin);
+    let has_renderer = |define: &'static str| renderers.contains(&define);
+    let opengl = has_renderer("BGFX_CONFIG_RENDERER_OPENGL");
+    let gles = has_renderer("BGFX_CONFIG_RENDERER_OPENGLES");
+    if opengl && gles {
+        panic!("The opengl and gles features can't be combined, bgfx builds one OpenGL renderer");
+    }
 
 
-    let curdir = current_dir().unwrap().as_path().display().to_string();
-    
-    //? Generate
-    if !Path::new("bgfx").exists() {
-        Command::new("sh").arg(format!("{}/update.sh", &curdir)).current_dir(&curdir).spawn().expect("Failed to update sources").wait().expect("Failed to copy toolchain");
+    #[cfg(feature = "generate-bindings")]
+    {
+        let lock = read_lock();
+        generate_bindings(&source_dir("bx", &lock), &source_dir("bgfx", &lock));
     }
 
-    // ? generate ffi
-    // ! broke..
-    // let bindings = bindgen::builder()
-    //     .layout_tests(false)
-    //     .clang_arg("-Ibx/include")
-
-    //     .prepend_enum_name(true)
-    //     .allowlist_function("bgfx.*")
-    //     .allowlist_type("bgfx.*")
-    //     .allowlist_type("BGFX_.*")
-    //     .allowlist_var("bgfx.*")
-    //     .allowlist_var("BGFX.*")
-
-    //     // specific types that break bindgen
-    //     .allowlist_var("BGFX_BUFFER_COMPUTE.*")
-    //     .allowlist_var("BGFX_STATE_WRITE_R.*")
-    //     .allowlist_var("BGFX_STENCIL_OP_PASS_Z_ZERO.*")
-    //     .allowlist_var("BGFX_CLEAR_NONE.*")
-    //     .allowlist_var("BGFX_DISCARD_NONE.*")
-    //     .allowlist_var("BGFX_BUFFER_NONE.*")
-    //     .allowlist_var("BGFX_TEXTURE_NONE.*")
-    //     .allowlist_var("BGFX_SAMPLER_NONE.*")
-    //     .allowlist_var("BGFX_RESET_NONE.*")
-    //     .allowlist_var("BGFX_CAPS_ALPHA_TO_COVERAGE.*")
-    //     .allowlist_var("BGFX_RESOLVE_NONE.*")
-    //     .allowlist_var("BGFX_PCI_ID_NONE.*")
-    //     .allowlist_var("BGFX_CUBE_MAP_POSITIVE_X.*")
-    //     .allowlist_var("BGFX_STATE_BLEND_FUNC_RT_1E.*")
-        
-    //     .header("src/header.h")
-    //     .allowlist_recursively(true)
-    //     .generate().expect("Failed to generate bindings");
-
-    // bindings
-    //     .write_to_file("src/lib.rs")
-    //     .expect("Couldn't write bindings!");
+    // a prebuilt bgfx comes with bx and bimg, only the bimg wrapper is compiled against them
+    let prebuilt = if no_bgfx { None } else { prebuilt_bgfx() };
+    // sources pinned in sources.lock, build.rs never fetches them itself
+    let lock = if prebuilt.is_some() { Vec::new() } else { read_lock() };
 
 
     // ! build
-    build.define("BGFX_CONFIG_RENDERER_WEBGPU", "0");
-    build.define("BGFX_CONFIG_RENDERER_GNM", "0");
+    for &(define, _) in RENDERERS {
+        let value = match define {
+            _ if !has_renderer(define) => "0",
+            // the minimum GLES version
+            "BGFX_CONFIG_RENDERER_OPENGLES" => "30",
+            _ => "1",
+        };
+        build.define(define, value);
+    }
     build.define("BIMG_DECODE_ASTC", "0");
-    build.define("BGFX_CONFIG_MULTITHREADED", "1"); // maybe
+    build.define("BGFX_CONFIG_MULTITHREADED", if feature("multithreaded") { "1" } else { "0" });
 
-    // disable debug
-    build.define("BX_CONFIG_DEBUG", "0");
-    build.define("NDEBUG", "1");
-    build.debug(false);
-    build.opt_level(3);
+    if feature("debug") {
+        build.define("BX_CONFIG_DEBUG", "1");
+        build.define("BGFX_CONFIG_DEBUG", "1");
+        build.debug(true);
+    } else {
+        build.define("BX_CONFIG_DEBUG", "0");
+        build.define("BGFX_CONFIG_DEBUG", "0");
+        build.define("NDEBUG", "1");
+    }
+    // -O and -g follow OPT_LEVEL and DEBUG of the Cargo profile
+    build.opt_level_str(&env::var("OPT_LEVEL").unwrap_or_else(|_| "3".to_owned()));
+
+    for limit in LIMITS {
+        println!("cargo:rerun-if-env-changed={}", limit);
+        if let Ok(value) = std::env::var(limit) {
+            let value: u32 = value.trim().parse().unwrap_or_else(|_| panic!("{} has to be a number, found {:?}", limit, value));
+            build.define(limit, value.to_string().as_str());
+        }
+    }
 
+    // bx headers replacing missing or broken system headers
+    let mut compat = None;
     if iswindows {
-        build.include("bx/include/compat/mingw");
-        // build.include("bgfx/3rdparty/dxsdk/include");
+        compat = Some("compat/mingw");
+        // build.include(bgfx.join("3rdparty/dxsdk/include"));
 
-        build.define("BGFX_CONFIG_RENDERER_VULKAN", "1");
-        build.define("BGFX_CONFIG_RENDERER_DIRECT3D11", "1");
         // build.define("_WIN32", None);
         // build.define("_HAS_EXCEPTIONS", "0");
         // build.define("_SCL_SECURE", "0");
@@ -86,32 +85,16 @@ fn main() {
         build.warnings(false);
     } 
     else if isdarwin {
-        build.define("BGFX_CONFIG_RENDERER_VULKAN", "0");
-        build.define("BGFX_CONFIG_RENDERER_OPENGL", "0");
-        build.define("BGFX_CONFIG_RENDERER_METAL", "1");
-
-        build.include("bx/include/compat/osx");
+        compat = Some("compat/osx");
         
         build.warnings(false);
     }
     else if isunix {
-        build.define("BGFX_CONFIG_RENDERER_VULKAN", "1");
-        build.define("BGFX_CONFIG_RENDERER_OPENGL", "1");
-
-        build.flag("-mpreferred-stack-boundary=4");
-        build.flag("-ffast-math");
-        build.flag("-fomit-frame-pointer");
-        build.flag("-g");
-        build.flag("-O3");
-        build.flag("-mfpmath=sse");
-        build.flag("-msse2");
-        build.flag("-m64");
-        build.flag("-fPIE");
-        build.flag("-fPIC");
-        build.flag("-std=c++14");
-        build.flag("-fno-rtti");
-        build.flag("-fno-exceptions");
-        build.flag("-fno-omit-frame-pointer");
+        let arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+        let features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+        for flag in flags::unix_flags(&arch, &features) {
+            build.flag(flag);
+        }
         
         // break the build
         // build.flag("-fsanitize=address");
@@ -123,111 +106,136 @@ fn main() {
     }
 
 
-    
-    // bx
-    build.include("bx/include");
-    build.include("bx/3rdparty");
-    
-    // build.file("bx/src/allocator.cpp");
-    build.file("bx/src/amalgamated.cpp");
-    build.file("bx/src/bounds.cpp");
-    build.file("bx/src/commandline.cpp");
-    build.file("bx/src/crtnone.cpp");
-    build.file("bx/src/debug.cpp");
-    build.file("bx/src/dtoa.cpp");
-    build.file("bx/src/easing.cpp");
-    build.file("bx/src/file.cpp");
-    build.file("bx/src/filepath.cpp");
-    build.file("bx/src/hash.cpp");
-    build.file("bx/src/math.cpp");
-    build.file("bx/src/mutex.cpp");
-    build.file("bx/src/os.cpp");
-    build.file("bx/src/process.cpp");
-    build.file("bx/src/semaphore.cpp");
-    build.file("bx/src/settings.cpp");
-    build.file("bx/src/sort.cpp");
-    build.file("bx/src/string.cpp");
-    build.file("bx/src/thread.cpp");
-    build.file("bx/src/timer.cpp");
-    build.file("bx/src/url.cpp");
-    build.file("bx/src/bx.cpp");
-
-    // bimg
-    build.include("bimg/include");
-    build.include("bimg/src");
-    build.include("bimg/3rdparty");
-    build.include("bimg/3rdparty/iqa/include/");
-    build.include("bimg/3rdparty/astc-codec/include");
-    build.include("bimg/3rdparty/tinyexr/deps/miniz/");
-
-
-    // add_all_files("3rdparty/libsquish/*.cpp", &mut build);
-    // add_all_files("src/image_encode.*", &mut build);
-    // add_all_files("src/image_cubemap_filter.*", &mut build);
-    // add_all_files("3rdparty/libsquish/*.cpp", &mut build);
-    // add_all_files("3rdparty/libsquish/*.h", &mut build);
-    // add_all_files("3rdparty/edtaa3/*.cpp", &mut build);
-    // add_all_files("3rdparty/edtaa3/*.h", &mut build);
-    // add_all_files("3rdparty/etc1/*.cpp", &mut build);
-    // add_all_files("3rdparty/etc1/*.h", &mut build);
-    // add_all_files("3rdparty/etc2/*.cpp", &mut build);
-    // add_all_files("3rdparty/etc2/*.hpp", &mut build);
-    // add_all_files("3rdparty/nvtt/*.cpp", &mut build);
-    // add_all_files("3rdparty/nvtt/*.h", &mut build);
-    // add_all_files("3rdparty/pvrtc/*.cpp", &mut build);
-    // add_all_files("3rdparty/pvrtc/*.h", &mut build);
-    // add_all_files("3rdparty/astc/*.cpp", &mut build);
-    // add_all_files("3rdparty/astc/*.h", &mut build);
-    // add_all_files("3rdparty/tinyexr/*.h", &mut build);
-    // add_all_files("3rdparty/iqa/include/*.h", &mut build);
-    // add_all_files("3rdparty/iqa/source/*.c", &mut build);
-    
-    build.file("bimg/src/image.cpp");
-    build.file("bimg/src/image_cubemap_filter.cpp");
-    build.file("bimg/src/image_decode.cpp");
-    build.file("bimg/src/image_encode.cpp");
-    build.file("bimg/src/image_gnf.cpp");
-    
-    // bgfx
-    build.include("bgfx/include");
-    build.include("bgfx/3rdparty");
-    build.include("bgfx/3rdparty/khronos/");
-    
-    build.file("bgfx/src/amalgamated.cpp");
-    build.file("bgfx/src/bgfx.cpp");
-    build.file("bgfx/src/debug_renderdoc.cpp");
-    build.file("bgfx/src/dxgi.cpp");
-    build.file("bgfx/src/glcontext_egl.cpp");
-    build.file("bgfx/src/glcontext_glx.cpp");
-    build.file("bgfx/src/glcontext_html5.cpp");
-    build.file("bgfx/src/glcontext_wgl.cpp");
-    build.file("bgfx/src/nvapi.cpp");
-    build.file("bgfx/src/renderer_agc.cpp");
-    build.file("bgfx/src/renderer_d3d11.cpp");
-    build.file("bgfx/src/renderer_d3d12.cpp");
-    build.file("bgfx/src/renderer_d3d9.cpp");
-    build.file("bgfx/src/renderer_gl.cpp");
-    build.file("bgfx/src/renderer_gnm.cpp");
-    build.file("bgfx/src/renderer_noop.cpp");
-    build.file("bgfx/src/renderer_nvn.cpp");
-    build.file("bgfx/src/renderer_vk.cpp");
-    build.file("bgfx/src/renderer_webgpu.cpp");
-    build.file("bgfx/src/shader.cpp");
-    build.file("bgfx/src/shader_dx9bc.cpp");
-    build.file("bgfx/src/shader_dxbc.cpp");
-    build.file("bgfx/src/shader_spirv.cpp");
-    build.file("bgfx/src/topology.cpp");
-    build.file("bgfx/src/vertexlayout.cpp");
+    // bx and bimg, for the bimg wrapper in src/bimg.cpp
+    if let Some(prebuilt) = &prebuilt {
+        for dir in &prebuilt.include_dirs {
+            if let Some(compat) = compat {
+                build.include(dir.join(compat));
+            }
+            build.include(dir);
+        }
+    } else {
+        let bx = source_dir("bx", &lock);
+        let bimg = source_dir("bimg", &lock);
+        if let Some(compat) = compat {
+            build.include(bx.join("include").join(compat));
+        }
+
+        // bx
+        build.include(bx.join("include"));
+        build.include(bx.join("3rdparty"));
+
+        // build.file(bx.join("src/allocator.cpp"));
+        build.file(bx.join("src/amalgamated.cpp"));
+        build.file(bx.join("src/bounds.cpp"));
+        build.file(bx.join("src/commandline.cpp"));
+        build.file(bx.join("src/crtnone.cpp"));
+        build.file(bx.join("src/debug.cpp"));
+        build.file(bx.join("src/dtoa.cpp"));
+        build.file(bx.join("src/easing.cpp"));
+        build.file(bx.join("src/file.cpp"));
+        build.file(bx.join("src/filepath.cpp"));
+        build.file(bx.join("src/hash.cpp"));
+        build.file(bx.join("src/math.cpp"));
+        build.file(bx.join("src/mutex.cpp"));
+        build.file(bx.join("src/os.cpp"));
+        build.file(bx.join("src/process.cpp"));
+        build.file(bx.join("src/semaphore.cpp"));
+        build.file(bx.join("src/settings.cpp"));
+        build.file(bx.join("src/sort.cpp"));
+        build.file(bx.join("src/string.cpp"));
+        build.file(bx.join("src/thread.cpp"));
+        build.file(bx.join("src/timer.cpp"));
+        build.file(bx.join("src/url.cpp"));
+        build.file(bx.join("src/bx.cpp"));
+
+        // bimg
+        build.include(bimg.join("include"));
+        build.include(bimg.join("src"));
+        build.include(bimg.join("3rdparty"));
+        build.include(bimg.join("3rdparty/iqa/include/"));
+        build.include(bimg.join("3rdparty/astc-codec/include"));
+        build.include(bimg.join("3rdparty/tinyexr/deps/miniz/"));
+
+
+        // encoders used by image_encode.cpp
+        build.include(bimg.join("3rdparty/nvtt"));
+        build.include(bimg.join("3rdparty/astc-encoder/include"));
+        add_all_files(&bimg, "3rdparty/libsquish/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/edtaa3/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/etc1/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/etc2/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/nvtt/**/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/pvrtc/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/astc/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/astc-encoder/source/*.cpp", &mut build);
+        add_all_files(&bimg, "3rdparty/iqa/source/*.c", &mut build);
+
+        build.file(bimg.join("src/image.cpp"));
+        build.file(bimg.join("src/image_cubemap_filter.cpp"));
+        build.file(bimg.join("src/image_decode.cpp"));
+        build.file(bimg.join("src/image_encode.cpp"));
+        build.file(bimg.join("src/image_gnf.cpp"));
+    }
+    build.file("src/bimg.cpp");
+
+    // graphics libraries bgfx links against, a shared bgfx brings its own
+    let link_bgfx_deps = !no_bgfx
+        && match &prebuilt {
+            Some(prebuilt) => prebuilt.is_static,
+            None => true,
+        };
+
+    if !no_bgfx && prebuilt.is_none() {
+        let bgfx = source_dir("bgfx", &lock);
+        check_api_version(&bgfx.join("include"), "the bgfx sources");
+        build.include(bgfx.join("include"));
+        build.include(bgfx.join("3rdparty"));
+        build.include(bgfx.join("3rdparty/khronos/"));
+
+        build.file(bgfx.join("src/bgfx.cpp"));
+        build.file(bgfx.join("src/debug_renderdoc.cpp"));
+        build.file(bgfx.join("src/dxgi.cpp"));
+        build.file(bgfx.join("src/nvapi.cpp"));
+        build.file(bgfx.join("src/renderer_noop.cpp"));
+        for &(define, source) in RENDERERS {
+            if let (true, Some(source)) = (has_renderer(define), source) {
+                build.file(bgfx.join("src").join(source));
+            }
+        }
+        if opengl || gles {
+            build.file(bgfx.join("src/glcontext_egl.cpp"));
+            build.file(bgfx.join("src/glcontext_glx.cpp"));
+            build.file(bgfx.join("src/glcontext_html5.cpp"));
+            build.file(bgfx.join("src/glcontext_wgl.cpp"));
+        }
+        build.file(bgfx.join("src/shader.cpp"));
+        build.file(bgfx.join("src/shader_dx9bc.cpp"));
+        build.file(bgfx.join("src/shader_dxbc.cpp"));
+        build.file(bgfx.join("src/shader_spirv.cpp"));
+        build.file(bgfx.join("src/topology.cpp"));
+        build.file(bgfx.join("src/vertexlayout.cpp"));
+    }
 
     build.compile("bgfx_sys");
+    // after bgfx_sys, the bimg wrapper in it uses the prebuilt bimg and bx
+    if let Some(prebuilt) = &prebuilt {
+        for line in &prebuilt.links {
+            println!("cargo:{}", line);
+        }
+    }
     // links
     if iswindows {
         println!("cargo:warning=Compiling to Windows");
 
         println!("cargo:rustc-link-lib=winpthread");
         println!("cargo:rustc-link-lib=stdc++");
-        println!("cargo:rustc-link-lib=gdi32");
-        println!("cargo:rustc-link-lib=psapi");        
+        if link_bgfx_deps {
+            if !renderers.is_empty() {
+                println!("cargo:rustc-link-lib=gdi32");
+            }
+            println!("cargo:rustc-link-lib=psapi");
+        }
     } 
     else if isdarwin {
         println!("cargo:warning=Compiling to Darwin");
@@ -235,17 +243,30 @@ fn main() {
         println!("cargo:rustc-link-lib=c++");
         
         // println!("cargo:rustc-link-lib=framework=OpenGL"); // deprecated by apple
-        println!("cargo:rustc-link-lib=framework=Cocoa");
-        println!("cargo:rustc-link-lib=framework=QuartzCore");
-        println!("cargo:rustc-link-lib=framework=Metal");
-        println!("cargo:rustc-link-lib=framework=MetalKit");        
+        if link_bgfx_deps && !renderers.is_empty() {
+            println!("cargo:rustc-link-lib=framework=Cocoa");
+        }
+        if link_bgfx_deps && has_renderer("BGFX_CONFIG_RENDERER_METAL") {
+            println!("cargo:rustc-link-lib=framework=QuartzCore");
+            println!("cargo:rustc-link-lib=framework=Metal");
+            println!("cargo:rustc-link-lib=framework=MetalKit");
+        }
     } 
     else if isunix {
         println!("cargo:warning=Compiling to Unix (linux)");
         
         println!("cargo:rustc-link-lib=stdc++");
-        println!("cargo:rustc-link-lib=GL");
-        println!("cargo:rustc-link-lib=X11");
+        if link_bgfx_deps {
+            if opengl {
+                println!("cargo:rustc-link-lib=GL");
+            }
+            if gles {
+                println!("cargo:rustc-link-lib=EGL");
+            }
+            if !renderers.is_empty() {
+                println!("cargo:rustc-link-lib=X11");
+            }
+        }
         println!("cargo:rustc-link-lib=pthread");
     }
     else {
@@ -254,6 +275,450 @@ fn main() {
     
     println!("cargo:warning=All Done");
     println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=sources.lock");
+    println!("cargo:rerun-if-changed=src/bimg.cpp");
     // println!("cargo:rerun-if-changed=src/ffi.rs");
     // println!("cargo:rerun-if-changed=src/lib.rs");
 }
+
+/// bgfx renderers as their `BGFX_CONFIG_RENDERER_*` define and source file. The noop renderer is
+/// always built.
+const RENDERERS: &[(&str, Option<&str>)] = &[
+    ("BGFX_CONFIG_RENDERER_AGC", Some("renderer_agc.cpp")),
+    ("BGFX_CONFIG_RENDERER_DIRECT3D9", Some("renderer_d3d9.cpp")),
+    ("BGFX_CONFIG_RENDERER_DIRECT3D11", Some("renderer_d3d11.cpp")),
+    ("BGFX_CONFIG_RENDERER_DIRECT3D12", Some("renderer_d3d12.cpp")),
+    ("BGFX_CONFIG_RENDERER_GNM", Some("renderer_gnm.cpp")),
+    // renderer_mtl.mm is Objective-C++ and isn't built yet
+    ("BGFX_CONFIG_RENDERER_METAL", None),
+    ("BGFX_CONFIG_RENDERER_NVN", Some("renderer_nvn.cpp")),
+    ("BGFX_CONFIG_RENDERER_OPENGL", Some("renderer_gl.cpp")),
+    ("BGFX_CONFIG_RENDERER_OPENGLES", Some("renderer_gl.cpp")),
+    ("BGFX_CONFIG_RENDERER_VULKAN", Some("renderer_vk.cpp")),
+    ("BGFX_CONFIG_RENDERER_WEBGPU", Some("renderer_webgpu.cpp")),
+];
+
+/// bgfx limits from `bgfx/src/config.h` that can be overridden with an environment variable of
+/// the same name, f.e. `BGFX_CONFIG_MAX_VIEWS=512`.
+const LIMITS: &[&str] = &[
+    "BGFX_CONFIG_MAX_VIEWS",
+    "BGFX_CONFIG_MAX_DRAW_CALLS",
+    "BGFX_CONFIG_MAX_BLIT_ITEMS",
+    "BGFX_CONFIG_MAX_MATRIX_CACHE",
+    "BGFX_CONFIG_MAX_RECT_CACHE",
+    "BGFX_CONFIG_MAX_VERTEX_LAYOUTS",
+    "BGFX_CONFIG_MAX_INDEX_BUFFERS",
+    "BGFX_CONFIG_MAX_VERTEX_BUFFERS",
+    "BGFX_CONFIG_MAX_DYNAMIC_INDEX_BUFFERS",
+    "BGFX_CONFIG_MAX_DYNAMIC_VERTEX_BUFFERS",
+    "BGFX_CONFIG_MAX_SHADERS",
+    "BGFX_CONFIG_MAX_TEXTURES",
+    "BGFX_CONFIG_MAX_TEXTURE_SAMPLERS",
+    "BGFX_CONFIG_MAX_FRAME_BUFFERS",
+    "BGFX_CONFIG_MAX_UNIFORMS",
+    "BGFX_CONFIG_MAX_OCCLUSION_QUERIES",
+    "BGFX_CONFIG_MAX_ENCODERS",
+    "BGFX_CONFIG_MAX_INSTANCE_DATA_COUNT",
+    "BGFX_CONFIG_TRANSIENT_VERTEX_BUFFER_SIZE",
+    "BGFX_CONFIG_TRANSIENT_INDEX_BUFFER_SIZE",
+    "BGFX_CONFIG_DYNAMIC_INDEX_BUFFER_SIZE",
+    "BGFX_CONFIG_DYNAMIC_VERTEX_BUFFER_SIZE",
+];
+
+/// Whether the cargo feature `name` is enabled.
+fn feature(name: &str) -> bool {
+    let name = name.to_uppercase().replace('-', "_");
+    std::env::var_os(format!("CARGO_FEATURE_{}", name)).is_some()
+}
+
+/// The `BGFX_CONFIG_RENDERER_*` defines of the renderers to build. Renderers picked with the
+/// `vulkan`, `opengl` and `gles` features replace the default ones of the platform, `noop-only`
+/// builds none and can't be combined with them.
+fn renderers(iswindows: bool, isdarwin: bool) -> Vec<&'static str> {
+    let picked: Vec<(&str, &str)> = [
+        ("vulkan", "BGFX_CONFIG_RENDERER_VULKAN"),
+        ("opengl", "BGFX_CONFIG_RENDERER_OPENGL"),
+        ("gles", "BGFX_CONFIG_RENDERER_OPENGLES"),
+    ]
+    .into_iter()
+    .filter(|(name, _)| feature(name))
+    .collect();
+
+    if feature("noop-only") {
+        if !picked.is_empty() {
+            let names: Vec<&str> = picked.iter().map(|(name, _)| *name).collect();
+            panic!("The noop-only feature can't be combined with {}, it builds no renderer", names.join(", "));
+        }
+        Vec::new()
+    } else if !picked.is_empty() {
+        picked.into_iter().map(|(_, define)| define).collect()
+    } else if iswindows {
+        vec!["BGFX_CONFIG_RENDERER_VULKAN", "BGFX_CONFIG_RENDERER_DIRECT3D11"]
+    } else if isdarwin {
+        vec!["BGFX_CONFIG_RENDERER_METAL"]
+    } else {
+        vec!["BGFX_CONFIG_RENDERER_VULKAN", "BGFX_CONFIG_RENDERER_OPENGL"]
+    }
+}
+
+/// Adds every file matching `pattern` in `root` to the build. Patterns that match nothing are
+/// ignored, the 3rdparty layout of bimg changes between versions.
+fn add_all_files(root: &Path, pattern: &str, build: &mut cc::Build) {
+    let pattern = root.join(pattern);
+    let pattern = pattern.to_str().expect("Source paths have to be valid UTF-8");
+    for path in glob::glob(pattern).expect("Invalid glob pattern").flatten() {
+        build.file(path);
+    }
+}
+
+/// bgfx, bx and bimg linked from `BGFX_LIB_DIR` or pkg-config instead of being built from source.
+struct Prebuilt {
+    /// `cargo:` instructions linking them.
+    links: Vec<String>,
+    /// Directories with the bx and bimg headers `src/bimg.cpp` is compiled against.
+    include_dirs: Vec<PathBuf>,
+    /// bgfx is linked statically, the graphics libraries it uses have to be linked too.
+    is_static: bool,
+}
+
+/// Looks for a prebuilt bgfx in `BGFX_LIB_DIR`, with its headers in `BGFX_INCLUDE_DIR` or the
+/// `include` directory next to it, or else with pkg-config. Returns `None` to build it from source.
+///
+/// bx and bimg come from the same place, `BGFX_LIB_DIR` has to contain them next to bgfx (f.e.
+/// `libbx.a`, `libbimg.a`, `libbimg_decode.a` and `libbimg_encode.a`), pkg-config looks them up
+/// as `bx` and `bimg` when they aren't part of `bgfx`. Only `src/bimg.cpp` is compiled then,
+/// against their headers, so neither the sources nor `sources.lock` are needed.
+fn prebuilt_bgfx() -> Option<Prebuilt> {
+    println!("cargo:rerun-if-env-changed=BGFX_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=BGFX_INCLUDE_DIR");
+
+    if let Some(lib_dir) = env::var_os("BGFX_LIB_DIR") {
+        let lib_dir = PathBuf::from(lib_dir);
+        let kind = find_library(&lib_dir, "bgfx").unwrap_or_else(|| {
+            panic!("BGFX_LIB_DIR is set but {} has no bgfx library", lib_dir.display())
+        });
+
+        let include_dir = match env::var_os("BGFX_INCLUDE_DIR") {
+            Some(include_dir) => PathBuf::from(include_dir),
+            None => lib_dir.join("../include"),
+        };
+        check_api_version(&include_dir, "the bgfx in BGFX_LIB_DIR");
+        let include_dirs = vec![include_dir];
+        if let Some(header) = missing_header(&include_dirs) {
+            panic!("{} has no {}, the bx and bimg headers have to be next to the bgfx ones", include_dirs[0].display(), header);
+        }
+
+        let mut links = vec![
+            format!("rustc-link-search=native={}", lib_dir.display()),
+            format!("rustc-link-lib={}=bgfx", kind),
+        ];
+        for name in ["bimg_encode", "bimg_decode", "bimg", "bx"] {
+            match find_library(&lib_dir, name) {
+                Some(kind) => links.push(format!("rustc-link-lib={}={}", kind, name)),
+                // some builds have the decoder and encoder in bimg
+                None if name.starts_with("bimg_") => {}
+                None => panic!("BGFX_LIB_DIR is set but {} has no {} library", lib_dir.display(), name),
+            }
+        }
+        return Some(Prebuilt {
+            links,
+            include_dirs,
+            is_static: kind == "static",
+        });
+    }
+
+    // pkg-config honors BGFX_NO_PKG_CONFIG
+    let library = pkg_config::Config::new().cargo_metadata(false).probe("bgfx").ok()?;
+    let expected = bindings_api_version(&bindings_path());
+    match library.include_paths.iter().find_map(|path| api_version(path)) {
+        Some(version) if version == expected => {}
+        Some(version) => {
+            println!("cargo:warning=Building bgfx from source, the bgfx found by pkg-config has BGFX_API_VERSION {} instead of {}", version, expected);
+            return None;
+        }
+        None => {
+            println!("cargo:warning=Building bgfx from source, the headers of the bgfx found by pkg-config are missing");
+            return None;
+        }
+    }
+
+    let mut libraries = vec![library];
+    for name in ["bimg", "bx"] {
+        if let Ok(library) = pkg_config::Config::new().cargo_metadata(false).probe(name) {
+            libraries.push(library);
+        }
+    }
+    let include_dirs: Vec<PathBuf> = libraries
+        .iter()
+        .flat_map(|library| library.include_paths.iter().cloned())
+        .collect();
+    if let Some(header) = missing_header(&include_dirs) {
+        println!("cargo:warning=Building bgfx from source, pkg-config found no {} for the bgfx it found", header);
+        return None;
+    }
+
+    let mut links = Vec::new();
+    for library in &libraries {
+        links.extend(library.link_paths.iter().map(|path| format!("rustc-link-search=native={}", path.display())));
+        links.extend(library.libs.iter().map(|lib| format!("rustc-link-lib={}", lib)));
+    }
+    Some(Prebuilt { links, include_dirs, is_static: false })
+}
+
+/// How the library `name` in `lib_dir` is linked, `static` or `dylib`. Static libraries win.
+fn find_library(lib_dir: &Path, name: &str) -> Option<&'static str> {
+    let files = [
+        ("static", format!("lib{}.a", name)),
+        ("static", format!("{}.lib", name)),
+        ("dylib", format!("lib{}.so", name)),
+        ("dylib", format!("lib{}.dylib", name)),
+        ("dylib", format!("lib{}.dll.a", name)),
+        ("dylib", format!("{}.dll", name)),
+    ];
+    files
+        .iter()
+        .find(|(_, file)| lib_dir.join(file).exists())
+        .map(|&(kind, _)| kind)
+}
+
+/// The first bx or bimg header `src/bimg.cpp` includes that isn't in any of `include_dirs`.
+fn missing_header(include_dirs: &[PathBuf]) -> Option<&'static str> {
+    ["bx/bx.h", "bimg/bimg.h", "bimg/decode.h", "bimg/encode.h"]
+        .into_iter()
+        .find(|header| !include_dirs.iter().any(|dir| dir.join(header).exists()))
+}
+
+/// `BGFX_API_VERSION` of the bindings at `path`, f.e. [bindings_path].
+fn bindings_api_version(path: &Path) -> u32 {
+    let bindings = fs::read_to_string(path).expect("Failed to read the bindings");
+    bindings
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("pub const BGFX_API_VERSION: u32 = "))
+        .and_then(|version| version.trim_end_matches(';').parse().ok())
+        .expect("The bindings have no BGFX_API_VERSION")
+}
+
+/// The generated bindings with the `generate-bindings` feature, the checked-in ones otherwise.
+fn bindings_path() -> PathBuf {
+    if feature("generate-bindings") {
+        PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("bindings.rs")
+    } else {
+        PathBuf::from("src/bindings.rs")
+    }
+}
+
+/// Generates the bindings for the headers in `src/header.h` into `OUT_DIR`, the `bindings_drift`
+/// test compares them with the checked-in `src/bindings.rs`.
+#[cfg(feature = "generate-bindings")]
+fn generate_bindings(bx: &Path, bgfx: &Path) {
+    println!("cargo:rerun-if-changed=src/header.h");
+    let bindings = bindgen::builder()
+        .layout_tests(false)
+        .clang_arg(format!("-I{}", bx.join("include").display()))
+        .clang_arg(format!("-I{}", bgfx.join("include").display()))
+
+        .prepend_enum_name(true)
+        .allowlist_function("bgfx.*")
+        .allowlist_type("bgfx.*")
+        .allowlist_type("BGFX_.*")
+        .allowlist_var("bgfx.*")
+        .allowlist_var("BGFX.*")
+
+        // specific types that break bindgen
+        .allowlist_var("BGFX_BUFFER_COMPUTE.*")
+        .allowlist_var("BGFX_STATE_WRITE_R.*")
+        .allowlist_var("BGFX_STENCIL_OP_PASS_Z_ZERO.*")
+        .allowlist_var("BGFX_CLEAR_NONE.*")
+        .allowlist_var("BGFX_DISCARD_NONE.*")
+        .allowlist_var("BGFX_BUFFER_NONE.*")
+        .allowlist_var("BGFX_TEXTURE_NONE.*")
+        .allowlist_var("BGFX_SAMPLER_NONE.*")
+        .allowlist_var("BGFX_RESET_NONE.*")
+        .allowlist_var("BGFX_CAPS_ALPHA_TO_COVERAGE.*")
+        .allowlist_var("BGFX_RESOLVE_NONE.*")
+        .allowlist_var("BGFX_PCI_ID_NONE.*")
+        .allowlist_var("BGFX_CUBE_MAP_POSITIVE_X.*")
+        .allowlist_var("BGFX_STATE_BLEND_FUNC_RT_1E.*")
+
+        .header("src/header.h")
+        .allowlist_recursively(true)
+        .generate().expect("Failed to generate bindings");
+
+    bindings
+        .write_to_file(bindings_path())
+        .expect("Couldn't write bindings!");
+}
+
+/// `BGFX_API_VERSION` from `bgfx/defines.h` in `include_dir`, f.e. `UINT32_C(115)`.
+fn api_version(include_dir: &Path) -> Option<u32> {
+    let defines = fs::read_to_string(include_dir.join("bgfx/defines.h")).ok()?;
+    let value = defines
+        .lines()
+        .find_map(|line| line.trim_start().strip_prefix("#define BGFX_API_VERSION"))?;
+    let digits: String = value
+        .rsplit('(')
+        .next()?
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// Fails the build when the headers of `what` in `include_dir` aren't the API version of the
+/// bindings.
+fn check_api_version(include_dir: &Path, what: &str) {
+    let expected = bindings_api_version(&bindings_path());
+    match api_version(include_dir) {
+        Some(version) if version == expected => {}
+        Some(version) => panic!(
+            "The bindings are generated for BGFX_API_VERSION {} but {} has version {}",
+            expected, what, version
+        ),
+        None => panic!(
+            "Can't check the BGFX_API_VERSION of {}, {} has no bgfx/defines.h (set BGFX_INCLUDE_DIR for a prebuilt bgfx)",
+            what,
+            include_dir.display()
+        ),
+    }
+}
+
+/// Reads the `name commit` lines of `sources.lock`, the commits of bgfx, bx and bimg the bindings
+/// are generated from.
+fn read_lock() -> Vec<(String, String)> {
+    let lock = fs::read_to_string("sources.lock").unwrap_or_else(|error| {
+        panic!("Failed to read sys/sources.lock ({}), run `sys/update.sh --api {}` to pin the sources", error, bindings_api_version(Path::new("src/bindings.rs")))
+    });
+    lock.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| match line.split_whitespace().collect::<Vec<_>>()[..] {
+            [name, commit] => (name.to_owned(), commit.to_lowercase()),
+            _ => panic!("Invalid line in sys/sources.lock: {:?}", line),
+        })
+        .collect()
+}
+
+/// Finds the sources of `name` from `<NAME>_SOURCE_DIR`, a `.tar.gz` in `<NAME>_SOURCE_TARBALL`
+/// (f.e. `https://github.com/bkaradzic/bgfx/archive/<commit>.tar.gz` downloaded ahead of time)
+/// or the directory fetched by `update.sh`, and checks they are at the commit of `sources.lock`.
+/// `BGFX_SKIP_SOURCE_CHECK` builds other commits with a warning, sources without a pinned commit
+/// are built with a warning too.
+fn source_dir(name: &str, lock: &[(String, String)]) -> PathBuf {
+    let expected = lock.iter().find(|(locked, _)| locked == name).map(|(_, commit)| commit.as_str());
+    let skip_check = env::var_os("BGFX_SKIP_SOURCE_CHECK").is_some();
+    if expected.is_none() {
+        println!(
+            "cargo:warning=sys/sources.lock has no commit for {}, building it unchecked. Run `sys/update.sh` to pin the commits of API version {}",
+            name, bindings_api_version(Path::new("src/bindings.rs"))
+        );
+    }
+    let expected_name = expected.unwrap_or("the pinned commit");
+    let dir_var = format!("{}_SOURCE_DIR", name.to_uppercase());
+    let tarball_var = format!("{}_SOURCE_TARBALL", name.to_uppercase());
+    println!("cargo:rerun-if-env-changed={}", dir_var);
+    println!("cargo:rerun-if-env-changed={}", tarball_var);
+    println!("cargo:rerun-if-env-changed=BGFX_SKIP_SOURCE_CHECK");
+
+    let (dir, commit) = if let Some(dir) = env::var_os(&dir_var) {
+        let dir = PathBuf::from(dir);
+        let commit = git_commit(&dir);
+        (dir, commit)
+    } else if let Some(tarball) = env::var_os(&tarball_var) {
+        extract_tarball(name, Path::new(&tarball))
+    } else if Path::new(name).exists() {
+        (PathBuf::from(name), git_commit(Path::new(name)))
+    } else {
+        panic!(
+            "The {} sources are missing. Run `sys/update.sh` to fetch the pinned commits, or set {} to a checkout or {} to a .tar.gz of {}",
+            name, dir_var, tarball_var, expected_name
+        );
+    };
+
+    if !dir.is_dir() {
+        panic!("{} is not a directory", dir.display());
+    }
+    match (commit, expected) {
+        (Some(commit), Some(expected)) if commit == expected => {}
+        (_, None) => {}
+        _ if skip_check => {
+            println!("cargo:warning=Building {} from {} without checking it is at {}", name, dir.display(), expected_name);
+        }
+        (Some(commit), _) => panic!(
+            "{} is at commit {} but sys/sources.lock pins {}, check out the pinned commit or set BGFX_SKIP_SOURCE_CHECK=1",
+            dir.display(), commit, expected_name
+        ),
+        (None, _) => panic!(
+            "Can't tell the commit of {}, it's neither a git checkout nor a GitHub tarball. Set BGFX_SKIP_SOURCE_CHECK=1 to build it anyway",
+            dir.display()
+        ),
+    }
+    dir
+}
+
+/// The commit checked out in the git repository at `dir`, read from `.git` without running git.
+fn git_commit(dir: &Path) -> Option<String> {
+    let mut git_dir = dir.join(".git");
+    // worktrees and submodules point to the repository
+    if git_dir.is_file() {
+        let link = fs::read_to_string(&git_dir).ok()?;
+        git_dir = dir.join(link.trim().strip_prefix("gitdir:")?.trim());
+    }
+
+    let head = fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    let commit = match head.strip_prefix("ref:") {
+        Some(reference) => {
+            let reference = reference.trim();
+            match fs::read_to_string(git_dir.join(reference)) {
+                Ok(commit) => commit,
+                Err(_) => fs::read_to_string(git_dir.join("packed-refs"))
+                    .ok()?
+                    .lines()
+                    .find(|line| line.ends_with(reference))?
+                    .split_whitespace()
+                    .next()?
+                    .to_owned(),
+            }
+        }
+        None => head.to_owned(),
+    };
+    Some(commit.trim().to_lowercase())
+}
+
+/// Extracts a GitHub tarball of `name` into `OUT_DIR`, returning the directory and the commit
+/// git archive stores in the global pax header.
+fn extract_tarball(name: &str, tarball: &Path) -> (PathBuf, Option<String>) {
+    let out_dir = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join(name);
+    let _ = fs::remove_dir_all(&out_dir);
+    println!("cargo:rerun-if-changed={}", tarball.display());
+
+    let file = File::open(tarball).unwrap_or_else(|error| panic!("Failed to open {}: {}", tarball.display(), error));
+    let mut archive = tar::Archive::new(flate2::read::GzDecoder::new(file));
+    let mut commit = None;
+    let entries = archive.entries().unwrap_or_else(|error| panic!("Failed to read {}: {}", tarball.display(), error));
+    for entry in entries {
+        let mut entry = entry.unwrap_or_else(|error| panic!("Failed to read {}: {}", tarball.display(), error));
+        if entry.header().entry_type() == tar::EntryType::XGlobalHeader {
+            if let Ok(Some(extensions)) = entry.pax_extensions() {
+                for extension in extensions.flatten() {
+                    if extension.key() == Ok("comment") {
+                        commit = extension.value().ok().map(|value| value.trim().to_lowercase());
+                    }
+                }
+            }
+            continue;
+        }
+
+        // strip the top directory, f.e. `bgfx-<commit>/`
+        let path: PathBuf = entry.path().expect("Invalid path in tarball").components().skip(1).collect();
+        if path.as_os_str().is_empty() {
+            continue;
+        }
+        let path = out_dir.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).expect("Failed to create source directory");
+        }
+        entry.unpack(&path).unwrap_or_else(|error| panic!("Failed to extract {}: {}", path.display(), error));
+    }
+    (out_dir, commit)
+}
@@ -0,0 +1,26 @@
+//! Compiler flags of the linux build, shared by `build.rs` and `tests/flags.rs`.
+
+/// The gcc/clang flags for a linux target with `arch` and the comma separated `features`, from
+/// `CARGO_CFG_TARGET_ARCH` and `CARGO_CFG_TARGET_FEATURE` (f.e. `x86_64` and `fxsr,sse,sse2`).
+/// The optimization level and debug info follow the Cargo profile through `cc` and aren't part
+/// of them.
+pub fn unix_flags(arch: &str, features: &str) -> Vec<&'static str> {
+    let has_feature = |name: &str| features.split(',').any(|feature| feature.trim() == name);
+    let mut flags = vec![
+        "-std=c++14",
+        "-fno-rtti",
+        "-fno-exceptions",
+        "-ffast-math",
+        "-fno-omit-frame-pointer",
+        "-fPIC",
+    ];
+    match arch {
+        "x86_64" => flags.extend(["-m64", "-mpreferred-stack-boundary=4"]),
+        "x86" => flags.extend(["-m32", "-mpreferred-stack-boundary=4"]),
+        _ => {}
+    }
+    if matches!(arch, "x86" | "x86_64") && has_feature("sse2") {
+        flags.extend(["-msse2", "-mfpmath=sse"]);
+    }
+    flags
+}
@@ -0,0 +1,170 @@
+// C API over the parts of bimg used by bgfx-rs. bimg only exposes a C++ API, so these functions
+// are compiled together with bgfx in build.rs and declared by hand in src/bimg.rs.
+
+#include <bx/allocator.h>
+#include <bx/error.h>
+#include <bx/string.h>
+#include <bimg/bimg.h>
+#include <bimg/decode.h>
+#include <bimg/encode.h>
+
+namespace
+{
+	BX_ERROR_RESULT(BIMG_RS_ERROR_PARSE, BX_MAKEFOURCC('b', 'r', 's', 0) );
+
+	bx::DefaultAllocator s_allocator;
+
+	void setError(const bx::Error& _err, char* _msg, uint32_t _max)
+	{
+		if (!_err.isOk()
+		&&  NULL != _msg
+		&&  0 != _max)
+		{
+			bx::strCopy(_msg, int32_t(_max), _err.getMessage() );
+		}
+	}
+} // namespace
+
+extern "C"
+{
+	struct bimg_image_info_t
+	{
+		uint32_t format;
+		uint32_t orientation;
+		uint32_t size;
+		uint32_t width;
+		uint32_t height;
+		uint32_t depth;
+		uint16_t num_layers;
+		uint8_t  num_mips;
+		bool     has_alpha;
+		bool     cube_map;
+		bool     srgb;
+	};
+
+	typedef struct bimg_image_container_s bimg_image_container_t;
+
+	/// Parses DDS, KTX, PVR3 or any image format bimg can decode (PNG, JPEG, TGA, HDR, EXR, ...).
+	/// Pass `dst_format` >= TextureFormat::Count to keep the format of the source.
+	/// Returns NULL on failure and writes the reason into `err_msg`.
+	bimg_image_container_t* bimg_image_parse(const void* _data, uint32_t _size, uint32_t _dstFormat, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		const bimg::TextureFormat::Enum dstFormat = _dstFormat < bimg::TextureFormat::Count
+			? bimg::TextureFormat::Enum(_dstFormat)
+			: bimg::TextureFormat::Count
+			;
+
+		bimg::ImageContainer* image = bimg::imageParse(&s_allocator, _data, _size, dstFormat, &err);
+
+		if (NULL == image)
+		{
+			if (err.isOk() )
+			{
+				BX_ERROR_SET(&err, BIMG_RS_ERROR_PARSE, "Unrecognized image format.");
+			}
+
+			setError(err, _errMsg, _errMax);
+		}
+
+		return (bimg_image_container_t*)image;
+	}
+
+	void bimg_image_free(bimg_image_container_t* _image)
+	{
+		bimg::imageFree( (bimg::ImageContainer*)_image);
+	}
+
+	void bimg_image_get_info(const bimg_image_container_t* _image, bimg_image_info_t* _info)
+	{
+		const bimg::ImageContainer* image = (const bimg::ImageContainer*)_image;
+
+		_info->format      = uint32_t(image->m_format);
+		_info->orientation = uint32_t(image->m_orientation);
+		_info->size        = image->m_size;
+		_info->width       = image->m_width;
+		_info->height      = image->m_height;
+		_info->depth       = image->m_depth;
+		_info->num_layers  = image->m_numLayers;
+		_info->num_mips    = image->m_numMips;
+		_info->has_alpha   = image->m_hasAlpha;
+		_info->cube_map    = image->m_cubeMap;
+		_info->srgb        = image->m_srgb;
+	}
+
+	const void* bimg_image_get_data(const bimg_image_container_t* _image)
+	{
+		return ( (const bimg::ImageContainer*)_image)->m_data;
+	}
+
+	/// Returns the size in bytes of an image with the given dimensions and format.
+	uint32_t bimg_image_get_size(uint16_t _width, uint16_t _height, uint16_t _depth, bool _cubeMap, bool _hasMips, uint16_t _numLayers, uint32_t _format)
+	{
+		return bimg::imageGetSize(NULL, _width, _height, _depth, _cubeMap, _hasMips, _numLayers, bimg::TextureFormat::Enum(_format) );
+	}
+
+	/// Encodes RGBA8 pixels into `dst`, which must hold `bimg_image_get_size` bytes.
+	bool bimg_image_encode_from_rgba8(void* _dst, const void* _src, uint32_t _width, uint32_t _height, uint32_t _depth, uint32_t _format, uint32_t _quality, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		bimg::imageEncodeFromRgba8(&s_allocator, _dst, _src, _width, _height, _depth, bimg::TextureFormat::Enum(_format), bimg::Quality::Enum(_quality), &err);
+		setError(err, _errMsg, _errMax);
+		return err.isOk();
+	}
+
+	/// Encodes RGBA32F pixels into `dst`, which must hold `bimg_image_get_size` bytes.
+	bool bimg_image_encode_from_rgba32f(void* _dst, const void* _src, uint32_t _width, uint32_t _height, uint32_t _depth, uint32_t _format, uint32_t _quality, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		bimg::imageEncodeFromRgba32f(&s_allocator, _dst, _src, _width, _height, _depth, bimg::TextureFormat::Enum(_format), bimg::Quality::Enum(_quality), &err);
+		setError(err, _errMsg, _errMax);
+		return err.isOk();
+	}
+
+	/// Allocates an image and copies `data` into it when it's not NULL.
+	bimg_image_container_t* bimg_image_alloc(uint32_t _format, uint16_t _width, uint16_t _height, uint16_t _depth, uint16_t _numLayers, bool _cubeMap, bool _hasMips, const void* _data)
+	{
+		return (bimg_image_container_t*)bimg::imageAlloc(&s_allocator, bimg::TextureFormat::Enum(_format), _width, _height, _depth, _numLayers, _cubeMap, _hasMips, _data);
+	}
+
+	/// Converts an image and all its mips to `dst_format`. Returns NULL if bimg can't convert
+	/// between the formats.
+	bimg_image_container_t* bimg_image_convert(const bimg_image_container_t* _image, uint32_t _dstFormat)
+	{
+		return (bimg_image_container_t*)bimg::imageConvert(&s_allocator, bimg::TextureFormat::Enum(_dstFormat), *(const bimg::ImageContainer*)_image);
+	}
+
+	/// Converts raw pixels between uncompressed formats. `dst` must hold the converted size.
+	bool bimg_image_convert_pixels(void* _dst, uint32_t _dstFormat, const void* _src, uint32_t _srcFormat, uint32_t _width, uint32_t _height, uint32_t _depth)
+	{
+		return bimg::imageConvert(&s_allocator, _dst, bimg::TextureFormat::Enum(_dstFormat), _src, bimg::TextureFormat::Enum(_srcFormat), _width, _height, _depth);
+	}
+
+	/// Converts a RGBA32F lat-long (equirectangular) image into a cubemap.
+	bimg_image_container_t* bimg_image_cubemap_from_lat_long_rgba32f(const bimg_image_container_t* _image, bool _useBilinearInterpolation, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		bimg::ImageContainer* image = bimg::imageCubemapFromLatLongRgba32F(&s_allocator, *(const bimg::ImageContainer*)_image, _useBilinearInterpolation, &err);
+		setError(err, _errMsg, _errMax);
+		return (bimg_image_container_t*)image;
+	}
+
+	/// Converts a RGBA32F horizontal strip of 6 faces into a cubemap.
+	bimg_image_container_t* bimg_image_cubemap_from_strip_rgba32f(const bimg_image_container_t* _image, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		bimg::ImageContainer* image = bimg::imageCubemapFromStripRgba32F(&s_allocator, *(const bimg::ImageContainer*)_image, &err);
+		setError(err, _errMsg, _errMax);
+		return (bimg_image_container_t*)image;
+	}
+
+	/// Filters a RGBA32F cubemap into a mip chain of specular radiance with increasing roughness.
+	bimg_image_container_t* bimg_image_cubemap_radiance_filter(const bimg_image_container_t* _image, uint32_t _lightingModel, char* _errMsg, uint32_t _errMax)
+	{
+		bx::Error err;
+		bimg::ImageContainer* image = bimg::imageCubemapRadianceFilter(&s_allocator, *(const bimg::ImageContainer*)_image, bimg::LightingModel::Enum(_lightingModel), &err);
+		setError(err, _errMsg, _errMax);
+		return (bimg_image_container_t*)image;
+	}
+
+} // extern "C"
@@ -0,0 +1,137 @@
+//! Declarations for the C API in `src/bimg.cpp`. bimg has no C API of its own, so these are
+//! written by hand and have to be kept in sync with the C++ side.
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct bimg_image_container_t {
+    _unused: [u8; 0],
+}
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct bimg_image_info_t {
+    pub format: u32,
+    pub orientation: u32,
+    pub size: u32,
+    pub width: u32,
+    pub height: u32,
+    pub depth: u32,
+    pub num_layers: u16,
+    pub num_mips: u8,
+    pub has_alpha: bool,
+    pub cube_map: bool,
+    pub srgb: bool,
+}
+
+extern "C" {
+    pub fn bimg_image_parse(
+        _data: *const ::std::os::raw::c_void,
+        _size: u32,
+        _dstFormat: u32,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> *mut bimg_image_container_t;
+}
+extern "C" {
+    pub fn bimg_image_free(_image: *mut bimg_image_container_t);
+}
+extern "C" {
+    pub fn bimg_image_get_info(
+        _image: *const bimg_image_container_t,
+        _info: *mut bimg_image_info_t,
+    );
+}
+extern "C" {
+    pub fn bimg_image_get_data(
+        _image: *const bimg_image_container_t,
+    ) -> *const ::std::os::raw::c_void;
+}
+extern "C" {
+    pub fn bimg_image_get_size(
+        _width: u16,
+        _height: u16,
+        _depth: u16,
+        _cubeMap: bool,
+        _hasMips: bool,
+        _numLayers: u16,
+        _format: u32,
+    ) -> u32;
+}
+extern "C" {
+    pub fn bimg_image_encode_from_rgba8(
+        _dst: *mut ::std::os::raw::c_void,
+        _src: *const ::std::os::raw::c_void,
+        _width: u32,
+        _height: u32,
+        _depth: u32,
+        _format: u32,
+        _quality: u32,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> bool;
+}
+extern "C" {
+    pub fn bimg_image_encode_from_rgba32f(
+        _dst: *mut ::std::os::raw::c_void,
+        _src: *const ::std::os::raw::c_void,
+        _width: u32,
+        _height: u32,
+        _depth: u32,
+        _format: u32,
+        _quality: u32,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> bool;
+}
+extern "C" {
+    pub fn bimg_image_alloc(
+        _format: u32,
+        _width: u16,
+        _height: u16,
+        _depth: u16,
+        _numLayers: u16,
+        _cubeMap: bool,
+        _hasMips: bool,
+        _data: *const ::std::os::raw::c_void,
+    ) -> *mut bimg_image_container_t;
+}
+extern "C" {
+    pub fn bimg_image_convert(
+        _image: *const bimg_image_container_t,
+        _dstFormat: u32,
+    ) -> *mut bimg_image_container_t;
+}
+extern "C" {
+    pub fn bimg_image_convert_pixels(
+        _dst: *mut ::std::os::raw::c_void,
+        _dstFormat: u32,
+        _src: *const ::std::os::raw::c_void,
+        _srcFormat: u32,
+        _width: u32,
+        _height: u32,
+        _depth: u32,
+    ) -> bool;
+}
+extern "C" {
+    pub fn bimg_image_cubemap_from_lat_long_rgba32f(
+        _image: *const bimg_image_container_t,
+        _useBilinearInterpolation: bool,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> *mut bimg_image_container_t;
+}
+extern "C" {
+    pub fn bimg_image_cubemap_from_strip_rgba32f(
+        _image: *const bimg_image_container_t,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> *mut bimg_image_container_t;
+}
+extern "C" {
+    pub fn bimg_image_cubemap_radiance_filter(
+        _image: *const bimg_image_container_t,
+        _lightingModel: u32,
+        _errMsg: *mut ::std::os::raw::c_char,
+        _errMax: u32,
+    ) -> *mut bimg_image_container_t;
+}
@@ -0,0 +1,2 @@
+// Entry point for bindgen, see the `generate-bindings` feature in build.rs.
+#include <bgfx/c99/bgfx.h>
@@ -0,0 +1,105 @@
+//! Compares the checked-in `src/bindings.rs` with the bindings generated from the bgfx headers by
+//! the `generate-bindings` feature.
+#![cfg(feature = "generate-bindings")]
+
+use std::collections::BTreeMap;
+
+use quote::ToTokens;
+
+/// A function signature, the `name: type` fields of a struct, the type and value of a const, an
+/// aliased type or the variants of an enum, without doc comments.
+type Item = Vec<String>;
+
+fn items(source: &str) -> BTreeMap<String, Item> {
+    let file = syn::parse_file(source).expect("Failed to parse the bindings");
+    let mut items = BTreeMap::new();
+    for item in file.items {
+        match item {
+            syn::Item::ForeignMod(foreign) => {
+                for item in foreign.items {
+                    if let syn::ForeignItem::Fn(function) = item {
+                        let name = format!("fn {}", function.sig.ident);
+                        items.insert(name, vec![function.sig.to_token_stream().to_string()]);
+                    }
+                }
+            }
+            syn::Item::Struct(item) => {
+                let fields = item
+                    .fields
+                    .iter()
+                    .map(|field| {
+                        let name = field.ident.as_ref().map(ToString::to_string);
+                        let ty = field.ty.to_token_stream().to_string();
+                        format!("{}: {}", name.unwrap_or_default(), ty)
+                    })
+                    .collect();
+                items.insert(format!("struct {}", item.ident), fields);
+            }
+            // the `BGFX_*` defines and the values of the C enums
+            syn::Item::Const(item) => {
+                let ty = item.ty.to_token_stream().to_string();
+                let value = item.expr.to_token_stream().to_string();
+                items.insert(
+                    format!("const {}", item.ident),
+                    vec![format!("{} = {}", ty, value)],
+                );
+            }
+            syn::Item::Type(item) => {
+                let ty = item.ty.to_token_stream().to_string();
+                items.insert(format!("type {}", item.ident), vec![ty]);
+            }
+            syn::Item::Enum(item) => {
+                let variants = item
+                    .variants
+                    .iter()
+                    .map(|variant| variant.to_token_stream().to_string())
+                    .collect();
+                items.insert(format!("enum {}", item.ident), variants);
+            }
+            _ => {}
+        }
+    }
+    items
+}
+
+/// What is only in `checked_in` or only in `generated`.
+fn diff(checked_in: &Item, generated: &Item) -> String {
+    let only = |a: &Item, b: &Item| -> Vec<String> {
+        a.iter().filter(|line| !b.contains(line)).cloned().collect()
+    };
+    format!(
+        "\n    checked in: {}\n    generated:  {}",
+        only(checked_in, generated).join(", "),
+        only(generated, checked_in).join(", ")
+    )
+}
+
+#[test]
+fn checked_in_bindings_match_headers() {
+    let generated_path = concat!(env!("OUT_DIR"), "/bindings.rs");
+    let generated = items(&std::fs::read_to_string(generated_path).unwrap());
+    let checked_in = items(include_str!("../src/bindings.rs"));
+
+    let mut drift = Vec::new();
+    for (name, item) in &generated {
+        match checked_in.get(name) {
+            None => drift.push(format!("{} is missing", name)),
+            Some(checked_in) if checked_in != item => {
+                drift.push(format!("{} differs{}", name, diff(checked_in, item)))
+            }
+            Some(_) => {}
+        }
+    }
+    for name in checked_in.keys() {
+        if !generated.contains_key(name) {
+            drift.push(format!("{} is not in the headers anymore", name));
+        }
+    }
+
+    assert!(
+        drift.is_empty(),
+        "src/bindings.rs has diverged from the bgfx headers, replace it with {}:\n{}",
+        generated_path,
+        drift.join("\n")
+    );
+}
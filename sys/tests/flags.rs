@@ -0,0 +1,62 @@
+//! Checks the compiler flags `build.rs` picks for linux targets.
+
+#[path = "../flags.rs"]
+mod flags;
+
+use flags::unix_flags;
+
+#[test]
+fn x86_64_uses_sse2() {
+    let flags = unix_flags("x86_64", "fxsr,sse,sse2");
+    for flag in [
+        "-m64",
+        "-msse2",
+        "-mfpmath=sse",
+        "-mpreferred-stack-boundary=4",
+    ] {
+        assert!(flags.contains(&flag), "{} missing from {:?}", flag, flags);
+    }
+    assert!(!flags.contains(&"-m32"));
+}
+
+#[test]
+fn x86_without_sse2() {
+    let flags = unix_flags("x86", "fxsr,sse");
+    assert!(flags.contains(&"-m32"));
+    assert!(!flags.contains(&"-msse2"));
+    assert!(!flags.contains(&"-mfpmath=sse"));
+
+    let flags = unix_flags("x86", "fxsr,sse,sse2");
+    assert!(flags.contains(&"-msse2"));
+    assert!(flags.contains(&"-mfpmath=sse"));
+}
+
+#[test]
+fn no_x86_flags_on_other_archs() {
+    for arch in ["aarch64", "riscv64", "arm"] {
+        let flags = unix_flags(arch, "neon,v8");
+        assert!(
+            flags.iter().all(|flag| !matches!(
+                *flag,
+                "-m64" | "-m32" | "-msse2" | "-mfpmath=sse" | "-mpreferred-stack-boundary=4"
+            )),
+            "x86 flags for {}: {:?}",
+            arch,
+            flags
+        );
+        assert!(flags.contains(&"-std=c++14"));
+    }
+}
+
+#[test]
+fn no_contradicting_flags() {
+    for arch in ["x86_64", "x86", "aarch64", "riscv64"] {
+        let flags = unix_flags(arch, "sse2");
+        assert!(flags.contains(&"-fno-omit-frame-pointer"));
+        assert!(!flags.contains(&"-fomit-frame-pointer"));
+        // the optimization level comes from the Cargo profile
+        assert!(!flags
+            .iter()
+            .any(|flag| flag.starts_with("-O") || *flag == "-g"));
+    }
+}
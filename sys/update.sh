@@ -1,11 +1,53 @@
-#bin/bash
-rm -rf bgfx
-rm -rf bx
-rm -rf bimg
-git clone --depth=1 https://github.com/bkaradzic/bgfx
-git clone --depth=1 https://github.com/bkaradzic/bx 
-git clone --depth=1 https://github.com/bkaradzic/bimg
-# sed -i 's/UINT8_C//' bgfx/include/bgfx/defines.h
-# sed -i 's/UINT16_C//' bgfx/include/bgfx/defines.h
-# sed -i 's/UINT32_C//' bgfx/include/bgfx/defines.h
-# sed -i 's/UINT64_C//' bgfx/include/bgfx/defines.h
+#!/bin/sh
+# Fetches bgfx, bx and bimg at the commits pinned in sources.lock. With --api VERSION it pins the
+# last bgfx commit of that BGFX_API_VERSION and the bx and bimg commits of the same date instead,
+# without pinned commits it pins the BGFX_API_VERSION of src/bindings.rs.
+# build.rs never runs this, it only checks the commits.
+set -e
+cd "$(dirname "$0")"
+
+fetch() {
+    rm -rf "$1"
+    git init -q "$1"
+    git -C "$1" fetch -q --depth=1 "https://github.com/bkaradzic/$1" "$2"
+    git -C "$1" checkout -q FETCH_HEAD
+}
+
+if [ "$1" != "--api" ] && ! grep -q '^[^#]' sources.lock; then
+    set -- --api "$(sed -n 's/^pub const BGFX_API_VERSION: u32 = \([0-9]*\);$/\1/p' src/bindings.rs)"
+fi
+
+if [ "$1" = "--api" ]; then
+    version="$2"
+    if [ -z "$version" ]; then
+        echo "usage: $0 --api VERSION, f.e. the BGFX_API_VERSION of src/bindings.rs" >&2
+        exit 1
+    fi
+    for name in bgfx bx bimg; do
+        rm -rf "$name"
+        git clone -q "https://github.com/bkaradzic/$name" "$name"
+    done
+
+    # the commit before the API version was bumped past $version
+    bump=$(git -C bgfx log --format=%H -S"version($((version + 1)))" -- scripts/bgfx.idl | tail -n 1)
+    if [ -n "$bump" ]; then
+        git -C bgfx checkout -q "$bump^"
+    fi
+    if ! grep -q "^version($version)" bgfx/scripts/bgfx.idl; then
+        echo "bgfx has no commit with API version $version" >&2
+        exit 1
+    fi
+    date=$(git -C bgfx log -1 --format=%cI)
+    for name in bx bimg; do
+        git -C "$name" checkout -q "$(git -C "$name" rev-list -1 --before="$date" HEAD)"
+    done
+
+    echo "# Commits of bgfx, bx and bimg the bindings are generated from, written by update.sh --api $version" > sources.lock
+    for name in bgfx bx bimg; do
+        echo "$name $(git -C "$name" rev-parse HEAD)" >> sources.lock
+    done
+else
+    grep -v '^#' sources.lock | while read -r name commit; do
+        fetch "$name" "$commit"
+    done
+fi